@@ -1,24 +1,41 @@
 //! Command handler dispatch for the track CLI.
 
-use crate::cli::handlers::CommandCtx;
-use crate::cli::Commands;
+use crate::cli::handlers::{CommandCtx, NewTaskSwitch};
+use crate::cli::{Commands, CompletionShell, StatusSections};
 use crate::db::Database;
-use crate::utils::Result;
-use clap_complete::Shell;
+use crate::models::{TaskListFilter, TaskOrder};
+use crate::use_cases::SyncOptions;
+use crate::utils::{term, time, Result};
+use std::path::Path;
 
 pub struct CommandHandler {
     db: Database,
+    assume_yes: bool,
 }
 
 impl CommandHandler {
-    pub fn new() -> Result<Self> {
-        let db = Database::new()?;
-        Ok(Self { db })
+    /// Opens the database at `db_path` (`--db`), falling back to `TRACK_DB_PATH`
+    /// and then the default data directory.
+    pub fn new(db_path: Option<&Path>) -> Result<Self> {
+        let db = Database::open_with_override(db_path)?;
+        Ok(Self {
+            db,
+            assume_yes: false,
+        })
     }
 
     #[allow(dead_code)]
     pub fn from_db(db: Database) -> Self {
-        Self { db }
+        Self {
+            db,
+            assume_yes: false,
+        }
+    }
+
+    /// Auto-confirm every prompt (`--yes`); per-command `--force` still works.
+    pub fn with_assume_yes(mut self, assume_yes: bool) -> Self {
+        self.assume_yes = assume_yes;
+        self
     }
 
     /// Returns a reference to the database instance.
@@ -29,7 +46,8 @@ impl CommandHandler {
     }
 
     pub fn handle(&self, command: Commands) -> Result<()> {
-        let ctx = CommandCtx::new(&self.db);
+        time::apply_time_style(self.db.get_time_style());
+        let ctx = CommandCtx::new(&self.db).with_assume_yes(self.assume_yes);
         match command {
             Commands::New {
                 name,
@@ -37,39 +55,147 @@ impl CommandHandler {
                 ticket,
                 ticket_url,
                 template,
+                vars,
+                switch_if_exists,
+                no_switch,
             } => super::handlers::handle_new(
                 &ctx,
                 &name,
                 description.as_deref(),
                 ticket.as_deref(),
                 ticket_url.as_deref(),
-                template.as_deref(),
+                template
+                    .as_deref()
+                    .map(|template| (template, vars.as_slice())),
+                if no_switch {
+                    NewTaskSwitch::Stay
+                } else if switch_if_exists {
+                    NewTaskSwitch::CreatedOrExisting
+                } else {
+                    NewTaskSwitch::Created
+                },
             ),
-            Commands::List { all } => super::handlers::handle_list(&ctx, all),
-            Commands::Switch { task_ref } => super::handlers::handle_switch(&ctx, &task_ref),
-            Commands::Status { id, json, all } => super::handlers::handle_info(&ctx, id, json, all),
-            Commands::Desc { description, task } => {
-                super::handlers::handle_desc(&ctx, description.as_deref(), task)
+            Commands::List {
+                all,
+                archived_only,
+                sort,
+                width,
+                no_truncate,
+            } => super::handlers::handle_list(
+                &ctx,
+                TaskListFilter::from_flags(all, archived_only),
+                sort.order(),
+                term::table_width(width, no_truncate),
+            ),
+            Commands::Switch { task_ref } => {
+                super::handlers::handle_switch(&ctx, task_ref.as_deref())
+            }
+            Commands::Status {
+                task_ref,
+                json,
+                all,
+                watch,
+                interval,
+                only,
+                no_todos,
+                no_links,
+                no_scraps,
+                no_worktrees,
+                absolute,
+            } => {
+                let sections =
+                    StatusSections::from_flags(&only, no_todos, no_links, no_scraps, no_worktrees);
+                if watch {
+                    super::handlers::handle_status_watch(
+                        &ctx, task_ref, all, sections, absolute, interval,
+                    )
+                } else {
+                    super::handlers::handle_info(&ctx, task_ref, json, all, sections, absolute)
+                }
             }
+            Commands::Stats { json } => super::handlers::handle_stats(&ctx, json),
+            Commands::Desc {
+                description,
+                append,
+                editor,
+                task,
+            } => super::handlers::handle_desc(
+                &ctx,
+                description.as_deref(),
+                append.as_deref(),
+                editor,
+                task,
+            ),
             Commands::Ticket {
                 ticket_id,
                 url,
+                set_url,
                 task,
-            } => super::handlers::handle_ticket(&ctx, &ticket_id, &url, task),
-            Commands::Archive { task_ref, force } => {
-                super::handlers::handle_archive(&ctx, task_ref.as_deref(), force)
-            }
+            } => match (ticket_id, url, set_url) {
+                (_, _, Some(url)) => super::handlers::handle_ticket_url(&ctx, &url, task),
+                // clap requires both positionals unless --url is given
+                (Some(ticket_id), Some(url), None) => {
+                    super::handlers::handle_ticket(&ctx, &ticket_id, &url, task)
+                }
+                _ => unreachable!("clap enforces ticket arguments"),
+            },
+            Commands::Archive { list: true, .. } => super::handlers::handle_list(
+                &ctx,
+                TaskListFilter::Archived,
+                TaskOrder::Created,
+                term::table_width(None, false),
+            ),
+            Commands::Archive {
+                task_ref, force, ..
+            } => super::handlers::handle_archive(&ctx, task_ref.as_deref(), force),
+            Commands::Export {
+                task_ref,
+                format,
+                all,
+                include_archived,
+                output,
+            } => super::handlers::handle_export(
+                &ctx,
+                task_ref.as_deref(),
+                format,
+                all,
+                include_archived,
+                output.as_deref(),
+            ),
+            Commands::Task(cmd) => super::handlers::handle_task(&ctx, cmd),
             Commands::Todo(cmd) => super::handlers::handle_todo(&ctx, cmd),
             Commands::Link(cmd) => super::handlers::handle_link(&ctx, cmd),
             Commands::Scrap(cmd) => super::handlers::handle_scrap(&ctx, cmd),
-            Commands::Sync { legacy } => super::handlers::handle_sync(&ctx, legacy),
+            Commands::Sync {
+                legacy,
+                create_base_worktree,
+                prune,
+                force,
+                json,
+                verify,
+            } => super::handlers::handle_sync(
+                &ctx,
+                SyncOptions {
+                    legacy,
+                    create_base_worktree,
+                },
+                prune,
+                force,
+                json,
+                verify.as_deref(),
+            ),
             Commands::Migrate(cmd) => super::handlers::handle_migrate(&ctx, cmd),
             Commands::Repo(cmd) => super::handlers::handle_repo(&ctx, cmd),
+            Commands::Worktree(cmd) => super::handlers::handle_worktree(&ctx, cmd),
             Commands::Alias(cmd) => super::handlers::handle_alias(&ctx, cmd),
+            Commands::Import(cmd) => super::handlers::handle_import(&ctx, cmd),
+            Commands::Doctor => super::handlers::handle_doctor(&ctx),
             Commands::LlmHelp => super::handlers::handle_llm_help(&ctx),
-            Commands::Completion { shell, dynamic } => {
-                super::handlers::handle_completion(&ctx, shell, dynamic)
-            }
+            Commands::Completion {
+                shell,
+                dynamic,
+                command,
+            } => super::handlers::handle_completion(&ctx, shell, dynamic, command),
             Commands::Complete { completion_type } => {
                 super::handlers::handle_complete(&ctx, completion_type)
             }
@@ -84,8 +210,8 @@ impl CommandHandler {
     }
 
     #[allow(dead_code)]
-    pub(crate) fn handle_completion(&self, shell: Shell, dynamic: bool) -> Result<()> {
-        super::handlers::handle_completion(&CommandCtx::new(&self.db), shell, dynamic)
+    pub(crate) fn handle_completion(&self, shell: CompletionShell, dynamic: bool) -> Result<()> {
+        super::handlers::handle_completion(&CommandCtx::new(&self.db), Some(shell), dynamic, None)
     }
 }
 
@@ -108,12 +234,7 @@ mod tests {
         let db = Database::new_in_memory().unwrap();
         let handler = CommandHandler::from_db(db);
 
-        for shell in [
-            clap_complete::Shell::Bash,
-            clap_complete::Shell::Zsh,
-            clap_complete::Shell::Fish,
-            clap_complete::Shell::PowerShell,
-        ] {
+        for &shell in <CompletionShell as clap::ValueEnum>::value_variants() {
             let result = handler.handle_completion(shell, false);
             assert!(
                 result.is_ok(),
@@ -122,7 +243,11 @@ mod tests {
             );
         }
 
-        for shell in [clap_complete::Shell::Bash, clap_complete::Shell::Zsh] {
+        for shell in [
+            CompletionShell::Bash,
+            CompletionShell::Zsh,
+            CompletionShell::Nushell,
+        ] {
             let result = handler.handle_completion(shell, true);
             assert!(
                 result.is_ok(),
@@ -1,65 +1,122 @@
 use crate::cli::handlers::CommandCtx;
-use crate::cli::CompletionType;
-use crate::models::TodoStatus;
+use crate::cli::{CompletionCommands, CompletionShell, CompletionType};
+use crate::models::{TaskListFilter, TodoStatus};
 use crate::services::{LinkService, RepoService, TaskService, TodoService};
-use crate::utils::Result;
+use crate::utils::{Result, TrackError};
 
 pub fn handle_completion(
-    _ctx: &CommandCtx,
-    shell: clap_complete::Shell,
+    ctx: &CommandCtx,
+    shell: Option<CompletionShell>,
     dynamic: bool,
+    command: Option<CompletionCommands>,
 ) -> Result<()> {
-    use clap::CommandFactory;
-    use clap_complete::generate;
-    use std::io;
+    if let Some(CompletionCommands::Test { completion_type }) = command {
+        return handle_completion_test(ctx, completion_type);
+    }
+    // clap requires the shell unless a subcommand is given
+    let Some(shell) = shell else {
+        return Ok(());
+    };
 
     if dynamic {
         // Output dynamic completion script
         let script = match shell {
-            clap_complete::Shell::Bash => {
-                include_str!("../../../completions/track.bash.dynamic")
-            }
-            clap_complete::Shell::Zsh => {
-                include_str!("../../../completions/_track.dynamic")
-            }
+            CompletionShell::Bash => include_str!("../../../completions/track.bash.dynamic"),
+            CompletionShell::Zsh => include_str!("../../../completions/_track.dynamic"),
+            CompletionShell::Nushell => include_str!("../../../completions/track.nu.dynamic"),
             _ => {
-                eprintln!("Dynamic completions are only available for bash and zsh.");
+                eprintln!("Dynamic completions are only available for bash, zsh, and nushell.");
                 eprintln!("Falling back to static completions for {:?}.", shell);
-                let mut cmd = crate::cli::Cli::command();
-                let bin_name = cmd.get_name().to_string();
-                generate(shell, &mut cmd, bin_name, &mut io::stdout());
+                print_static_completion(shell);
                 return Ok(());
             }
         };
         print!("{}", script);
     } else {
-        // Generate static completion using clap_complete
-        let mut cmd = crate::cli::Cli::command();
-        let bin_name = cmd.get_name().to_string();
-        generate(shell, &mut cmd, bin_name, &mut io::stdout());
+        print_static_completion(shell);
     }
 
     Ok(())
 }
 
+/// Generates a static completion script from the clap command tree.
+fn print_static_completion(shell: CompletionShell) {
+    use clap::CommandFactory;
+    use clap_complete::generate;
+    use std::io;
+
+    let mut cmd = crate::cli::Cli::command();
+    let bin_name = cmd.get_name().to_string();
+    match shell.clap_shell() {
+        Some(shell) => generate(shell, &mut cmd, bin_name, &mut io::stdout()),
+        None => generate(
+            clap_complete_nushell::Nushell,
+            &mut cmd,
+            bin_name,
+            &mut io::stdout(),
+        ),
+    }
+}
+
 pub fn handle_complete(ctx: &CommandCtx, completion_type: CompletionType) -> Result<()> {
+    for candidate in completion_candidates(ctx, completion_type)? {
+        println!("{}", candidate);
+    }
+    Ok(())
+}
+
+/// `track completion test`: prints the candidates `_complete` would emit and
+/// fails if any of them would confuse the dynamic completion scripts.
+pub fn handle_completion_test(ctx: &CommandCtx, completion_type: CompletionType) -> Result<()> {
+    let candidates = completion_candidates(ctx, completion_type)?;
+    let mut malformed = 0;
+    for candidate in &candidates {
+        if is_well_formed(candidate) {
+            println!("{}", candidate);
+        } else {
+            malformed += 1;
+            println!("{}  <-- malformed", candidate.replace('\n', "\\n"));
+        }
+    }
+
+    if candidates.is_empty() && ctx.db.get_current_task_id()?.is_none() {
+        eprintln!("No candidates (no active task).");
+    } else {
+        eprintln!("{} candidate(s), {} malformed", candidates.len(), malformed);
+    }
+    if malformed > 0 {
+        return Err(TrackError::MalformedCompletionCandidates(malformed));
+    }
+    Ok(())
+}
+
+/// Candidates are `ID:...` on a single line; the scripts split on the first `:`.
+fn is_well_formed(candidate: &str) -> bool {
+    !candidate.contains('\n')
+        && candidate
+            .split_once(':')
+            .is_some_and(|(id, _)| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn completion_candidates(ctx: &CommandCtx, completion_type: CompletionType) -> Result<Vec<String>> {
+    let mut candidates = Vec::new();
     match completion_type {
         CompletionType::Tasks => {
-            // Output task IDs and names for 'track switch'
+            // Task IDs and names for 'track switch'
             let task_service = TaskService::new(ctx.db);
-            let tasks = task_service.list_tasks(false)?; // Don't include archived
+            let tasks = task_service.list_tasks(TaskListFilter::Active)?; // Don't include archived
 
             for task in tasks {
                 // Format: ID:Name (or ID:Ticket:Name if ticket exists)
                 if let Some(ticket) = &task.ticket_id {
-                    println!("{}:{}:{}", task.id, ticket, task.name);
+                    candidates.push(format!("{}:{}:{}", task.id, ticket, task.name));
                 } else {
-                    println!("{}:{}", task.id, task.name);
+                    candidates.push(format!("{}:{}", task.id, task.name));
                 }
             }
         }
         CompletionType::Todos => {
-            // Output TODO IDs and content for current task
+            // TODO IDs and content for current task
             let current_task_id = ctx.db.get_current_task_id()?;
             if let Some(task_id) = current_task_id {
                 let todo_service = TodoService::new(ctx.db);
@@ -69,13 +126,13 @@ pub fn handle_complete(ctx: &CommandCtx, completion_type: CompletionType) -> Res
                     // Only show pending todos
                     if todo.status == TodoStatus::Pending {
                         // Format: ID:Content
-                        println!("{}:{}", todo.task_index, todo.content);
+                        candidates.push(format!("{}:{}", todo.task_index, todo.content));
                     }
                 }
             }
         }
         CompletionType::Links => {
-            // Output link IDs and URLs for current task
+            // Link IDs and URLs for current task
             let current_task_id = ctx.db.get_current_task_id()?;
             if let Some(task_id) = current_task_id {
                 let link_service = LinkService::new(ctx.db);
@@ -83,12 +140,12 @@ pub fn handle_complete(ctx: &CommandCtx, completion_type: CompletionType) -> Res
 
                 for link in links {
                     // Format: ID:Title:URL
-                    println!("{}:{}:{}", link.task_index, link.title, link.url);
+                    candidates.push(format!("{}:{}:{}", link.task_index, link.title, link.url));
                 }
             }
         }
         CompletionType::Repos => {
-            // Output repo IDs and paths for current task
+            // Repo IDs and paths for current task
             let current_task_id = ctx.db.get_current_task_id()?;
             if let Some(task_id) = current_task_id {
                 let repo_service = RepoService::new(ctx.db);
@@ -96,11 +153,11 @@ pub fn handle_complete(ctx: &CommandCtx, completion_type: CompletionType) -> Res
 
                 for repo in repos {
                     // Format: ID:Path
-                    println!("{}:{}", repo.task_index, repo.repo_path);
+                    candidates.push(format!("{}:{}", repo.task_index, repo.repo_path));
                 }
             }
         }
     }
 
-    Ok(())
+    Ok(candidates)
 }
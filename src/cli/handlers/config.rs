@@ -1,7 +1,10 @@
 use crate::cli::handlers::CommandCtx;
 use crate::cli::ConfigCommands;
+use crate::db::{TIME_FORMAT_KEY, TIME_ZONE_KEY};
 use crate::models::VcsMode;
-use crate::utils::{Result, TrackError};
+use crate::services::{LinkService, NotifyService};
+use crate::utils::time::{parse_time_zone, validate_time_format};
+use crate::utils::{http, Result, TrackError};
 
 pub fn handle_config(ctx: &CommandCtx, command: ConfigCommands) -> Result<()> {
     match command {
@@ -24,6 +27,38 @@ pub fn handle_config(ctx: &CommandCtx, command: ConfigCommands) -> Result<()> {
                         }
                     }
                 }
+                "notify-webhook" => {
+                    let url = value.trim();
+                    if !url.is_empty() {
+                        LinkService::new(ctx.db).validate_url(url)?;
+                    }
+                    NotifyService::new(ctx.db).set_webhook_url(url)?;
+                    if url.is_empty() {
+                        println!("Disabled TODO completion webhook");
+                    } else {
+                        println!("Set TODO completion webhook: {}", http::redact_url(url));
+                    }
+                }
+                "time-zone" => {
+                    let utc = parse_time_zone(&value)?;
+                    let zone = if utc { "utc" } else { "local" };
+                    ctx.db.set_app_state(TIME_ZONE_KEY, zone)?;
+                    println!("Set timestamp time zone: {zone}");
+                    if std::env::var_os("TRACK_TZ").is_some() {
+                        println!("(TRACK_TZ is set and takes precedence)");
+                    }
+                }
+                "time-format" => {
+                    let pattern = value.trim();
+                    if pattern.is_empty() {
+                        ctx.db.set_app_state(TIME_FORMAT_KEY, "")?;
+                        println!("Reset timestamp format to the defaults");
+                    } else {
+                        validate_time_format(pattern)?;
+                        ctx.db.set_app_state(TIME_FORMAT_KEY, pattern)?;
+                        println!("Set timestamp format: {pattern}");
+                    }
+                }
                 other => return Err(TrackError::UnknownConfigKey(other.to_string())),
             }
         }
@@ -40,6 +75,30 @@ pub fn handle_config(ctx: &CommandCtx, command: ConfigCommands) -> Result<()> {
             let vcs_mode = ctx.db.get_vcs_mode()?;
             println!("VCS mode: {vcs_mode} (jj = agent-skill-jj, git = plain git worktrees)");
 
+            match NotifyService::new(ctx.db).webhook_url()? {
+                Some(url) => println!("TODO completion webhook: {}", http::redact_url(&url)),
+                None => println!("TODO completion webhook: (not set)"),
+            }
+
+            let zone = ctx
+                .db
+                .get_app_state(TIME_ZONE_KEY)?
+                .unwrap_or_else(|| "local".to_string());
+            match std::env::var("TRACK_TZ") {
+                Ok(env) if !env.trim().is_empty() => {
+                    println!("Time zone: {zone} (overridden by TRACK_TZ={env})")
+                }
+                _ => println!("Time zone: {zone}"),
+            }
+            match ctx
+                .db
+                .get_app_state(TIME_FORMAT_KEY)?
+                .filter(|pattern| !pattern.is_empty())
+            {
+                Some(pattern) => println!("Time format: {pattern}"),
+                None => println!("Time format: (default)"),
+            }
+
             if let Some(calendar_id) = ctx.db.get_app_state("calendar_id")? {
                 println!("Google Calendar ID: {}", calendar_id);
             } else {
@@ -0,0 +1,29 @@
+use crate::cli::handlers::CommandCtx;
+use crate::use_cases::DoctorUseCase;
+use crate::utils::{Result, TrackError};
+
+pub fn handle_doctor(ctx: &CommandCtx) -> Result<()> {
+    let report = DoctorUseCase::new(ctx.db).run()?;
+
+    println!("=== Track Doctor ===\n");
+    for check in &report.checks {
+        println!(
+            "[{}] {}: {}",
+            check.status.label(),
+            check.name,
+            check.detail
+        );
+        if let Some(hint) = &check.hint {
+            println!("       Hint: {hint}");
+        }
+    }
+
+    let failures = report.failures();
+    if failures > 0 {
+        return Err(TrackError::Other(format!(
+            "doctor found {failures} failing check(s)"
+        )));
+    }
+
+    Ok(())
+}
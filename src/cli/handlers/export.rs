@@ -0,0 +1,103 @@
+use crate::cli::handlers::task::write_status_markdown;
+use crate::cli::handlers::CommandCtx;
+use crate::cli::{ExportFormat, StatusSections};
+use crate::models::TaskListFilter;
+use crate::services::TaskService;
+use crate::use_cases::GetTaskInfoUseCase;
+use crate::utils::{Result, TrackError};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+pub fn handle_export(
+    ctx: &CommandCtx,
+    task_ref: Option<&str>,
+    format: ExportFormat,
+    all: bool,
+    include_archived: bool,
+    output: Option<&Path>,
+) -> Result<()> {
+    let task_service = TaskService::new(ctx.db);
+
+    if !all {
+        let task_id = match task_ref {
+            Some(task_ref) => task_service.resolve_task_id(task_ref)?,
+            None => ctx
+                .db
+                .get_current_task_id()?
+                .ok_or(TrackError::NoActiveTask)?,
+        };
+        let document = render_task(ctx, task_id, format)?;
+        return match output {
+            Some(path) => {
+                fs::write(path, document)?;
+                println!("Exported task #{} to {}", task_id, path.display());
+                Ok(())
+            }
+            None => Ok(io::stdout().write_all(document.as_bytes())?),
+        };
+    }
+
+    let filter = if include_archived {
+        TaskListFilter::All
+    } else {
+        TaskListFilter::Active
+    };
+    let tasks = task_service.list_tasks(filter)?;
+
+    if let Some(dir) = output {
+        fs::create_dir_all(dir)?;
+        for task in &tasks {
+            let file = dir.join(format!("task-{}.{}", task.id, format.extension()));
+            fs::write(file, render_task(ctx, task.id, format)?)?;
+        }
+        println!("Exported {} task(s) to {}", tasks.len(), dir.display());
+        return Ok(());
+    }
+
+    let document = match format {
+        ExportFormat::Markdown => tasks
+            .iter()
+            .map(|task| render_task(ctx, task.id, format))
+            .collect::<Result<Vec<_>>>()?
+            .join("\n"),
+        ExportFormat::Json => {
+            let info = GetTaskInfoUseCase::new(ctx.db);
+            let bundles = tasks
+                .iter()
+                .map(|task| info.to_cli_json(&info.load(task.id)?))
+                .collect::<Result<Vec<_>>>()?;
+            to_pretty_json(&serde_json::Value::Array(bundles))?
+        }
+    };
+    io::stdout().write_all(document.as_bytes())?;
+    Ok(())
+}
+
+/// Renders one task: the full `track status` markdown, or the `status --json` bundle.
+fn render_task(ctx: &CommandCtx, task_id: i64, format: ExportFormat) -> Result<String> {
+    let info = GetTaskInfoUseCase::new(ctx.db);
+    let snapshot = info.load(task_id)?;
+
+    match format {
+        ExportFormat::Markdown => {
+            let mut buf = Vec::new();
+            write_status_markdown(
+                &mut buf,
+                ctx,
+                &snapshot,
+                true,
+                StatusSections::default(),
+                true,
+            )?;
+            String::from_utf8(buf).map_err(|e| TrackError::SerializationFailed(e.to_string()))
+        }
+        ExportFormat::Json => to_pretty_json(&info.to_cli_json(&snapshot)?),
+    }
+}
+
+fn to_pretty_json(value: &serde_json::Value) -> Result<String> {
+    serde_json::to_string_pretty(value)
+        .map(|json| json + "\n")
+        .map_err(|e| TrackError::SerializationFailed(e.to_string()))
+}
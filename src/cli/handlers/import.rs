@@ -0,0 +1,37 @@
+use crate::cli::handlers::CommandCtx;
+use crate::cli::ImportCommands;
+use crate::use_cases::{ImportTaskUseCase, TaskBundle};
+use crate::utils::Result;
+use std::io::Read;
+
+pub fn handle_import(ctx: &CommandCtx, command: ImportCommands) -> Result<()> {
+    match command {
+        ImportCommands::Json {
+            file,
+            skip_conflicting_ticket,
+        } => {
+            let json = if file == "-" {
+                let mut buf = String::new();
+                std::io::stdin().read_to_string(&mut buf)?;
+                buf
+            } else {
+                std::fs::read_to_string(&file)?
+            };
+
+            let bundle = TaskBundle::from_json(&json)?;
+            let outcome =
+                ImportTaskUseCase::new(ctx.db).execute(&bundle, skip_conflicting_ticket)?;
+
+            if let Some(ticket) = &outcome.skipped_ticket {
+                eprintln!("Warning: ticket {ticket} is already used by another task; imported without it.");
+            }
+            println!(
+                "Imported task #{}: {} ({} TODOs, {} links, {} scraps)",
+                outcome.task.id, outcome.task.name, outcome.todos, outcome.links, outcome.scraps
+            );
+            println!("Switched to task #{}", outcome.task.id);
+        }
+    }
+
+    Ok(())
+}
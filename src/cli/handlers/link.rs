@@ -1,8 +1,14 @@
 use crate::cli::handlers::CommandCtx;
 use crate::cli::LinkCommands;
 use crate::services::LinkService;
+use crate::use_cases::status_document::StatusLink;
 use crate::utils::{Result, TrackError};
 use prettytable::{format, Cell, Row, Table};
+use std::thread;
+use std::time::Duration;
+
+/// Pause between browser launches so a long list doesn't flood the browser.
+const OPEN_DELAY: Duration = Duration::from_millis(300);
 
 pub fn handle_link(ctx: &CommandCtx, command: LinkCommands) -> Result<()> {
     let current_task_id = ctx
@@ -16,12 +22,22 @@ pub fn handle_link(ctx: &CommandCtx, command: LinkCommands) -> Result<()> {
             let link = link_service.add_link(current_task_id, &url, title.as_deref())?;
             println!("Added link #{}: {}", link.task_index, link.title);
         }
-        LinkCommands::List => {
+        LinkCommands::List { json } => {
             let links = link_service.list_links(current_task_id)?;
+            if json {
+                // Same fields as the `links` entries of `track status --json`.
+                let output: Vec<_> = links.iter().map(StatusLink::from).collect();
+                let json = serde_json::to_string_pretty(&output)
+                    .map_err(|e| TrackError::SerializationFailed(e.to_string()))?;
+                println!("{json}");
+                return Ok(());
+            }
+
             let mut table = Table::new();
             table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
             table.set_titles(Row::new(vec![
                 Cell::new("ID"),
+                Cell::new("Kind"),
                 Cell::new("Title"),
                 Cell::new("URL"),
             ]));
@@ -29,6 +45,7 @@ pub fn handle_link(ctx: &CommandCtx, command: LinkCommands) -> Result<()> {
             for link in links {
                 table.add_row(Row::new(vec![
                     Cell::new(&link.task_index.to_string()),
+                    Cell::new(link.kind()),
                     Cell::new(&link.title),
                     Cell::new(&link.url),
                 ]));
@@ -42,14 +59,51 @@ pub fn handle_link(ctx: &CommandCtx, command: LinkCommands) -> Result<()> {
             // Find link by task_index
             let link = links
                 .iter()
-                .find(|l| l.task_index == index as i64)
-                .ok_or(TrackError::LinkIndexNotFound(index as i64))?;
+                .find(|l| l.task_index == index)
+                .ok_or(TrackError::LinkIndexNotFound(index))?;
 
             // Delete link via service
             link_service.delete_link(link.id)?;
 
             println!("Deleted link #{}: {}", index, link.title);
         }
+        LinkCommands::Move { index, to } => {
+            let link = link_service.move_link(current_task_id, index, to)?;
+            println!(
+                "Moved link #{} to #{}: {}",
+                index, link.task_index, link.title
+            );
+        }
+        LinkCommands::OpenAll { dry_run } => {
+            let links = link_service.list_links(current_task_id)?;
+            if links.is_empty() {
+                println!("No links for this task.");
+                return Ok(());
+            }
+
+            let mut opened = 0;
+            for link in &links {
+                if let Err(e) = link_service.validate_url(&link.url) {
+                    eprintln!("Warning: skipping link #{}: {}", link.task_index, e);
+                    continue;
+                }
+                if dry_run {
+                    println!("#{} {}: {}", link.task_index, link.title, link.url);
+                    continue;
+                }
+
+                if opened > 0 {
+                    thread::sleep(OPEN_DELAY);
+                }
+                match open::that(&link.url) {
+                    Ok(()) => {
+                        println!("Opened #{}: {}", link.task_index, link.url);
+                        opened += 1;
+                    }
+                    Err(e) => eprintln!("Warning: failed to open {}: {}", link.url, e),
+                }
+            }
+        }
     }
 
     Ok(())
@@ -30,6 +30,7 @@ track status --json
 
 | Field | Use |
 |-------|-----|
+| `schema_version` | Status JSON shape version (currently 1) |
 | `workflow.phase` | setup · sync_required · execute · task_complete · archived |
 | `workflow.next_action.command` | Next command to run |
 | `jj.slug` | jj-task workspace name |
@@ -103,10 +104,12 @@ This guide explains the standard workflow for completing tasks.
 4. **Register Repositories**: `track repo add [path]`
    - Register working repositories (default: current directory).
    - Optionally specify base bookmark: `track repo add --base <bookmark>`
+   - Without `--base`, the current bookmark (on `@` or `@-`, else the git branch) is recorded as the base.
    - Run this for each repository involved in the task.
 
 5. **Add TODOs**: `track todo add "<content>"` or `track todo add "<content>" --no-workspace`
    - Default TODOs expect a jj-task workspace. Use `--no-workspace` for research/planning items.
+   - `track task set auto-workspace off` makes `--no-workspace` the task's default; `--workspace` overrides it.
    - Legacy `--worktree` is deprecated — use one jj-task workspace per task.
 
 6. **Add Links**: `track link add <url> [--title "<title>"]`
@@ -140,40 +143,80 @@ This guide explains the standard workflow for completing tasks.
 | Command | Description |
 |---------|-------------|
 | `track sync [--legacy]` | Git: create worktree. JJ: legacy per-TODO only (else use jj-task) |
+| `track sync --json` | Summary counts only (created/reused/skipped/missing/failures) |
+| `track sync --prune --force` | Remove worktrees of done/cancelled TODOs without prompting |
+| `track sync --verify "<command>"` | Run a check (e.g. `cargo check`) in each newly created worktree; reports pass/fail |
+| `track worktree list [--all-tasks] [--size] [--status <state>]` | List worktrees; `--size` adds disk usage, `--status merged\|removed\|all` shows history (default active) |
+| `track worktree add <repo> --base-worktree` | Register a missing base worktree (no-op if the task has one) |
+| `track worktree remove <id> [--force]` | Remove a worktree (base is refused while TODO worktrees remain; record kept if the directory survives) |
+| `track worktree remove <id> --purge` | Delete the record instead of keeping it as `removed` history |
+| `track worktree merge-status <id>` | Check whether a worktree bookmark is merged into the task bookmark |
 | `track migrate legacy-worktrees [--dry-run] [--force]` | Clear legacy flags; remove legacy worktree DB/jj workspaces |
 | `track status` | Show current task, TODOs, workspaces, links |
+| `track status --only todos` | Show just the listed sections (`--no-scraps` etc. to hide one) |
 | `track status --json` | **Preferred for agents** — task + workflow + todos_agent + guardrails |
+| `track export [--all] [-f json] [-o <path>]` | Snapshot one or every task as markdown/JSON |
 | `track status --all` | Show all scraps instead of recent |
 | `track new "<name>"` | Create new task |
 | `track new "<name>" --ticket <id> --ticket-url <url>` | Create task with ticket |
 | `track new "<name>" --template <ref>` | Create task from template (copies TODOs) |
+| `track new "<name>" --template <ref> --var NAME=VALUE` | Fill `{{{{NAME}}}}` placeholders in copied TODOs (repeatable) |
 | `track list` | List all tasks |
+| `track list --sort recent` | List tasks by last activity |
+| `track list --no-truncate` | Show full names/tickets (terminal output is cut to fit by default; `--width N` to set the width) |
 | `track desc [text]` | View or set task description |
+| `track desc --append "<text>"` | Append a line to the description |
+| `cat notes.md \| track desc --editor` | Replace the description with stdin (opens `$EDITOR` on a terminal) |
 | `track ticket <ticket_id> <url>` | Link ticket to current task |
+| `track ticket --url <url>` | Fix the ticket URL only (ID unchanged) |
 | `track switch <id>` | Switch to another task |
 | `track switch t:<ticket_id>` | Switch by ticket reference |
 | `track switch a:<alias>` | Switch by alias |
+| `track task status blocked\|paused\|active` | Set a task aside without archiving it |
+| `track task touch [task_ref]` | Bump a task's last-activity time |
 | `track archive [task_ref] [--force]` | Archive task (requires `jj-task done` when workspace active) |
 | `track alias set <alias>` | Set alias for current task |
 | `track alias set <alias> --force` | Overwrite existing alias on another task |
 | `track alias remove` | Remove alias from current task |
 | `track repo add [path]` | Register repository (default: current dir) |
 | `track repo add --base <bookmark>` | Register with custom base bookmark |
+| `track repo add --base-commit <hash>` | Register with the branch point pinned to a commit |
 | `track repo list` | List registered repositories |
-| `track repo remove <index>` | Remove repository by task-scoped index |
+| `track repo remove <index-or-path>` | Remove repository by task-scoped index or path (`--worktrees` also removes its worktrees after confirming; `--force` skips the prompt) |
+| `track repo sync-links` | Record open/merged/closed state of PR/issue links, shown in `track status` |
 | `track todo add "<text>"` | Add TODO |
+| `track todo add "<text>" --estimate <n>` | Record a planning estimate (rolled up in `status`) |
+| `track todo add "<text>" --due <date>` | Set a due date (`YYYY-MM-DD`, `today`, `tomorrow`, `3d`) |
+| `track todo add "<text>" --auto-link` | Also add URLs in the text as task links (skips already-linked URLs) |
+| `track todo due <index> <date>` | Change a TODO's due date (`--clear` removes it) |
 | `track todo add "<text>" [--no-workspace]` | Add TODO (`--no-workspace` for research) |
+| `track todo add "<text>" --after-current` | Insert right after the current TODO (`--top` to make it next) |
 | `track todo list` | List TODOs |
+| `track todo show <index>` | Show one TODO with full content, worktrees, and scraps |
+| `track todo list --completed-since 3d` | List TODOs completed in the last 3 days (also `12h`, `2w`, `YYYY-MM-DD`) |
+| `track todo list --worktree-only` | TODOs that have or requested a worktree, with branch/path |
+| `track todo list --limit 20 --offset 40` | Page through long TODO lists |
+| `track todo list --no-truncate` | Show full TODO content (`--width N` fits it into N columns) |
 | `track todo workspace <index>` | Show or recreate TODO workspace |
+| `track todo workspace <index> --branch <name>` | Create it on a named branch (ticket prefix kept) |
 | `track todo done <index>` | Complete TODO (rebases workspace if exists) |
+| `track todo done <index> --commit "msg"` | Commit a dirty workspace, then complete |
+| `track todo done <index> --close-issue` | Also close the task's GitHub/GitLab issue (best-effort) |
+| `track todo done <index> --push` | Push the task bookmark after merging (best-effort) |
+| `track todo done <index> --note <text>` | Record a closing note as a scrap attached to the TODO |
 | `track todo update <index> cancelled` | Cancel a pending TODO (use `todo done` to complete) |
+| `track todo set-status cancelled --matching <text>` | Cancel every pending TODO containing `<text>` (`--force` above five) |
 | `track todo delete <index>` | Delete TODO |
 | `track link add <url>` | Add reference link |
 | `track link add <url> --title "<title>"` | Add link with custom title |
 | `track link list` | List all links |
 | `track link delete <index>` | Delete link by task-scoped index |
+| `track link move <index> --to <pos>` | Reorder a link within the task |
+| `track link open-all [--dry-run]` | Open all task links in the browser (not useful for agents; prefer `--dry-run`) |
 | `track scrap add "<note>"` | Record work note |
 | `track scrap list` | List all scraps |
+| `track scrap list --json` | Scraps as JSON (content, content_html, created_at, active_todo_id, pinned) |
+| `track scrap add "<note>" --pin` / `track scrap pin <index>` | Pin a durable reference note ahead of the log (`unpin` to undo) |
 | `track webui` | Start web-based UI (default: http://localhost:3000) |
 | `track llm-help` | Show this help message |
 
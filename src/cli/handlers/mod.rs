@@ -3,38 +3,75 @@
 mod alias;
 mod completion;
 mod config;
+mod doctor;
+mod export;
+mod import;
 mod link;
 mod llm_help;
 mod migrate;
 mod repo;
 mod scrap;
+mod stats;
 mod sync;
 mod task;
 mod todo;
+mod worktree;
 
 pub use alias::handle_alias;
 pub use completion::{handle_complete, handle_completion};
 pub use config::handle_config;
+pub use doctor::handle_doctor;
+pub use export::handle_export;
+pub use import::handle_import;
 pub use link::handle_link;
 pub use llm_help::handle_llm_help;
 pub use migrate::handle_migrate;
 pub use repo::handle_repo;
 pub use scrap::handle_scrap;
+pub use stats::handle_stats;
 pub use sync::handle_sync;
 pub use task::{
-    handle_archive, handle_desc, handle_info, handle_list, handle_new, handle_switch, handle_ticket,
+    handle_archive, handle_desc, handle_info, handle_list, handle_new, handle_status_watch,
+    handle_switch, handle_task, handle_ticket, handle_ticket_url, NewTaskSwitch,
 };
 pub use todo::handle_todo;
+pub use worktree::handle_worktree;
 
 /// Shared database access for command handlers.
 use crate::db::Database;
+use crate::utils::Result;
+use std::io::{self, Write};
 
 pub struct CommandCtx<'a> {
     pub db: &'a Database,
+    /// Auto-confirm prompts (`--yes`).
+    pub assume_yes: bool,
 }
 
 impl<'a> CommandCtx<'a> {
     pub fn new(db: &'a Database) -> Self {
-        Self { db }
+        Self {
+            db,
+            assume_yes: false,
+        }
+    }
+
+    pub fn with_assume_yes(mut self, assume_yes: bool) -> Self {
+        self.assume_yes = assume_yes;
+        self
+    }
+
+    /// Prints `prompt` and reads a y/N answer; `--yes` answers for the user.
+    pub fn confirm(&self, prompt: &str) -> Result<bool> {
+        print!("{prompt}");
+        if self.assume_yes {
+            println!("y");
+            return Ok(true);
+        }
+        io::stdout().flush()?;
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
     }
 }
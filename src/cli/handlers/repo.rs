@@ -1,7 +1,8 @@
 use crate::cli::handlers::CommandCtx;
 use crate::cli::RepoCommands;
-use crate::services::RepoService;
-use crate::utils::{Result, TrackError};
+use crate::services::git_worktree::current_git_branch;
+use crate::services::{ForgeClient, ForgeRef, RepoService, WorktreeService};
+use crate::utils::{CommandExt, Result, TrackError};
 use prettytable::{format, Cell, Row, Table};
 
 pub fn handle_repo(ctx: &CommandCtx, command: RepoCommands) -> Result<()> {
@@ -12,11 +13,19 @@ pub fn handle_repo(ctx: &CommandCtx, command: RepoCommands) -> Result<()> {
     let repo_service = RepoService::new(ctx.db);
 
     match command {
-        RepoCommands::Add { path, base } => {
+        RepoCommands::Add {
+            path,
+            base,
+            base_commit,
+        } => {
             let repo_path = path.as_deref().unwrap_or(".");
+            let detected = base.is_none() && base_commit.is_none();
 
-            // Determine base bookmark and change ID
-            let (base_branch, base_commit_hash) = if let Some(bookmark) = base {
+            // Determine base bookmark and change ID. A pinned commit is stored without a
+            // bookmark so sync branches from the exact hash rather than a moving head.
+            let (base_branch, base_commit_hash) = if let Some(commit) = base_commit {
+                (None, Some(resolve_commit_id(repo_path, &commit)?))
+            } else if let Some(bookmark) = base {
                 let hash_output = std::process::Command::new("jj")
                     .args([
                         "-R",
@@ -28,7 +37,7 @@ pub fn handle_repo(ctx: &CommandCtx, command: RepoCommands) -> Result<()> {
                         "-T",
                         "commit_id",
                     ])
-                    .output()?;
+                    .logged_output()?;
 
                 if !hash_output.status.success() {
                     return Err(TrackError::Jj(format!(
@@ -41,20 +50,7 @@ pub fn handle_repo(ctx: &CommandCtx, command: RepoCommands) -> Result<()> {
                     .to_string();
                 (Some(bookmark), Some(hash))
             } else {
-                let bookmark_output = std::process::Command::new("jj")
-                    .args(["-R", repo_path, "bookmark", "list", "-r", "@", "-T", "name"])
-                    .output()?;
-
-                if !bookmark_output.status.success() {
-                    return Err(TrackError::Jj(
-                        "Failed to resolve current bookmark".to_string(),
-                    ));
-                }
-
-                let bookmark = String::from_utf8_lossy(&bookmark_output.stdout)
-                    .lines()
-                    .find(|line| !line.trim().is_empty())
-                    .map(|line| line.trim().to_string());
+                let bookmark = detect_base_bookmark(repo_path)?;
 
                 let hash = if let Some(ref name) = bookmark {
                     let hash_output = std::process::Command::new("jj")
@@ -68,7 +64,7 @@ pub fn handle_repo(ctx: &CommandCtx, command: RepoCommands) -> Result<()> {
                             "-T",
                             "commit_id",
                         ])
-                        .output()?;
+                        .logged_output()?;
 
                     if !hash_output.status.success() {
                         return Err(TrackError::Jj(
@@ -96,11 +92,21 @@ pub fn handle_repo(ctx: &CommandCtx, command: RepoCommands) -> Result<()> {
             )?;
             println!("Registered repository: {}", repo.repo_path);
             if let Some(branch) = base_branch {
-                println!(
-                    "Base bookmark: {} ({})",
-                    branch,
-                    &base_commit_hash.unwrap()[..8]
-                );
+                let suffix = if detected { ", detected" } else { "" };
+                if let Some(hash) = base_commit_hash {
+                    println!(
+                        "Base bookmark: {} ({}{})",
+                        branch,
+                        &hash[..8.min(hash.len())],
+                        suffix
+                    );
+                } else if detected {
+                    println!("Base bookmark: {} (detected)", branch);
+                } else {
+                    println!("Base bookmark: {}", branch);
+                }
+            } else if let Some(hash) = base_commit_hash {
+                println!("Base commit: {} (pinned)", &hash[..12.min(hash.len())]);
             }
         }
         RepoCommands::List => {
@@ -121,19 +127,163 @@ pub fn handle_repo(ctx: &CommandCtx, command: RepoCommands) -> Result<()> {
 
             table.printstd();
         }
-        RepoCommands::Remove { id } => {
-            let repos = repo_service.list_repos(current_task_id)?;
+        RepoCommands::Remove {
+            repo_ref,
+            worktrees,
+            force,
+        } => {
+            let repo = repo_service.resolve_repo(current_task_id, &repo_ref)?;
+            let worktree_service = WorktreeService::new(ctx.db);
+            let leftovers =
+                worktree_service.list_worktrees_for_repo(current_task_id, &repo.repo_path)?;
 
-            // Find repo by task_index
-            let repo = repos
-                .iter()
-                .find(|r| r.task_index == id)
-                .ok_or(TrackError::TaskRepoIndexNotFound(id))?;
+            if worktrees && !leftovers.is_empty() {
+                println!("Worktrees to remove with repository #{}:", repo.task_index);
+                for wt in &leftovers {
+                    println!("  #{} {} ({})", wt.id, wt.path, wt.branch);
+                }
+                let prompt = format!("Remove {} worktree(s)? [y/N]: ", leftovers.len());
+                if !force && !ctx.confirm(&prompt)? {
+                    println!("Cancelled.");
+                    return Ok(());
+                }
+
+                // Keep going past failures so one stuck worktree does not hide the rest.
+                let mut failed = 0;
+                for wt in &leftovers {
+                    match worktree_service.remove_worktree(wt.id, false) {
+                        Ok(()) => println!("Removed worktree #{} ({})", wt.id, wt.branch),
+                        Err(err) => {
+                            eprintln!(
+                                "  ✗ Failed to remove worktree #{} {}: {}",
+                                wt.id, wt.path, err
+                            );
+                            failed += 1;
+                        }
+                    }
+                }
+                if failed > 0 {
+                    return Err(TrackError::RepoWorktreeRemovalIncomplete(failed));
+                }
+            }
 
             repo_service.remove_repo(repo.id)?;
-            println!("Removed repository #{}", id);
+            println!(
+                "Removed repository #{}: {}",
+                repo.task_index, repo.repo_path
+            );
+
+            if !worktrees && !leftovers.is_empty() {
+                eprintln!(
+                    "Warning: {} worktree(s) created from this repository remain:",
+                    leftovers.len()
+                );
+                for wt in &leftovers {
+                    eprintln!("  #{} {} ({})", wt.id, wt.path, wt.branch);
+                }
+                eprintln!(
+                    "Remove them with `track worktree remove <id>` or rerun with --worktrees."
+                );
+            }
+        }
+        RepoCommands::SyncLinks => {
+            let worktree_service = WorktreeService::new(ctx.db);
+            let client = ForgeClient::new();
+            let mut updated = 0;
+            let mut skipped = 0;
+
+            for worktree in worktree_service.list_worktrees(current_task_id)? {
+                for link in worktree_service.list_repo_links(worktree.id)? {
+                    let Some(forge_ref) = ForgeRef::parse(&link.url) else {
+                        skipped += 1;
+                        continue;
+                    };
+                    // One unreachable forge or missing token should not abort the rest.
+                    match client.fetch_state(&forge_ref) {
+                        Ok(state) => {
+                            worktree_service.set_repo_link_state(link.id, &state)?;
+                            println!("{}: {} [{}]", link.kind, link.url, state);
+                            updated += 1;
+                        }
+                        Err(e) => {
+                            eprintln!("Warning: could not fetch state for {}: {}", link.url, e)
+                        }
+                    }
+                }
+            }
+
+            println!(
+                "Updated {} link(s), skipped {} non-forge link(s).",
+                updated, skipped
+            );
         }
     }
 
     Ok(())
 }
+
+/// Detects the branch the repository is currently on so task branches have a
+/// recorded base even when `--base` is omitted. Checks the bookmarks on the
+/// working-copy commit and its parent (jj usually sits on an empty change above
+/// the bookmark), then falls back to the git branch for colocated repositories.
+fn detect_base_bookmark(repo_path: &str) -> Result<Option<String>> {
+    for revision in ["@", "@-"] {
+        let bookmark_output = std::process::Command::new("jj")
+            .args([
+                "-R",
+                repo_path,
+                "bookmark",
+                "list",
+                "-r",
+                revision,
+                "-T",
+                "name ++ \"\\n\"",
+            ])
+            .logged_output()?;
+
+        if !bookmark_output.status.success() {
+            return Err(TrackError::Jj(
+                "Failed to resolve current bookmark".to_string(),
+            ));
+        }
+
+        let bookmark = String::from_utf8_lossy(&bookmark_output.stdout)
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(str::to_string);
+        if bookmark.is_some() {
+            return Ok(bookmark);
+        }
+    }
+
+    Ok(current_git_branch(repo_path))
+}
+
+/// Resolves `revision` to the full commit ID of exactly one commit in `repo_path`.
+fn resolve_commit_id(repo_path: &str, revision: &str) -> Result<String> {
+    let output = std::process::Command::new("jj")
+        .args([
+            "-R",
+            repo_path,
+            "log",
+            "-r",
+            revision,
+            "--no-graph",
+            "-T",
+            "commit_id ++ \"\\n\"",
+        ])
+        .logged_output()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut ids = stdout
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+    match (output.status.success(), ids.next(), ids.next()) {
+        (true, Some(id), None) => Ok(id.to_string()),
+        _ => Err(TrackError::Jj(format!(
+            "Base commit '{revision}' does not resolve to a single commit in {repo_path}"
+        ))),
+    }
+}
@@ -1,8 +1,7 @@
 use crate::cli::handlers::CommandCtx;
 use crate::cli::ScrapCommands;
 use crate::services::ScrapService;
-use crate::utils::{Result, TrackError};
-use chrono::Local;
+use crate::utils::{format_timestamp, Result, TrackError};
 
 pub fn handle_scrap(ctx: &CommandCtx, command: ScrapCommands) -> Result<()> {
     let current_task_id = ctx
@@ -12,22 +11,44 @@ pub fn handle_scrap(ctx: &CommandCtx, command: ScrapCommands) -> Result<()> {
     let scrap_service = ScrapService::new(ctx.db);
 
     match command {
-        ScrapCommands::Add { content } => {
-            let scrap = scrap_service.add_scrap(current_task_id, &content)?;
-            let timestamp = scrap
-                .created_at
-                .with_timezone(&Local)
-                .format("%Y-%m-%d %H:%M:%S");
-            println!("Added scrap at {}", timestamp);
+        ScrapCommands::Add { content, pin } => {
+            let scrap = scrap_service.add_scrap_with_pin(current_task_id, &content, pin)?;
+            let timestamp = format_timestamp(scrap.created_at, "%Y-%m-%d %H:%M:%S");
+            if scrap.pinned {
+                println!("Added pinned scrap #{} at {}", scrap.scrap_id, timestamp);
+            } else {
+                println!("Added scrap #{} at {}", scrap.scrap_id, timestamp);
+            }
+        }
+        ScrapCommands::Pin { index } => {
+            let scrap = scrap_service.set_pinned(current_task_id, index, true)?;
+            println!("Pinned scrap #{}", scrap.scrap_id);
+        }
+        ScrapCommands::Unpin { index } => {
+            let scrap = scrap_service.set_pinned(current_task_id, index, false)?;
+            println!("Unpinned scrap #{}", scrap.scrap_id);
         }
-        ScrapCommands::List => {
+        ScrapCommands::List { json } => {
             let scraps = scrap_service.list_scraps(current_task_id)?;
+            if json {
+                // Same fields as the WebUI scraps card, with an RFC3339 timestamp.
+                let output: Vec<_> = scraps
+                    .iter()
+                    .map(|scrap| scrap.card_json(|at| at.to_rfc3339()))
+                    .collect();
+                let json = serde_json::to_string_pretty(&output)
+                    .map_err(|e| TrackError::SerializationFailed(e.to_string()))?;
+                println!("{json}");
+                return Ok(());
+            }
+
             for scrap in scraps {
-                let timestamp = scrap
-                    .created_at
-                    .with_timezone(&Local)
-                    .format("%Y-%m-%d %H:%M:%S");
-                println!("[{}]", timestamp);
+                let timestamp = format_timestamp(scrap.created_at, "%Y-%m-%d %H:%M:%S");
+                if scrap.pinned {
+                    println!("#{} [{}] (pinned)", scrap.scrap_id, timestamp);
+                } else {
+                    println!("#{} [{}]", scrap.scrap_id, timestamp);
+                }
                 println!("  {}", scrap.content);
                 println!();
             }
@@ -0,0 +1,45 @@
+use crate::cli::handlers::CommandCtx;
+use crate::services::StatsService;
+use crate::utils::{Result, TrackError};
+use prettytable::{format, Cell, Row, Table};
+
+pub fn handle_stats(ctx: &CommandCtx, json: bool) -> Result<()> {
+    let stats = StatsService::new(ctx.db).collect()?;
+
+    if json {
+        let json = serde_json::to_string_pretty(&stats)
+            .map_err(|e| TrackError::SerializationFailed(e.to_string()))?;
+        println!("{json}");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.set_titles(Row::new(vec![Cell::new("Metric"), Cell::new("Count")]));
+
+    let rows = [
+        ("Tasks (open)", stats.tasks_active.to_string()),
+        ("Tasks (archived)", stats.tasks_archived.to_string()),
+        ("Tasks (total)", stats.total_tasks().to_string()),
+        ("TODOs (pending)", stats.todos_pending.to_string()),
+        ("TODOs (done)", stats.todos_done.to_string()),
+        ("TODOs (cancelled)", stats.todos_cancelled.to_string()),
+        ("TODOs (total)", stats.total_todos().to_string()),
+        (
+            "TODOs per task (avg)",
+            format!("{:.2}", stats.avg_todos_per_task),
+        ),
+        ("Estimate (pending)", stats.estimate_pending.to_string()),
+        ("Estimate (done)", stats.estimate_done.to_string()),
+        ("Estimate (cancelled)", stats.estimate_cancelled.to_string()),
+        ("Worktrees", stats.worktrees.to_string()),
+        ("Scraps", stats.scraps.to_string()),
+    ];
+
+    for (label, value) in rows {
+        table.add_row(Row::new(vec![Cell::new(label), Cell::new(&value)]));
+    }
+
+    table.printstd();
+    Ok(())
+}
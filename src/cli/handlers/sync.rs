@@ -1,15 +1,40 @@
 use crate::cli::handlers::CommandCtx;
 use crate::models::VcsMode;
-use crate::use_cases::{RepoSyncOutcome, SyncTaskUseCase};
+use crate::use_cases::{
+    verify_worktree, RepoSyncOutcome, SyncOptions, SyncSummary, SyncTaskUseCase,
+};
 use crate::utils::{Result, TrackError};
 
-pub fn handle_sync(ctx: &CommandCtx, legacy: bool) -> Result<()> {
+pub fn handle_sync(
+    ctx: &CommandCtx,
+    options: SyncOptions,
+    prune: bool,
+    force: bool,
+    json: bool,
+    verify: Option<&str>,
+) -> Result<()> {
+    let legacy = options.legacy;
     let current_task_id = ctx
         .db
         .get_current_task_id()?
         .ok_or(TrackError::NoActiveTask)?;
 
-    let outcome = SyncTaskUseCase::new(ctx.db).execute(current_task_id, legacy)?;
+    let use_case = SyncTaskUseCase::new(ctx.db);
+    // Refuse up front so a sync that cannot run does not prune first.
+    use_case.validate(current_task_id, legacy)?;
+    if prune {
+        prune_finished_worktrees(ctx, &use_case, current_task_id, force)?;
+    }
+
+    let outcome = use_case.execute(current_task_id, options)?;
+    let summary = outcome.summary();
+
+    if json {
+        let json = serde_json::to_string_pretty(&summary)
+            .map_err(|e| TrackError::SerializationFailed(e.to_string()))?;
+        println!("{json}");
+        return Ok(());
+    }
 
     match outcome.vcs_mode {
         VcsMode::Jj => {
@@ -26,7 +51,9 @@ pub fn handle_sync(ctx: &CommandCtx, legacy: bool) -> Result<()> {
             RepoSyncOutcome::Missing => {
                 println!("  ⚠ Repository not found, skipping\n");
             }
-            RepoSyncOutcome::BookmarkCreated { base_ref, edit_ok } => {
+            RepoSyncOutcome::BookmarkCreated {
+                base_ref, edit_ok, ..
+            } => {
                 println!(
                     "  ✓ Bookmark {} created from {}",
                     outcome.task_bookmark, base_ref
@@ -37,7 +64,9 @@ pub fn handle_sync(ctx: &CommandCtx, legacy: bool) -> Result<()> {
                 println!("  ✓ Bookmark {} already exists", outcome.task_bookmark);
                 print_edit_result(&outcome.task_bookmark, *edit_ok);
             }
-            RepoSyncOutcome::BookmarkCreateFailed { base_ref, detail } => {
+            RepoSyncOutcome::BookmarkCreateFailed {
+                base_ref, detail, ..
+            } => {
                 println!(
                     "  ✗ Failed to create bookmark {} from {} ({})",
                     outcome.task_bookmark, base_ref, detail
@@ -46,6 +75,7 @@ pub fn handle_sync(ctx: &CommandCtx, legacy: bool) -> Result<()> {
             RepoSyncOutcome::WorktreeCreated {
                 base_ref,
                 workspace_path,
+                ..
             } => {
                 println!(
                     "  ✓ Worktree {} created from {} at {}",
@@ -58,13 +88,28 @@ pub fn handle_sync(ctx: &CommandCtx, legacy: bool) -> Result<()> {
                     outcome.task_bookmark, workspace_path
                 );
             }
-            RepoSyncOutcome::WorktreeCreateFailed { base_ref, detail } => {
+            RepoSyncOutcome::WorktreeCreateFailed {
+                base_ref, detail, ..
+            } => {
                 println!(
                     "  ✗ Failed to create worktree {} from {} ({})",
                     outcome.task_bookmark, base_ref, detail
                 );
             }
         }
+        if repo_outcome.created_from_head() {
+            eprintln!(
+                "  warning: no base branch stored for this repo; {} was based on the current checkout.\n  Re-register it with `track repo remove` and `track repo add --base <branch>` to start from mainline.",
+                outcome.task_bookmark
+            );
+        }
+    }
+
+    for base in &outcome.base_worktrees {
+        println!("Registered base worktree: {} ({})", base.path, base.branch);
+    }
+    if options.create_base_worktree && outcome.vcs_mode == VcsMode::Git {
+        eprintln!("warning: --create-base-worktree only applies in JJ mode; ignored.");
     }
 
     if outcome.vcs_mode == VcsMode::Jj {
@@ -106,10 +151,92 @@ pub fn handle_sync(ctx: &CommandCtx, legacy: bool) -> Result<()> {
         println!("\nLegacy sync finished (bookmark only). Prefer jj-task for new work.");
     }
 
+    print_summary(&summary);
+    if let Some(command) = verify {
+        print_verification(&outcome.created_worktree_paths(), command);
+    }
     println!("Sync complete.");
     Ok(())
 }
 
+/// Runs `--verify` in each new worktree; failures are reported, not returned.
+fn print_verification(paths: &[&str], command: &str) {
+    println!("\nVerifying new worktrees with `{command}`:");
+    if paths.is_empty() {
+        println!("  No worktrees created; nothing to verify.");
+        return;
+    }
+
+    let mut failed = 0;
+    for path in paths {
+        let result = verify_worktree(path, command);
+        match &result.detail {
+            None => println!("  ✓ {}", result.workspace_path),
+            Some(detail) => {
+                failed += 1;
+                println!("  ✗ {} ({})", result.workspace_path, detail);
+            }
+        }
+    }
+    println!(
+        "  Verify: {} passed, {} failed",
+        paths.len() - failed,
+        failed
+    );
+}
+
+fn print_summary(summary: &SyncSummary) {
+    println!("\nSummary:");
+    println!("  Branches created:  {}", summary.branches_created);
+    println!("  Branches reused:   {}", summary.branches_reused);
+    println!("  Worktrees created: {}", summary.worktrees_created);
+    println!("  Worktrees skipped: {}", summary.worktrees_skipped);
+    println!("  Repos missing:     {}", summary.repos_missing);
+    println!("  Failures:          {}", summary.failures);
+}
+
+/// Removes worktrees of done/cancelled TODOs, asking first unless `force`.
+fn prune_finished_worktrees(
+    ctx: &CommandCtx,
+    use_case: &SyncTaskUseCase,
+    task_id: i64,
+    force: bool,
+) -> Result<()> {
+    let candidates = use_case.prune_candidates(task_id)?;
+    if candidates.is_empty() {
+        println!("No worktrees to prune.\n");
+        return Ok(());
+    }
+
+    println!("Worktrees of finished TODOs:");
+    for candidate in &candidates {
+        println!(
+            "  TODO #{} ({}): {}",
+            candidate.todo_index,
+            candidate.todo_status.as_str(),
+            candidate.worktree.path
+        );
+    }
+
+    if !force && !ctx.confirm(&format!("Remove {} worktree(s)? [y/N]: ", candidates.len()))? {
+        println!("Skipped pruning.\n");
+        return Ok(());
+    }
+
+    let outcome = use_case.prune(candidates);
+    for removed in &outcome.removed {
+        println!("  ✓ Removed {}", removed.worktree.path);
+    }
+    for (candidate, detail) in &outcome.failed {
+        eprintln!(
+            "  ✗ Failed to remove {}: {}",
+            candidate.worktree.path, detail
+        );
+    }
+    println!();
+    Ok(())
+}
+
 fn print_edit_result(task_bookmark: &str, edit_ok: bool) {
     if edit_ok {
         println!("  ✓ Moved workspace to {}\n", task_bookmark);
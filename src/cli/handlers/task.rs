@@ -1,13 +1,32 @@
 use crate::cli::handlers::CommandCtx;
-use crate::models::TodoAddOptions;
+use crate::cli::{StatusSections, TaskCommands, TaskSetCommands};
+use crate::models::{
+    fill_template_vars, EstimateRollup, RepoLink, TaskListFilter, TaskOrder, TaskStatus,
+    TemplateVar, TodoAddOptions, TodoStatus,
+};
 use crate::services::{TaskService, TodoService, WorktreeService};
 use crate::use_cases::{
     ArchiveTaskStep, ArchiveTaskUseCase, CreateTodayTaskUseCase, GetTaskInfoUseCase,
+    MergeSourceFate, MergeTaskUseCase, TaskInfoSnapshot,
 };
-use crate::utils::{Result, TrackError};
-use chrono::Local;
+use crate::utils::{color, editor, format_timestamp, humanize_since, term, Result, TrackError};
+use chrono::{DateTime, Local, Utc};
+use dialoguer::Select;
 use prettytable::{format, Cell, Row, Table};
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Read, Write};
+use std::thread;
+use std::time::Duration;
+
+/// How `track new` changes the current task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewTaskSwitch {
+    /// Switch to the created task
+    Created,
+    /// Like `Created`, but switch to the task already holding the ticket instead of failing
+    CreatedOrExisting,
+    /// Leave the current task unchanged
+    Stay,
+}
 
 pub fn handle_new(
     ctx: &CommandCtx,
@@ -15,10 +34,29 @@ pub fn handle_new(
     description: Option<&str>,
     ticket: Option<&str>,
     ticket_url: Option<&str>,
-    template: Option<&str>,
+    template: Option<(&str, &[TemplateVar])>,
+    switch: NewTaskSwitch,
 ) -> Result<()> {
     let task_service = TaskService::new(ctx.db);
-    let task = task_service.create_task(name, description, ticket, ticket_url)?;
+    let created = if switch == NewTaskSwitch::Stay {
+        task_service.insert_task(name, description, ticket, ticket_url)
+    } else {
+        task_service.create_task(name, description, ticket, ticket_url)
+    };
+    let task = match created {
+        Err(TrackError::DuplicateTicket(ticket_id, existing_id))
+            if switch == NewTaskSwitch::CreatedOrExisting =>
+        {
+            let task = task_service.switch_task(existing_id)?;
+            println!(
+                "Ticket {} already belongs to task #{}: {} (reused)",
+                ticket_id, task.id, task.name
+            );
+            println!("Switched to task #{}", task.id);
+            return Ok(());
+        }
+        result => result?,
+    };
 
     println!("Created task #{}: {}", task.id, task.name);
     if let Some(ticket_id) = &task.ticket_id {
@@ -28,10 +66,12 @@ pub fn handle_new(
         }
         println!();
     }
-    println!("Switched to task #{}", task.id);
+    if switch != NewTaskSwitch::Stay {
+        println!("Switched to task #{}", task.id);
+    }
 
     // If template is specified, copy TODOs from template task
-    if let Some(template_ref) = template {
+    if let Some((template_ref, vars)) = template {
         let template_task_id = task_service.resolve_task_id(template_ref)?;
         let template_task = task_service.get_task(template_task_id)?;
 
@@ -50,28 +90,87 @@ pub fn handle_new(
                 template_task.name
             );
 
+            let mut unfilled: Vec<String> = Vec::new();
             for template_todo in &template_todos {
+                let (content, missing) = fill_template_vars(&template_todo.content, vars);
+                for name in missing {
+                    if !unfilled.contains(&name) {
+                        unfilled.push(name);
+                    }
+                }
                 todo_service.add_todo(
                     task.id,
-                    &template_todo.content,
+                    &content,
                     TodoAddOptions {
                         worktree_requested: template_todo.worktree_requested,
                         requires_workspace: template_todo.requires_workspace,
+                        estimate: template_todo.estimate,
+                        due_date: None,
                     },
                 )?;
             }
 
             println!("Successfully copied {} TODOs", template_todos.len());
+            if !unfilled.is_empty() {
+                eprintln!(
+                    "Warning: no value for template placeholder(s): {} (pass --var NAME=VALUE)",
+                    unfilled.join(", ")
+                );
+            }
         }
     }
 
     Ok(())
 }
 
-pub fn handle_list(ctx: &CommandCtx, include_archived: bool) -> Result<()> {
+/// Lists tasks, cutting the Ticket and Name columns to fit `width` when given.
+pub fn handle_list(
+    ctx: &CommandCtx,
+    filter: TaskListFilter,
+    order: TaskOrder,
+    width: Option<usize>,
+) -> Result<()> {
     let task_service = TaskService::new(ctx.db);
-    let tasks = task_service.list_tasks(include_archived)?;
+    let tasks = task_service.list_tasks_ordered(filter, order)?;
     let current_task_id = ctx.db.get_current_task_id()?;
+    let use_color = color::enabled();
+
+    let rows: Vec<_> = tasks
+        .iter()
+        .map(|task| {
+            let marker = if current_task_id == Some(task.id) {
+                "*"
+            } else {
+                " "
+            };
+            (
+                marker,
+                task.id.to_string(),
+                task.ticket_id.as_deref().unwrap_or("-"),
+                task.name.as_str(),
+                task.status,
+                format_timestamp(task.created_at, "%Y-%m-%d %H:%M:%S"),
+            )
+        })
+        .collect();
+
+    let (ticket_width, name_width) = match width {
+        Some(width) => {
+            let fixed = [
+                1,
+                term::column_width("ID", rows.iter().map(|row| row.1.as_str())),
+                term::column_width("Status", rows.iter().map(|row| row.4.as_str())),
+                term::column_width("Created", rows.iter().map(|row| row.5.as_str())),
+            ];
+            let flexible = [
+                term::column_width("Ticket", rows.iter().map(|row| row.2)),
+                term::column_width("Name", rows.iter().map(|row| row.3)),
+            ];
+            let fitted = term::fit_columns(width, &fixed, &flexible);
+            (fitted[0], fitted[1])
+        }
+        None => (usize::MAX, usize::MAX),
+    };
 
     let mut table = Table::new();
     table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
@@ -84,22 +183,14 @@ pub fn handle_list(ctx: &CommandCtx, include_archived: bool) -> Result<()> {
         Cell::new("Created"),
     ]));
 
-    for task in tasks {
-        let is_current = current_task_id == Some(task.id);
-        let marker = if is_current { "*" } else { " " };
-        let ticket = task.ticket_id.as_deref().unwrap_or("-");
-        let created = task
-            .created_at
-            .with_timezone(&Local)
-            .format("%Y-%m-%d %H:%M:%S");
-
+    for (marker, id, ticket, name, status, created) in &rows {
         table.add_row(Row::new(vec![
             Cell::new(marker),
-            Cell::new(&task.id.to_string()),
-            Cell::new(ticket),
-            Cell::new(&task.name),
-            Cell::new(task.status.as_str()),
-            Cell::new(&created.to_string()),
+            Cell::new(id),
+            Cell::new(&term::truncate(ticket, ticket_width)),
+            Cell::new(&term::truncate(name, name_width)),
+            Cell::new(&color::task_status(*status, use_color)),
+            Cell::new(created),
         ]));
     }
 
@@ -107,9 +198,18 @@ pub fn handle_list(ctx: &CommandCtx, include_archived: bool) -> Result<()> {
     Ok(())
 }
 
-pub fn handle_switch(ctx: &CommandCtx, task_ref: &str) -> Result<()> {
+pub fn handle_switch(ctx: &CommandCtx, task_ref: Option<&str>) -> Result<()> {
     let task_service = TaskService::new(ctx.db);
 
+    let task_ref = match task_ref {
+        Some(task_ref) => task_ref.to_string(),
+        None => match pick_task(ctx)? {
+            Some(task_id) => task_id.to_string(),
+            None => return Ok(()),
+        },
+    };
+    let task_ref = task_ref.as_str();
+
     // Check if the user wants to switch to today's task
     if task_ref.to_lowercase() == "today" {
         let task = CreateTodayTaskUseCase::new(ctx.db).get_or_create()?;
@@ -124,76 +224,190 @@ pub fn handle_switch(ctx: &CommandCtx, task_ref: &str) -> Result<()> {
     let task = task_service.switch_task(task_id)?;
 
     println!("Switched to task #{}: {}", task.id, task.name);
+    if task.status != TaskStatus::Active {
+        eprintln!(
+            "warning: task #{} is {}; run `track task status active` to resume it",
+            task.id,
+            task.status.as_str()
+        );
+    }
     Ok(())
 }
 
+/// Interactively select an active task; returns `None` when nothing was chosen.
+fn pick_task(ctx: &CommandCtx) -> Result<Option<i64>> {
+    let tasks = TaskService::new(ctx.db).list_tasks(TaskListFilter::Active)?;
+    if tasks.is_empty() {
+        println!("No active tasks. Create one with `track new <name>`.");
+        return Ok(None);
+    }
+
+    if !io::stdin().is_terminal() || !io::stderr().is_terminal() {
+        handle_list(
+            ctx,
+            TaskListFilter::Active,
+            TaskOrder::Created,
+            term::table_width(None, false),
+        )?;
+        println!("\nNo task given. Run `track switch <task_ref>` to switch.");
+        return Ok(None);
+    }
+
+    let current_task_id = ctx.db.get_current_task_id()?;
+    let items: Vec<String> = tasks
+        .iter()
+        .map(|task| match &task.ticket_id {
+            Some(ticket) => format!("#{} [{}] {}", task.id, ticket, task.name),
+            None => format!("#{} {}", task.id, task.name),
+        })
+        .collect();
+    let default = tasks
+        .iter()
+        .position(|task| Some(task.id) == current_task_id)
+        .unwrap_or(0);
+
+    let selection = Select::new()
+        .with_prompt("Switch to task")
+        .items(&items)
+        .default(default)
+        .interact_opt()
+        .map_err(|dialoguer::Error::IO(err)| TrackError::Io(err))?;
+
+    match selection {
+        Some(index) => Ok(Some(tasks[index].id)),
+        None => {
+            println!("Cancelled.");
+            Ok(None)
+        }
+    }
+}
+
 pub fn handle_info(
     ctx: &CommandCtx,
     task_ref: Option<String>,
     json: bool,
     all_scraps: bool,
+    sections: StatusSections,
+    absolute: bool,
 ) -> Result<()> {
     let task_service = TaskService::new(ctx.db);
     let task_id = match task_ref {
         Some(ref t_ref) => task_service.resolve_task_id(t_ref)?,
-        None => ctx
-            .db
-            .get_current_task_id()?
-            .ok_or(TrackError::NoActiveTask)?,
+        None => {
+            let task_id = ctx
+                .db
+                .get_current_task_id()?
+                .ok_or(TrackError::NoActiveTask)?;
+            // app_state can outlive its task (archived, or the row edited away)
+            let available = match task_service.get_task(task_id) {
+                Ok(task) => task.status != TaskStatus::Archived,
+                Err(TrackError::TaskNotFound(_)) => false,
+                Err(e) => return Err(e),
+            };
+            if !available {
+                ctx.db.clear_current_task_id()?;
+                // Keep stdout parseable: JSON callers get the error instead of prose
+                if json {
+                    return Err(TrackError::NoActiveTask);
+                }
+                println!("Current task is no longer available; run `track switch`");
+                return Ok(());
+            }
+            task_id
+        }
     };
 
     let info = GetTaskInfoUseCase::new(ctx.db);
     let snapshot = info.load(task_id)?;
-    let worktree_service = WorktreeService::new(ctx.db);
 
     if json {
-        let output = info.to_cli_json(&snapshot)?;
+        let output = info.status_document(&snapshot);
         let json = serde_json::to_string_pretty(&output)
             .map_err(|e| TrackError::SerializationFailed(e.to_string()))?;
         println!("{json}");
         return Ok(());
     }
 
+    let mut out = io::stdout().lock();
+    write_status_markdown(&mut out, ctx, &snapshot, all_scraps, sections, absolute)
+}
+
+/// `kind: url`, followed by the forge state once `track repo sync-links` recorded one.
+fn format_repo_link(link: &RepoLink) -> String {
+    match &link.state {
+        Some(state) => format!("{}: {} [{}]", link.kind, link.url, state),
+        None => format!("{}: {}", link.kind, link.url),
+    }
+}
+
+/// Renders the markdown view of `track status` for one task.
+///
+/// Scrap and TODO completion times read as `3h ago` for the past week unless
+/// `absolute` is set.
+pub(crate) fn write_status_markdown(
+    out: &mut impl Write,
+    ctx: &CommandCtx,
+    snapshot: &TaskInfoSnapshot,
+    all_scraps: bool,
+    sections: StatusSections,
+    absolute: bool,
+) -> Result<()> {
+    let worktree_service = WorktreeService::new(ctx.db);
     let task = &snapshot.task;
     let todos = &snapshot.todos;
     let links = &snapshot.links;
     let scraps = &snapshot.scraps;
     let worktrees = &snapshot.worktrees;
     let repos = &snapshot.repos;
-    let base_branch = GetTaskInfoUseCase::base_bookmark(&snapshot);
+    let base_branch = GetTaskInfoUseCase::base_bookmark(snapshot);
+    let now = Utc::now();
+    let timestamp = |at: DateTime<Utc>| {
+        if absolute {
+            format_timestamp(at, "%Y-%m-%d %H:%M")
+        } else {
+            humanize_since(at, now)
+        }
+    };
 
-    println!("# Task #{}: {}", task.id, task.name);
-    println!();
+    writeln!(out, "# Task #{}: {}", task.id, task.name)?;
+    writeln!(out)?;
 
-    let created = task
-        .created_at
-        .with_timezone(&Local)
-        .format("%Y-%m-%d %H:%M:%S");
-    println!("**Created:** {created}");
+    let created = format_timestamp(task.created_at, "%Y-%m-%d %H:%M:%S");
+    writeln!(out, "**Created:** {created}")?;
 
     if let Some(ticket_id) = &task.ticket_id {
         if let Some(url) = &task.ticket_url {
-            println!("**Ticket:** [{}]({url})", ticket_id);
+            writeln!(out, "**Ticket:** [{}]({url})", ticket_id)?;
         } else {
-            println!("**Ticket:** {ticket_id}");
+            writeln!(out, "**Ticket:** {ticket_id}")?;
         }
     }
 
-    println!("**Base Bookmark:** `{base_branch}`");
-    println!();
+    writeln!(out, "**Base Bookmark:** `{base_branch}`")?;
+    writeln!(out)?;
 
     // Description
-    if let Some(desc) = &task.description {
-        println!("## Description");
-        println!();
-        println!("{}", desc);
-        println!();
+    let description = task.description.as_ref().filter(|_| sections.description);
+    if let Some(desc) = description {
+        writeln!(out, "## Description")?;
+        writeln!(out)?;
+        writeln!(out, "{}", desc)?;
+        writeln!(out)?;
     }
 
     // TODOs
-    if !todos.is_empty() {
-        println!("## TODOs");
-        println!();
+    if sections.todos && !todos.is_empty() {
+        writeln!(out, "## TODOs")?;
+        writeln!(out)?;
+        if let Some(rollup) = EstimateRollup::from_todos(todos) {
+            writeln!(
+                out,
+                "**Estimate:** {} total, {} remaining",
+                rollup.total, rollup.remaining
+            )?;
+            writeln!(out)?;
+        }
+        let today = Local::now().date_naive();
         for todo in todos {
             let marker = match todo.status.as_str() {
                 "done" => "x",
@@ -208,132 +422,398 @@ pub fn handle_info(
                 "cancelled" => "~~",
                 _ => "",
             };
+            let estimate = todo
+                .estimate
+                .map(|estimate| format!(" (est: {estimate})"))
+                .unwrap_or_default();
+            let due = match todo.due_date {
+                Some(due) if todo.is_overdue(today) => format!(" **(overdue: {due})**"),
+                Some(due) if todo.status == TodoStatus::Pending => format!(" (due: {due})"),
+                _ => String::new(),
+            };
             if let Some(completed_at) = todo.completed_at {
-                let done_time = completed_at.with_timezone(&Local).format("%Y-%m-%d %H:%M");
-                println!(
-                    "- [{}] **[{}]**{} {}{} (done: {})",
-                    marker, todo.task_index, status_indicator, todo.content, status_end, done_time
-                );
+                let done_time = timestamp(completed_at);
+                writeln!(
+                    out,
+                    "- [{}] **[{}]**{} {}{}{} (done: {})",
+                    marker,
+                    todo.task_index,
+                    status_indicator,
+                    todo.content,
+                    status_end,
+                    estimate,
+                    done_time
+                )?;
             } else {
-                println!(
-                    "- [{}] **[{}]**{} {}{}",
-                    marker, todo.task_index, status_indicator, todo.content, status_end
-                );
+                writeln!(
+                    out,
+                    "- [{}] **[{}]**{} {}{}{}{}",
+                    marker,
+                    todo.task_index,
+                    status_indicator,
+                    todo.content,
+                    status_end,
+                    estimate,
+                    due
+                )?;
             }
 
             // Find and display worktree for this TODO
             for worktree in worktrees {
-                if worktree.todo_id == Some(todo.id) {
-                    println!("  - **Workspace:**");
-                    println!("    - **Path:** `{}`", worktree.path);
-                    println!("    - **Bookmark:** `{}`", worktree.branch);
+                if sections.worktrees && worktree.todo_id == Some(todo.id) {
+                    writeln!(out, "  - **Workspace:**")?;
+                    writeln!(out, "    - **Path:** `{}`", worktree.path)?;
+                    writeln!(out, "    - **Bookmark:** `{}`", worktree.branch)?;
 
                     let repo_links = worktree_service.list_repo_links(worktree.id)?;
                     if !repo_links.is_empty() {
-                        println!("    - **Repository Links:**");
+                        writeln!(out, "    - **Repository Links:**")?;
                         for link in repo_links {
-                            println!("      - {}: {}", link.kind, link.url);
+                            writeln!(out, "      - {}", format_repo_link(&link))?;
                         }
                     }
                 }
             }
+            if sections.worktrees {
+                for worktree in &snapshot.worktree_history {
+                    if worktree.todo_id == Some(todo.id) {
+                        writeln!(
+                            out,
+                            "  - **History:** {} bookmark `{}`",
+                            worktree.status.as_str(),
+                            worktree.branch
+                        )?;
+                    }
+                }
+            }
         }
-        println!();
+        writeln!(out)?;
     }
 
     // Links
-    if !links.is_empty() {
-        println!("## Links");
-        println!();
+    if sections.links && !links.is_empty() {
+        writeln!(out, "## Links")?;
+        writeln!(out)?;
         for link in links {
-            println!("- [{}]({})", link.title, link.url);
+            writeln!(out, "- [{}]({})", link.title, link.url)?;
         }
-        println!();
+        writeln!(out)?;
     }
 
     // Repositories
-    if !repos.is_empty() {
-        println!("## Repositories");
-        println!();
+    if sections.repos && !repos.is_empty() {
+        writeln!(out, "## Repositories")?;
+        writeln!(out)?;
         for repo in repos {
-            print!("- `{}`", repo.repo_path);
+            write!(out, "- **[{}]** `{}`", repo.task_index, repo.repo_path)?;
 
             // Display base branch and commit hash if available
             if let Some(ref base_branch) = repo.base_branch {
                 if let Some(ref base_hash) = repo.base_commit_hash {
                     // Show both branch and short hash
                     let short_hash = &base_hash[..std::cmp::min(8, base_hash.len())];
-                    print!(" (base: {} @ {})", base_branch, short_hash);
+                    write!(out, " (base: {} @ {})", base_branch, short_hash)?;
                 } else {
                     // Show only branch
-                    print!(" (base: {})", base_branch);
+                    write!(out, " (base: {})", base_branch)?;
                 }
             } else if let Some(ref base_hash) = repo.base_commit_hash {
                 // Show only hash
                 let short_hash = &base_hash[..std::cmp::min(8, base_hash.len())];
-                print!(" (base: {})", short_hash);
+                write!(out, " (base: {})", short_hash)?;
             }
 
-            println!();
+            writeln!(out)?;
         }
-        println!();
+        writeln!(out)?;
     }
 
     // Scraps
-    if !scraps.is_empty() {
+    if sections.scraps && !scraps.is_empty() {
         if all_scraps {
-            println!("## Scraps");
+            writeln!(out, "## Scraps")?;
         } else {
-            println!("## Recent Scraps");
+            writeln!(out, "## Recent Scraps")?;
         }
-        println!();
+        writeln!(out)?;
 
-        let count = if all_scraps { scraps.len() } else { 5 };
+        // Pinned scraps are listed first and always shown; the limit applies to the rest.
+        let pinned = scraps.iter().take_while(|scrap| scrap.pinned).count();
+        let count = if all_scraps { scraps.len() } else { pinned + 5 };
 
         for scrap in scraps.iter().take(count) {
-            let timestamp = scrap.created_at.with_timezone(&Local).format("%H:%M");
-            println!("### [{}]", timestamp);
-            println!();
+            if scrap.pinned {
+                writeln!(out, "### [{}] (pinned)", timestamp(scrap.created_at))?;
+            } else {
+                writeln!(out, "### [{}]", timestamp(scrap.created_at))?;
+            }
+            writeln!(out)?;
             // Wrap content in blockquote to prevent markdown heading conflicts
             for line in scrap.content.lines() {
                 if line.is_empty() {
-                    println!(">");
+                    writeln!(out, ">")?;
                 } else {
-                    println!("> {}", line);
+                    writeln!(out, "> {}", line)?;
                 }
             }
-            println!();
+            writeln!(out)?;
         }
-        println!();
+        writeln!(out)?;
     }
 
     // Worktrees (only those not associated with a TODO, e.g., base worktrees)
-    let orphan_worktrees = GetTaskInfoUseCase::orphan_worktrees(&snapshot);
+    let orphan_worktrees = GetTaskInfoUseCase::orphan_worktrees(snapshot);
 
-    if !orphan_worktrees.is_empty() {
-        println!("## Workspaces");
-        println!();
+    if sections.worktrees && !orphan_worktrees.is_empty() {
+        writeln!(out, "## Workspaces")?;
+        writeln!(out)?;
         for worktree in orphan_worktrees {
-            println!("### Workspace #{}", worktree.id);
-            println!();
-            println!("- **Path:** `{}`", worktree.path);
-            println!("- **Bookmark:** `{}`", worktree.branch);
+            writeln!(out, "### Workspace #{}", worktree.id)?;
+            writeln!(out)?;
+            writeln!(out, "- **Path:** `{}`", worktree.path)?;
+            writeln!(out, "- **Bookmark:** `{}`", worktree.branch)?;
 
             let repo_links = worktree_service.list_repo_links(worktree.id)?;
             if !repo_links.is_empty() {
-                println!("- **Repository Links:**");
+                writeln!(out, "- **Repository Links:**")?;
                 for link in repo_links {
-                    println!("  - {}: {}", link.kind, link.url);
+                    writeln!(out, "  - {}", format_repo_link(&link))?;
                 }
             }
+            writeln!(out)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Redraws `track status` whenever the database revisions or current task change,
+/// and on every tick while relative times ("5m ago") are shown so they stay current.
+///
+/// Polls every `interval_secs` seconds until interrupted (Ctrl+C).
+pub fn handle_status_watch(
+    ctx: &CommandCtx,
+    task_ref: Option<String>,
+    all_scraps: bool,
+    sections: StatusSections,
+    absolute: bool,
+    interval_secs: u64,
+) -> Result<()> {
+    let interval = Duration::from_secs(interval_secs.max(1));
+    let mut last_seen = None;
+
+    loop {
+        let current = (ctx.db.get_current_task_id()?, ctx.db.get_all_revs()?);
+        if !absolute || last_seen.as_ref() != Some(&current) {
+            // Clear the screen and move the cursor home before redrawing.
+            print!("\x1b[2J\x1b[H");
+            if let Err(err) =
+                handle_info(ctx, task_ref.clone(), false, all_scraps, sections, absolute)
+            {
+                println!("Error: {err}");
+            }
             println!();
+            println!(
+                "Watching for changes every {}s (Ctrl+C to stop)...",
+                interval.as_secs()
+            );
+            io::stdout().flush()?;
+            last_seen = Some(current);
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+pub fn handle_task(ctx: &CommandCtx, command: TaskCommands) -> Result<()> {
+    match command {
+        TaskCommands::Set(TaskSetCommands::AutoLink { value, task }) => {
+            let task_id = match task {
+                Some(id) => id,
+                None => ctx
+                    .db
+                    .get_current_task_id()?
+                    .ok_or(TrackError::NoActiveTask)?,
+            };
+            TaskService::new(ctx.db).set_auto_link(task_id, value.is_on())?;
+            println!(
+                "auto-link {} for task #{}",
+                if value.is_on() { "enabled" } else { "disabled" },
+                task_id
+            );
+        }
+        TaskCommands::Set(TaskSetCommands::AutoWorkspace { value, task }) => {
+            let task_id = match task {
+                Some(id) => id,
+                None => ctx
+                    .db
+                    .get_current_task_id()?
+                    .ok_or(TrackError::NoActiveTask)?,
+            };
+            TaskService::new(ctx.db).set_auto_workspace(task_id, value.is_on())?;
+            println!(
+                "auto-workspace {} for task #{}",
+                if value.is_on() { "enabled" } else { "disabled" },
+                task_id
+            );
+        }
+        TaskCommands::Status { state, task } => {
+            let task_id = match task {
+                Some(id) => id,
+                None => ctx
+                    .db
+                    .get_current_task_id()?
+                    .ok_or(TrackError::NoActiveTask)?,
+            };
+            let task = TaskService::new(ctx.db).set_status(task_id, state.status())?;
+            println!(
+                "Task #{} is now {}: {}",
+                task.id,
+                task.status.as_str(),
+                task.name
+            );
+        }
+        TaskCommands::MoveTodo {
+            id,
+            to_task,
+            keep_worktree,
+        } => {
+            let current_task_id = ctx
+                .db
+                .get_current_task_id()?
+                .ok_or(TrackError::NoActiveTask)?;
+            let task_service = TaskService::new(ctx.db);
+            let dest_task_id = task_service.resolve_task_id(&to_task)?;
+            let todo_service = TodoService::new(ctx.db);
+            let todo = todo_service.get_todo_by_index(current_task_id, id)?;
+
+            let worktrees: Vec<_> = WorktreeService::new(ctx.db)
+                .list_worktrees(current_task_id)?
+                .into_iter()
+                .filter(|wt| wt.todo_id == Some(todo.id))
+                .collect();
+
+            let moved = todo_service.move_to_task(todo.id, dest_task_id, keep_worktree)?;
+            println!(
+                "Moved TODO #{} to task #{} as TODO #{}: {}",
+                id, dest_task_id, moved.task_index, moved.content
+            );
+
+            for wt in &worktrees {
+                if keep_worktree {
+                    eprintln!(
+                        "Warning: worktree {} moved with the TODO; its branch '{}' keeps the old task's naming.",
+                        wt.path, wt.branch
+                    );
+                } else {
+                    eprintln!(
+                        "Warning: worktree {} stays with task #{} and is no longer linked to the TODO (use --keep-worktree to move it).",
+                        wt.path, current_task_id
+                    );
+                }
+            }
+        }
+        TaskCommands::Merge {
+            source_ref,
+            into,
+            delete,
+        } => {
+            let task_service = TaskService::new(ctx.db);
+            let source_id = task_service.resolve_task_id(&source_ref)?;
+            let dest_id = task_service.resolve_task_id(&into)?;
+            let fate = if delete {
+                MergeSourceFate::Delete
+            } else {
+                MergeSourceFate::Archive
+            };
+            let outcome = MergeTaskUseCase::new(ctx.db).execute(source_id, dest_id, fate)?;
+
+            if let Some(ticket) = &outcome.dropped_ticket {
+                eprintln!(
+                    "Warning: both tasks have tickets; kept {} and dropped {}.",
+                    outcome.dest.ticket_id.as_deref().unwrap_or_default(),
+                    ticket
+                );
+            }
+            for path in &outcome.skipped_repos {
+                eprintln!(
+                    "Warning: repository {} was already registered on task #{}.",
+                    path, dest_id
+                );
+            }
+            if outcome.worktrees > 0 {
+                eprintln!(
+                    "Warning: {} TODO worktree(s) moved with their TODOs; their branches keep the old task's naming.",
+                    outcome.worktrees
+                );
+            }
+            for (id, path) in &outcome.kept_worktrees {
+                eprintln!(
+                    "Warning: worktree #{} ({}) stays with archived task #{}.",
+                    id, path, source_id
+                );
+            }
+            println!(
+                "Merged task #{} into #{}: {} TODO(s), {} link(s), {} scrap(s), {} repo(s)",
+                source_id, dest_id, outcome.todos, outcome.links, outcome.scraps, outcome.repos
+            );
+            let fate = match fate {
+                MergeSourceFate::Archive => "Archived",
+                MergeSourceFate::Delete => "Deleted",
+            };
+            println!("{} task #{}: {}", fate, source_id, outcome.source.name);
+        }
+        TaskCommands::Touch { task_ref } => {
+            let task_service = TaskService::new(ctx.db);
+            let task_id = match task_ref {
+                Some(task_ref) => task_service.resolve_task_id(&task_ref)?,
+                None => ctx
+                    .db
+                    .get_current_task_id()?
+                    .ok_or(TrackError::NoActiveTask)?,
+            };
+            let task = task_service.touch_task(task_id)?;
+            println!("Touched task #{}: {}", task.id, task.name);
         }
     }
 
     Ok(())
 }
 
-pub fn handle_desc(ctx: &CommandCtx, description: Option<&str>, task: Option<i64>) -> Result<()> {
+/// Replaces the description with the text saved in `$EDITOR`, or read from
+/// stdin when it is not a terminal. An empty result leaves it untouched.
+fn edit_desc(task_service: &TaskService, task_id: i64) -> Result<()> {
+    let current = task_service
+        .get_task(task_id)?
+        .description
+        .unwrap_or_default();
+    let edited = if io::stdin().is_terminal() {
+        editor::edit_text(&current, "DESCRIPTION.md")?
+    } else {
+        let mut buf = String::new();
+        io::stdin().read_to_string(&mut buf)?;
+        buf
+    };
+
+    let edited = edited.trim_end();
+    if edited.trim().is_empty() {
+        println!("Empty description; nothing saved for task #{}", task_id);
+    } else if edited == current.trim_end() {
+        println!("Description unchanged for task #{}", task_id);
+    } else {
+        task_service.set_description(task_id, edited)?;
+        println!("Updated description for task #{}", task_id);
+    }
+    Ok(())
+}
+
+pub fn handle_desc(
+    ctx: &CommandCtx,
+    description: Option<&str>,
+    append: Option<&str>,
+    editor: bool,
+    task: Option<i64>,
+) -> Result<()> {
     let task_id = match task {
         Some(id) => id,
         None => ctx
@@ -344,6 +824,16 @@ pub fn handle_desc(ctx: &CommandCtx, description: Option<&str>, task: Option<i64
 
     let task_service = TaskService::new(ctx.db);
 
+    if let Some(text) = append {
+        task_service.append_description(task_id, text)?;
+        println!("Appended to description for task #{}", task_id);
+        return Ok(());
+    }
+
+    if editor {
+        return edit_desc(&task_service, task_id);
+    }
+
     match description {
         Some(desc) => {
             // Set mode
@@ -383,7 +873,7 @@ pub fn handle_ticket(
     };
 
     let task_service = TaskService::new(ctx.db);
-    task_service.link_ticket(task_id, ticket_id, url)?;
+    let ticket_id = task_service.link_ticket(task_id, ticket_id, url)?;
 
     println!("Linked ticket {} to task #{}", ticket_id, task_id);
     println!("URL: {}", url);
@@ -391,6 +881,21 @@ pub fn handle_ticket(
     Ok(())
 }
 
+pub fn handle_ticket_url(ctx: &CommandCtx, url: &str, task: Option<i64>) -> Result<()> {
+    let task_id = match task {
+        Some(id) => id,
+        None => ctx
+            .db
+            .get_current_task_id()?
+            .ok_or(TrackError::NoActiveTask)?,
+    };
+
+    TaskService::new(ctx.db).set_ticket_url(task_id, url)?;
+    println!("Updated ticket URL of task #{}: {}", task_id, url);
+
+    Ok(())
+}
+
 pub fn handle_archive(ctx: &CommandCtx, task_ref: Option<&str>, force: bool) -> Result<()> {
     let use_case = ArchiveTaskUseCase::new(ctx.db);
     let task_id = use_case.resolve_task_id(task_ref)?;
@@ -402,12 +907,8 @@ pub fn handle_archive(ctx: &CommandCtx, task_ref: Option<&str>, force: bool) ->
             for line in &view.warning_lines {
                 println!("{line}");
             }
-            print!("{}", view.prompt);
-            io::stdout().flush()?;
-            let mut input = String::new();
-            io::stdin().read_line(&mut input)?;
 
-            if !matches!(input.trim().to_lowercase().as_str(), "y" | "yes") {
+            if !ctx.confirm(&view.prompt)? {
                 println!("Cancelled.");
                 return Ok(());
             }
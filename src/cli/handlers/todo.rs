@@ -1,14 +1,24 @@
 use crate::cli::handlers::CommandCtx;
 use crate::cli::TodoCommands;
-use crate::models::{TodoAction, TodoAddOptions, TodoStatus};
-use crate::services::TodoService;
+use crate::models::{Todo, TodoAction, TodoAddOptions, TodoPosition, TodoStatus};
+use crate::services::{
+    ForgeClient, ForgeRef, LinkService, NotifyService, ScrapService, TaskService, TodoCounts,
+    TodoService, WorktreeService,
+};
 use crate::use_cases::{
-    ApplyTodoActionUseCase, CompleteTodoUseCase, DeleteTodoStep, DeleteTodoUseCase,
-    TodoWorkspaceRequest, TodoWorkspaceUseCase,
+    ApplyTodoActionUseCase, CompleteTodoOptions, CompleteTodoOutcome, CompleteTodoUseCase,
+    DeleteTodoStep, DeleteTodoUseCase, TodoWorkspaceRequest, TodoWorkspaceUseCase,
+};
+use crate::utils::color::{self, Style};
+use crate::utils::{
+    extract_urls, format_timestamp, parse_due_date, parse_since, term, Result, TrackError,
 };
-use crate::utils::{Result, TrackError};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 use prettytable::{format, Cell, Row, Table};
-use std::io::{self, Write};
+use std::collections::HashSet;
+
+/// Most TODOs `todo set-status` changes without `--force`.
+const BULK_STATUS_LIMIT: usize = 5;
 
 pub fn handle_todo(ctx: &CommandCtx, command: TodoCommands) -> Result<()> {
     let current_task_id = ctx
@@ -22,37 +32,127 @@ pub fn handle_todo(ctx: &CommandCtx, command: TodoCommands) -> Result<()> {
             text,
             worktree,
             no_workspace,
+            workspace,
+            top,
+            after_current,
+            links,
+            estimate,
+            due,
+            auto_link,
+            no_auto_link,
         } => {
             if worktree {
                 return Err(TrackError::WorktreeFlagRemoved);
             }
-            let options = TodoAddOptions::from_flags(false, no_workspace);
-            let todo = todo_service.add_todo(current_task_id, &text, options)?;
+            let due_date = due
+                .map(|due| parse_due_date(&due, Local::now().date_naive()))
+                .transpose()?;
+            // Validate every URL up front so a bad link doesn't leave a half-added TODO.
+            let link_service = LinkService::new(ctx.db);
+            for url in &links {
+                link_service.validate_url(url)?;
+            }
+            // Precedence: explicit flag > task auto-workspace default > on
+            let flag = (workspace || no_workspace).then_some(workspace);
+            let requires_workspace =
+                TaskService::new(ctx.db).auto_workspace(current_task_id, flag)?;
+            let options = TodoAddOptions::from_flags(false, !requires_workspace)
+                .with_estimate(estimate)
+                .with_due_date(due_date);
+            let position = TodoPosition::from_flags(top, after_current);
+            let todo = todo_service.add_todo_at(current_task_id, &text, options, position)?;
             println!("Added TODO #{}: {}", todo.task_index, todo.content);
+            if let Some(due) = todo.due_date {
+                println!("Due {due}");
+            }
 
-            if no_workspace {
+            if !requires_workspace {
                 println!("No jj-task/git workspace required for this TODO");
             }
+
+            for url in &links {
+                let link = link_service.add_link(current_task_id, url, None)?;
+                println!("Added link #{}: {}", link.task_index, link.title);
+            }
+
+            let flag = (auto_link || no_auto_link).then_some(auto_link);
+            if TaskService::new(ctx.db).auto_link(current_task_id, flag)? {
+                let existing: HashSet<String> = link_service
+                    .list_links(current_task_id)?
+                    .into_iter()
+                    .map(|link| link.url)
+                    .collect();
+                for url in extract_urls(&todo.content) {
+                    if existing.contains(url) {
+                        continue;
+                    }
+                    let link = link_service.add_link(current_task_id, url, None)?;
+                    println!("Added link #{}: {}", link.task_index, link.title);
+                }
+            }
         }
-        TodoCommands::List => {
-            let todos = todo_service.list_todos(current_task_id)?;
+        TodoCommands::List {
+            completed_since,
+            worktree_only,
+            limit,
+            offset,
+            width,
+            no_truncate,
+        } => {
+            let width = term::table_width(width, no_truncate);
+            if let Some(since) = completed_since {
+                return print_completed_since(&todo_service, current_task_id, &since, width);
+            }
+            if worktree_only {
+                return print_worktree_todos(ctx, &todo_service, current_task_id);
+            }
+
+            let counts = todo_service.count_todos(current_task_id)?;
+            if counts.total() == 0 {
+                println!("No TODOs yet. Add one with `track todo add <text>`.");
+                return Ok(());
+            }
+            println!("{}", status_summary(&counts));
+            let todos = todo_service.list_todos_page(current_task_id, limit, offset)?;
+            let shown = todos.len();
+
+            let use_color = color::enabled();
+            let today = Local::now().date_naive();
+            let content_width = width.map_or(usize::MAX, |width| {
+                let ids: Vec<String> = todos.iter().map(|t| t.task_index.to_string()).collect();
+                let dues: Vec<String> = todos.iter().map(|t| due_label(t, today, false)).collect();
+                let fixed = [
+                    term::column_width("ID", ids.iter().map(String::as_str)),
+                    term::column_width("Status", todos.iter().map(|t| t.status.as_str())),
+                    term::column_width("Due", dues.iter().map(String::as_str)),
+                ];
+                let content =
+                    term::column_width("Content", todos.iter().map(|t| t.content.as_str()));
+                term::fit_columns(width, &fixed, &[content])[0]
+            });
+
             let mut table = Table::new();
             table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
             table.set_titles(Row::new(vec![
                 Cell::new("ID"),
                 Cell::new("Status"),
+                Cell::new("Due"),
                 Cell::new("Content"),
             ]));
 
             for todo in todos {
                 table.add_row(Row::new(vec![
                     Cell::new(&todo.task_index.to_string()),
-                    Cell::new(todo.status.as_str()),
-                    Cell::new(&todo.content),
+                    Cell::new(&color::todo_status(todo.status, use_color)),
+                    Cell::new(&due_label(&todo, today, use_color)),
+                    Cell::new(&term::truncate(&todo.content, content_width)),
                 ]));
             }
 
             table.printstd();
+            if limit.is_some() || offset > 0 {
+                println!("{}", page_footer(offset, shown, counts.total()));
+            }
         }
         TodoCommands::Update { id, status } => {
             let todo = todo_service.get_todo_by_index(current_task_id, id)?;
@@ -64,21 +164,105 @@ pub fn handle_todo(ctx: &CommandCtx, command: TodoCommands) -> Result<()> {
             }
             println!("Updated TODO #{} status to '{}'", id, status);
         }
-        TodoCommands::Done { id } => {
-            let outcome = CompleteTodoUseCase::new(ctx.db).execute(current_task_id, id)?;
-            if let Some(branch) = outcome.merged_bookmark {
+        TodoCommands::SetStatus {
+            status,
+            matching,
+            all_pending: _,
+            force,
+        } => {
+            let todos = todo_service.find_pending_todos(current_task_id, matching.as_deref())?;
+            if todos.is_empty() {
+                println!("No pending TODOs match");
+                return Ok(());
+            }
+            if todos.len() > BULK_STATUS_LIMIT && !force {
+                return Err(TrackError::BulkStatusRequiresForce {
+                    count: todos.len(),
+                    limit: BULK_STATUS_LIMIT,
+                });
+            }
+
+            let status = status.status();
+            if status == TodoStatus::Done {
+                // Worktrees must be merged by `todo done`, one TODO at a time
+                let worktrees = WorktreeService::new(ctx.db).list_worktrees(current_task_id)?;
+                let with_worktree: Vec<String> = todos
+                    .iter()
+                    .filter(|todo| worktrees.iter().any(|wt| wt.todo_id == Some(todo.id)))
+                    .map(|todo| format!("#{}", todo.task_index))
+                    .collect();
+                if !with_worktree.is_empty() {
+                    return Err(TrackError::BulkDoneHasWorktrees(with_worktree.join(", ")));
+                }
+            }
+
+            let ids: Vec<i64> = todos.iter().map(|todo| todo.id).collect();
+            let changed = todo_service.close_todos(current_task_id, &ids, status)?;
+            for todo in &todos {
+                println!("  [{}] {}", todo.task_index, todo.content_text());
+            }
+            println!("Marked {} TODO(s) as {}", changed, status.as_str());
+        }
+        TodoCommands::Due { id, date, clear } => {
+            let todo = todo_service.get_todo_by_index(current_task_id, id)?;
+            let due_date = match date {
+                Some(date) if !clear => Some(parse_due_date(&date, Local::now().date_naive())?),
+                _ => None,
+            };
+            todo_service.set_due_date(todo.id, due_date)?;
+            match due_date {
+                Some(due) => println!("TODO #{} is due {}", id, due),
+                None => println!("Cleared due date of TODO #{}", id),
+            }
+        }
+        TodoCommands::Done {
+            id,
+            commit,
+            close_issue,
+            push,
+            note,
+        } => {
+            let outcome = CompleteTodoUseCase::new(ctx.db).execute_with_options(
+                current_task_id,
+                id,
+                CompleteTodoOptions {
+                    commit_message: commit.as_deref(),
+                    note: note.as_deref(),
+                },
+            )?;
+            if let Some(branch) = &outcome.merged_bookmark {
                 println!(
                     "Rebased and removed workspace for TODO #{} (bookmark: {}).",
                     id, branch
                 );
             }
             println!("Marked TODO #{} as done.", id);
+            if let Some(scrap_index) = outcome.note_scrap_id {
+                println!("Recorded closing note as scrap #{}.", scrap_index);
+            }
+
+            // Best-effort: a failed notification must not undo a completed TODO.
+            if let Err(err) =
+                notify_todo_done(ctx, current_task_id, id, outcome.merged_bookmark.as_deref())
+            {
+                eprintln!("warning: webhook notification failed: {err}");
+            }
+
+            if push {
+                push_after_done(ctx, current_task_id, &outcome);
+            }
+
+            if close_issue {
+                close_ticket_issue(ctx, current_task_id)?;
+            }
         }
         TodoCommands::Workspace {
             id,
             recreate,
             force,
             all,
+            strict,
+            branch,
         } => {
             let outcome = TodoWorkspaceUseCase::new(ctx.db).execute(
                 current_task_id,
@@ -87,6 +271,8 @@ pub fn handle_todo(ctx: &CommandCtx, command: TodoCommands) -> Result<()> {
                     recreate,
                     force,
                     all_repos: all,
+                    strict,
+                    branch: branch.as_deref(),
                 },
             )?;
 
@@ -95,9 +281,21 @@ pub fn handle_todo(ctx: &CommandCtx, command: TodoCommands) -> Result<()> {
             }
 
             if all {
-                for path in outcome.paths {
+                for path in &outcome.paths {
                     println!("{path}");
                 }
+                if !outcome.failures.is_empty() {
+                    for (repo_path, err) in &outcome.failures {
+                        eprintln!("error: {repo_path}: {err}");
+                    }
+                    let failed = outcome.failures.len();
+                    eprintln!(
+                        "{} workspace(s) ready, {} failed",
+                        outcome.paths.len(),
+                        failed
+                    );
+                    return Err(TrackError::WorkspaceSetupIncomplete(failed));
+                }
             } else {
                 println!("{}", outcome.paths[0]);
                 if outcome.paths.len() > 1 {
@@ -113,13 +311,7 @@ pub fn handle_todo(ctx: &CommandCtx, command: TodoCommands) -> Result<()> {
             let outcome = match use_case.run(current_task_id, id, force)? {
                 DeleteTodoStep::Completed(outcome) => outcome,
                 DeleteTodoStep::NeedsConfirmation(prompt) => {
-                    let view = prompt.view();
-                    print!("{}", view.prompt);
-                    io::stdout().flush()?;
-                    let mut input = String::new();
-                    io::stdin().read_line(&mut input)?;
-
-                    if !matches!(input.trim().to_lowercase().as_str(), "y" | "yes") {
+                    if !ctx.confirm(&prompt.view().prompt)? {
                         println!("Cancelled.");
                         return Ok(());
                     }
@@ -134,7 +326,261 @@ pub fn handle_todo(ctx: &CommandCtx, command: TodoCommands) -> Result<()> {
             todo_service.move_to_next(current_task_id, id)?;
             println!("Moved TODO #{} to the front (next todo to work on)", id);
         }
+        TodoCommands::Show { id } => {
+            let todo = todo_service.get_todo_by_index(current_task_id, id)?;
+            print_todo_details(ctx, &todo)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// One-line count summary printed above `todo list`, e.g. "3 pending, 5 done, 1 cancelled".
+fn status_summary(counts: &TodoCounts) -> String {
+    format!(
+        "{} pending, {} done, {} cancelled",
+        counts.pending, counts.done, counts.cancelled
+    )
+}
+
+/// Footer under a paginated `todo list`, e.g. "Showing 21–40 of 130 TODOs".
+fn page_footer(offset: usize, shown: usize, total: usize) -> String {
+    if shown == 0 {
+        format!("Showing 0 of {total} TODOs (offset {offset} is past the end)")
+    } else {
+        format!(
+            "Showing {}–{} of {} TODOs",
+            offset + 1,
+            offset + shown,
+            total
+        )
+    }
+}
+
+/// Due column of `todo list`: the date, flagged in red once a pending TODO is overdue.
+fn due_label(todo: &Todo, today: NaiveDate, use_color: bool) -> String {
+    match todo.due_date {
+        Some(due) if todo.is_overdue(today) => {
+            color::paint(&format!("{due} (overdue)"), Style::Red, use_color)
+        }
+        Some(due) => due.to_string(),
+        None => "-".to_string(),
+    }
+}
+
+/// Lists TODOs with a worktree (or a pending request for one) and where it lives.
+fn print_worktree_todos(ctx: &CommandCtx, todo_service: &TodoService, task_id: i64) -> Result<()> {
+    let todos = todo_service.list_todos(task_id)?;
+    let worktrees = WorktreeService::new(ctx.db).list_worktrees(task_id)?;
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.set_titles(Row::new(vec![
+        Cell::new("ID"),
+        Cell::new("Status"),
+        Cell::new("Content"),
+        Cell::new("Branch"),
+        Cell::new("Path"),
+    ]));
+
+    let use_color = color::enabled();
+    let mut shown = 0;
+    for todo in &todos {
+        let attached: Vec<_> = worktrees
+            .iter()
+            .filter(|wt| wt.todo_id == Some(todo.id))
+            .collect();
+        if attached.is_empty() && !todo.worktree_requested {
+            continue;
+        }
+        shown += 1;
+
+        let (branch, path) = if attached.is_empty() {
+            ("-".to_string(), "(not created yet)".to_string())
+        } else {
+            (
+                attached
+                    .iter()
+                    .map(|wt| wt.branch.as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                attached
+                    .iter()
+                    .map(|wt| wt.path.as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            )
+        };
+        table.add_row(Row::new(vec![
+            Cell::new(&todo.task_index.to_string()),
+            Cell::new(&color::todo_status(todo.status, use_color)),
+            Cell::new(&todo.content),
+            Cell::new(&branch),
+            Cell::new(&path),
+        ]));
+    }
+
+    if shown == 0 {
+        println!("No TODOs with worktrees.");
+        return Ok(());
+    }
+    table.printstd();
+    Ok(())
+}
+
+fn print_completed_since(
+    todo_service: &TodoService,
+    task_id: i64,
+    since: &str,
+    width: Option<usize>,
+) -> Result<()> {
+    let since = parse_since(since, Utc::now())?;
+    let todos = todo_service.list_completed_since(task_id, since)?;
+    if todos.is_empty() {
+        println!(
+            "No TODOs completed since {}.",
+            format_timestamp(since, "%Y-%m-%d %H:%M")
+        );
+        return Ok(());
+    }
+
+    let rows: Vec<_> = todos
+        .iter()
+        .map(|todo| {
+            let completed = todo
+                .completed_at
+                .map(|at| format_timestamp(at, "%Y-%m-%d %H:%M"))
+                .unwrap_or_default();
+            (
+                todo.task_index.to_string(),
+                completed,
+                todo.content.as_str(),
+            )
+        })
+        .collect();
+    let content_width = width.map_or(usize::MAX, |width| {
+        let fixed = [
+            term::column_width("ID", rows.iter().map(|row| row.0.as_str())),
+            term::column_width("Completed", rows.iter().map(|row| row.1.as_str())),
+        ];
+        let content = term::column_width("Content", rows.iter().map(|row| row.2));
+        term::fit_columns(width, &fixed, &[content])[0]
+    });
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.set_titles(Row::new(vec![
+        Cell::new("ID"),
+        Cell::new("Completed"),
+        Cell::new("Content"),
+    ]));
+
+    for (id, completed, content) in &rows {
+        table.add_row(Row::new(vec![
+            Cell::new(id),
+            Cell::new(completed),
+            Cell::new(&term::truncate(content, content_width)),
+        ]));
+    }
+
+    table.printstd();
+    Ok(())
+}
+
+fn print_todo_details(ctx: &CommandCtx, todo: &Todo) -> Result<()> {
+    let format_time = |at: DateTime<Utc>| format_timestamp(at, "%Y-%m-%d %H:%M");
+
+    println!("TODO #{} [{}]", todo.task_index, todo.status.as_str());
+    println!("Created:   {}", format_time(todo.created_at));
+    if let Some(due) = todo.due_date {
+        let overdue = if todo.is_overdue(Local::now().date_naive()) {
+            " (overdue)"
+        } else {
+            ""
+        };
+        println!("Due:       {}{}", due, overdue);
+    }
+    if let Some(completed_at) = todo.completed_at {
+        println!("Completed: {}", format_time(completed_at));
+    }
+
+    let worktrees = WorktreeService::new(ctx.db).list_worktrees(todo.task_id)?;
+    for wt in worktrees.iter().filter(|wt| wt.todo_id == Some(todo.id)) {
+        println!("Worktree:  {} ({})", wt.path, wt.branch);
+    }
+
+    println!("\n{}", todo.content_text());
+
+    let scraps: Vec<_> = ScrapService::new(ctx.db)
+        .list_scraps(todo.task_id)?
+        .into_iter()
+        .filter(|scrap| scrap.active_todo_id == Some(todo.task_index))
+        .collect();
+    if !scraps.is_empty() {
+        println!("\nScraps ({}):", scraps.len());
+        for scrap in scraps {
+            println!("  [{}] {}", format_time(scrap.created_at), scrap.content);
+        }
+    }
+
+    Ok(())
+}
+
+/// Pushes the task bookmark from the repo the TODO was merged into, or the
+/// task's base repo when it had no worktree; failures only warn.
+fn push_after_done(ctx: &CommandCtx, task_id: i64, outcome: &CompleteTodoOutcome) {
+    let worktree_service = WorktreeService::new(ctx.db);
+    let repo_path = match &outcome.merge_target {
+        Some(repo_path) => repo_path.clone(),
+        None => match worktree_service.task_base_repo(task_id) {
+            Ok(Some(repo_path)) => repo_path,
+            Ok(None) => {
+                eprintln!("warning: task has no repository; nothing to push");
+                return;
+            }
+            Err(err) => {
+                eprintln!("warning: push skipped: {err}");
+                return;
+            }
+        },
+    };
+
+    match worktree_service.push_task_bookmark(task_id, &repo_path) {
+        Ok(bookmark) => println!("Pushed bookmark {}", bookmark),
+        Err(err) => eprintln!("warning: push skipped: {err}"),
+    }
+}
+
+/// Closes the task's ticket issue on GitHub/GitLab; failures only warn.
+fn close_ticket_issue(ctx: &CommandCtx, task_id: i64) -> Result<()> {
+    let task = TaskService::new(ctx.db).get_task(task_id)?;
+    let Some(issue) = ForgeRef::from_ticket(task.ticket_id.as_deref(), task.ticket_url.as_deref())
+    else {
+        eprintln!("warning: task has no GitHub/GitLab issue ticket; not closing anything");
+        return Ok(());
+    };
+
+    match ForgeClient::new().close_issue(&issue) {
+        Ok(()) => println!("Closed issue {}", issue.web_url()),
+        Err(err) => eprintln!("warning: failed to close {}: {err}", issue.web_url()),
+    }
+    Ok(())
+}
+
+/// Posts the completion to the configured webhook, if any.
+fn notify_todo_done(
+    ctx: &CommandCtx,
+    task_id: i64,
+    todo_index: i64,
+    branch: Option<&str>,
+) -> Result<()> {
+    let notify_service = NotifyService::new(ctx.db);
+    if notify_service.webhook_url()?.is_none() {
+        return Ok(());
     }
 
+    let task = TaskService::new(ctx.db).get_task(task_id)?;
+    let todo = TodoService::new(ctx.db).get_todo_by_index(task_id, todo_index)?;
+    notify_service.todo_done(&task, &todo, branch)?;
     Ok(())
 }
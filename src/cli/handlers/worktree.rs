@@ -0,0 +1,307 @@
+use crate::cli::handlers::CommandCtx;
+use crate::cli::WorktreeCommands;
+use crate::models::{Worktree, WorktreeStatus};
+use crate::services::{RepoService, TaskService, TaskWorktree, TodoService, WorktreeService};
+use crate::utils::{determine_link_kind, disk, Result, TrackError};
+use prettytable::{format, Cell, Row, Table};
+use std::path::Path;
+
+pub fn handle_worktree(ctx: &CommandCtx, command: WorktreeCommands) -> Result<()> {
+    if let WorktreeCommands::List {
+        all_tasks: true,
+        size,
+        status,
+    } = command
+    {
+        return print_all_worktrees(ctx, size, status.status());
+    }
+
+    let current_task_id = ctx
+        .db
+        .get_current_task_id()?
+        .ok_or(TrackError::NoActiveTask)?;
+    let worktree_service = WorktreeService::new(ctx.db);
+
+    match command {
+        WorktreeCommands::List { size, status, .. } => {
+            let worktrees =
+                worktree_service.list_worktrees_by_status(current_task_id, status.status())?;
+            let mut table = Table::new();
+            table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+            let mut titles = vec![
+                Cell::new("ID"),
+                Cell::new("Branch"),
+                Cell::new("Path"),
+                Cell::new("Status"),
+            ];
+            if size {
+                titles.push(Cell::new("Size"));
+            }
+            table.set_titles(Row::new(titles));
+
+            for worktree in worktrees {
+                let mut cells = vec![
+                    Cell::new(&worktree.id.to_string()),
+                    Cell::new(&worktree.branch),
+                    Cell::new(&worktree.path),
+                    Cell::new(worktree.status.as_str()),
+                ];
+                if size {
+                    cells.push(Cell::new(&worktree_size(&worktree.path)));
+                }
+                table.add_row(Row::new(cells));
+            }
+
+            table.printstd();
+        }
+        WorktreeCommands::Add {
+            repo_ref,
+            branch,
+            todo,
+            from,
+            use_existing,
+            base_worktree,
+        } => {
+            // `--todo` takes the task-scoped index; worktrees store the global TODO id
+            let todo_id = todo
+                .map(|index| TodoService::new(ctx.db).get_todo_by_index(current_task_id, index))
+                .transpose()?
+                .map(|todo| todo.id);
+            let repo = RepoService::new(ctx.db).resolve_repo(current_task_id, &repo_ref)?;
+            let task = TaskService::new(ctx.db).get_task(current_task_id)?;
+            let ticket_id = task.ticket_id.as_deref();
+
+            if base_worktree {
+                let (base, created) = worktree_service.ensure_base_worktree(
+                    current_task_id,
+                    &repo.repo_path,
+                    ticket_id,
+                )?;
+                if created {
+                    println!(
+                        "Registered base worktree #{} ({}) at {}",
+                        base.id, base.branch, base.path
+                    );
+                } else {
+                    println!(
+                        "Task already has base worktree #{} ({}) at {}; nothing to do",
+                        base.id, base.branch, base.path
+                    );
+                }
+                return Ok(());
+            }
+
+            let (worktree, attached) = match from.as_deref() {
+                Some(from) => (
+                    worktree_service.add_worktree_from(
+                        current_task_id,
+                        &repo.repo_path,
+                        branch.as_deref(),
+                        ticket_id,
+                        todo_id,
+                        from,
+                    )?,
+                    false,
+                ),
+                None if use_existing => worktree_service.add_or_attach_worktree(
+                    current_task_id,
+                    &repo.repo_path,
+                    branch.as_deref(),
+                    ticket_id,
+                    todo_id,
+                )?,
+                None => (
+                    worktree_service.add_worktree(
+                        current_task_id,
+                        &repo.repo_path,
+                        branch.as_deref(),
+                        ticket_id,
+                        todo_id,
+                        false,
+                    )?,
+                    false,
+                ),
+            };
+            println!(
+                "{} worktree #{} ({}) at {}",
+                if attached { "Attached" } else { "Created" },
+                worktree.id,
+                worktree.branch,
+                worktree.path
+            );
+        }
+        WorktreeCommands::Link {
+            id,
+            url,
+            detect,
+            kind,
+        } => {
+            let worktree = current_task_worktree(&worktree_service, current_task_id, id)?;
+            // clap requires either a URL or --detect
+            let url = match url {
+                Some(url) if !detect => url,
+                _ => worktree_service.detect_pr_url(&worktree)?,
+            };
+
+            let kind = kind.unwrap_or_else(|| determine_link_kind(&url).to_string());
+            let link = worktree_service.add_repo_link(worktree.id, &url, &kind)?;
+            println!(
+                "Linked {} to worktree #{} ({}): {}",
+                link.kind, worktree.id, worktree.branch, link.url
+            );
+        }
+        WorktreeCommands::Remove { id, force, purge } => {
+            let worktree = worktree_service.get_worktree(id)?;
+            if worktree.task_id != current_task_id {
+                return Err(TrackError::WorktreeNotFound(id));
+            }
+            if worktree.status != WorktreeStatus::Active {
+                if !purge {
+                    return Err(retired(&worktree));
+                }
+                worktree_service.purge_worktree(worktree.id)?;
+                println!("Purged worktree #{} ({})", worktree.id, worktree.branch);
+                return Ok(());
+            }
+
+            if !force {
+                worktree_service.ensure_removable(&worktree)?;
+            }
+            if force {
+                if let Some(err) = worktree_service.force_remove_worktree(worktree.id)? {
+                    eprintln!("warning: {err}");
+                }
+            } else {
+                worktree_service.remove_worktree(worktree.id, false)?;
+            }
+            if purge {
+                worktree_service.purge_worktree(worktree.id)?;
+                println!(
+                    "Removed and purged worktree #{} ({})",
+                    worktree.id, worktree.branch
+                );
+            } else {
+                println!("Removed worktree #{} ({})", worktree.id, worktree.branch);
+            }
+        }
+        WorktreeCommands::Rename {
+            id,
+            new_branch,
+            move_dir,
+        } => {
+            let worktree = current_task_worktree(&worktree_service, current_task_id, id)?;
+            let renamed = worktree_service.rename_worktree(worktree.id, &new_branch, move_dir)?;
+            println!(
+                "Renamed worktree #{}: {} -> {}",
+                renamed.id, worktree.branch, renamed.branch
+            );
+            if renamed.path != worktree.path {
+                println!("  Moved to {}", renamed.path);
+            }
+        }
+        WorktreeCommands::MergeStatus { id } => {
+            let worktree = current_task_worktree(&worktree_service, current_task_id, id)?;
+            let status = worktree_service.merge_status(&worktree)?;
+            if status.is_merged() {
+                println!(
+                    "Worktree #{} ({}) is already merged into {}",
+                    worktree.id, status.branch, status.base
+                );
+            } else {
+                println!(
+                    "Worktree #{} ({}) has {} commit(s) not in {}",
+                    worktree.id, status.branch, status.unmerged_commits, status.base
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Admin view of every worktree track manages, across all tasks.
+fn print_all_worktrees(ctx: &CommandCtx, size: bool, status: Option<WorktreeStatus>) -> Result<()> {
+    let worktrees = WorktreeService::new(ctx.db).list_all_worktrees(status)?;
+    if worktrees.is_empty() {
+        println!("No worktrees recorded.");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    let mut titles = vec![
+        Cell::new("Task"),
+        Cell::new("ID"),
+        Cell::new("Branch"),
+        Cell::new("Path"),
+        Cell::new("Status"),
+    ];
+    if size {
+        titles.push(Cell::new("Size"));
+    }
+    table.set_titles(Row::new(titles));
+
+    let mut missing = 0;
+    for TaskWorktree {
+        worktree,
+        task_name,
+    } in &worktrees
+    {
+        let path = if Path::new(&worktree.path).exists() {
+            worktree.path.clone()
+        } else {
+            missing += 1;
+            format!("{} (missing)", worktree.path)
+        };
+        let mut cells = vec![
+            Cell::new(&format!("#{} {}", worktree.task_id, task_name)),
+            Cell::new(&worktree.id.to_string()),
+            Cell::new(&worktree.branch),
+            Cell::new(&path),
+            Cell::new(worktree.status.as_str()),
+        ];
+        if size {
+            cells.push(Cell::new(&worktree_size(&worktree.path)));
+        }
+        table.add_row(Row::new(cells));
+    }
+
+    table.printstd();
+    println!(
+        "{} worktree(s), {} missing on disk",
+        worktrees.len(),
+        missing
+    );
+    Ok(())
+}
+
+/// Human-readable disk usage of a worktree directory, `-` when it cannot be read.
+fn worktree_size(path: &str) -> String {
+    disk::dir_size(Path::new(path))
+        .map(disk::format_size)
+        .unwrap_or_else(|_| "-".to_string())
+}
+
+/// Fetch an active worktree, rejecting worktrees that belong to another task
+/// and merged/removed ones kept only as history.
+fn current_task_worktree(
+    worktree_service: &WorktreeService,
+    task_id: i64,
+    worktree_id: i64,
+) -> Result<Worktree> {
+    let worktree = worktree_service.get_worktree(worktree_id)?;
+    if worktree.task_id != task_id {
+        return Err(TrackError::WorktreeNotFound(worktree_id));
+    }
+    if worktree.status != WorktreeStatus::Active {
+        return Err(retired(&worktree));
+    }
+    Ok(worktree)
+}
+
+fn retired(worktree: &Worktree) -> TrackError {
+    TrackError::WorktreeRetired {
+        id: worktree.id,
+        status: worktree.status.as_str().to_string(),
+    }
+}
@@ -7,7 +7,9 @@
 pub mod handler;
 pub mod handlers;
 
+use crate::models::{TaskOrder, TaskStatus, TemplateVar, TodoStatus, WorktreeStatus};
 use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
 
 /// Types of completion data that can be output
 #[derive(Debug, Clone, ValueEnum)]
@@ -22,11 +24,63 @@ pub enum CompletionType {
     Repos,
 }
 
+#[derive(Subcommand)]
+pub enum CompletionCommands {
+    /// Print the dynamic completion candidates for the current task and check their format
+    Test {
+        /// Type of completion data to check
+        #[arg(value_enum)]
+        completion_type: CompletionType,
+    },
+}
+
+/// Shells `track completion` can generate scripts for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CompletionShell {
+    Bash,
+    Elvish,
+    Fish,
+    Nushell,
+    #[value(name = "powershell")]
+    PowerShell,
+    Zsh,
+}
+
+impl CompletionShell {
+    /// The matching `clap_complete` shell; `None` for generators from other crates.
+    pub fn clap_shell(self) -> Option<clap_complete::Shell> {
+        match self {
+            CompletionShell::Bash => Some(clap_complete::Shell::Bash),
+            CompletionShell::Elvish => Some(clap_complete::Shell::Elvish),
+            CompletionShell::Fish => Some(clap_complete::Shell::Fish),
+            CompletionShell::PowerShell => Some(clap_complete::Shell::PowerShell),
+            CompletionShell::Zsh => Some(clap_complete::Shell::Zsh),
+            CompletionShell::Nushell => None,
+        }
+    }
+}
+
 /// Main CLI structure for the track application.
 #[derive(Parser)]
 #[command(name = "track")]
 #[command(about = "WorkTracker CLI - Manage your development tasks and context", long_about = None)]
 pub struct Cli {
+    /// Log shelled-out git/jj commands to stderr (-vv for command stderr)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Disable colored output (also honored: NO_COLOR)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Database file to use (overrides TRACK_DB_PATH and the default location)
+    #[arg(long, global = true, value_name = "PATH")]
+    pub db: Option<PathBuf>,
+
+    /// Answer yes to every confirmation prompt (for scripts)
+    #[arg(short = 'y', long, global = true)]
+    pub yes: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -42,7 +96,7 @@ pub enum Commands {
         #[arg(short, long)]
         description: Option<String>,
 
-        /// Ticket ID (e.g., PROJ-123, owner/repo/456)
+        /// Ticket ID (e.g., PROJ-123, owner/repo/456, owner/repo#456, or an issue URL)
         #[arg(short, long)]
         ticket: Option<String>,
 
@@ -53,6 +107,18 @@ pub enum Commands {
         /// Template task reference (ID, ticket, or alias) to copy TODOs from
         #[arg(long)]
         template: Option<String>,
+
+        /// Fill `{{NAME}}` placeholders in template TODOs (repeatable, NAME=VALUE)
+        #[arg(long = "var", value_name = "NAME=VALUE", requires = "template")]
+        vars: Vec<TemplateVar>,
+
+        /// Switch to the existing task instead of failing when the ticket is taken
+        #[arg(long, requires = "ticket")]
+        switch_if_exists: bool,
+
+        /// Create the task without making it the current task
+        #[arg(long, conflicts_with = "switch_if_exists")]
+        no_switch: bool,
     },
 
     /// List tasks
@@ -60,18 +126,35 @@ pub enum Commands {
         /// Include archived tasks
         #[arg(short, long)]
         all: bool,
+
+        /// Show only archived tasks
+        #[arg(long, conflicts_with = "all")]
+        archived_only: bool,
+
+        /// Order by creation time or by most recent activity
+        #[arg(long, value_enum, default_value = "created")]
+        sort: ListSort,
+
+        /// Fit the table into N columns, cutting long names and tickets (default: terminal width)
+        #[arg(long, value_name = "N")]
+        width: Option<usize>,
+
+        /// Never cut long names or tickets
+        #[arg(long, conflicts_with = "width")]
+        no_truncate: bool,
     },
 
-    /// Switch to a different task
+    /// Switch to a different task (interactive picker when no task is given)
     Switch {
-        /// Task ID or ticket reference (e.g., 1 or t:PROJ-123)
-        task_ref: String,
+        /// Task ID, ticket, or alias reference (e.g., 1, t:PROJ-123, a:myalias)
+        task_ref: Option<String>,
     },
 
     /// Show detailed information about the current task
+    #[command(alias = "info")]
     Status {
-        /// Task ID or ticket reference (e.g., 1 or t:PROJ-123)
-        id: Option<String>,
+        /// Task ID, ticket, or alias reference (e.g., 1, t:PROJ-123, a:myalias)
+        task_ref: Option<String>,
 
         /// Output in JSON format
         #[arg(short, long)]
@@ -80,6 +163,51 @@ pub enum Commands {
         /// Show all scraps
         #[arg(short, long)]
         all: bool,
+
+        /// Redraw the status whenever the database changes (Ctrl+C to stop)
+        #[arg(short, long, conflicts_with = "json")]
+        watch: bool,
+
+        /// Polling interval in seconds for --watch
+        #[arg(long, value_name = "SECS", default_value = "2", requires = "watch")]
+        interval: u64,
+
+        /// Show only these sections (repeatable or comma-separated)
+        #[arg(
+            long,
+            value_enum,
+            value_delimiter = ',',
+            value_name = "SECTION",
+            conflicts_with_all = ["json", "no_todos", "no_links", "no_scraps", "no_worktrees"]
+        )]
+        only: Vec<StatusSection>,
+
+        /// Hide the TODO list
+        #[arg(long, conflicts_with = "json")]
+        no_todos: bool,
+
+        /// Hide task links
+        #[arg(long, conflicts_with = "json")]
+        no_links: bool,
+
+        /// Hide scraps
+        #[arg(long, conflicts_with = "json")]
+        no_scraps: bool,
+
+        /// Hide workspaces and their repository links
+        #[arg(long, conflicts_with = "json")]
+        no_worktrees: bool,
+
+        /// Show full timestamps instead of relative times like `3h ago`
+        #[arg(long, conflicts_with = "json")]
+        absolute: bool,
+    },
+
+    /// Show activity counts across all tasks
+    Stats {
+        /// Output in JSON format
+        #[arg(short, long)]
+        json: bool,
     },
 
     /// View or set task description
@@ -87,6 +215,14 @@ pub enum Commands {
         /// Description text (if omitted, displays current description)
         description: Option<String>,
 
+        /// Append this text on a new line instead of replacing the description
+        #[arg(long, value_name = "TEXT", conflicts_with = "description")]
+        append: Option<String>,
+
+        /// Edit the description in $EDITOR (reads stdin when not on a terminal)
+        #[arg(short, long, conflicts_with_all = ["description", "append"])]
+        editor: bool,
+
         /// Target task ID (defaults to current task)
         #[arg(short, long)]
         task: Option<i64>,
@@ -95,10 +231,16 @@ pub enum Commands {
     /// Link a ticket to a task
     Ticket {
         /// Ticket ID
-        ticket_id: String,
+        #[arg(required_unless_present = "set_url")]
+        ticket_id: Option<String>,
 
         /// Ticket URL
-        url: String,
+        #[arg(required_unless_present = "set_url")]
+        url: Option<String>,
+
+        /// Only replace the ticket URL, keeping the ticket ID
+        #[arg(long = "url", value_name = "URL", conflicts_with_all = ["ticket_id", "url"])]
+        set_url: Option<String>,
 
         /// Target task ID (defaults to current task)
         #[arg(long)]
@@ -107,14 +249,22 @@ pub enum Commands {
 
     /// Archive a task
     Archive {
-        /// Task ID or ticket reference (defaults to current task)
+        /// Task ID, ticket, or alias reference (defaults to current task)
         task_ref: Option<String>,
 
         /// Skip jj-task and dirty-workspace checks
         #[arg(short, long)]
         force: bool,
+
+        /// List archived tasks instead of archiving
+        #[arg(long, conflicts_with_all = ["task_ref", "force"])]
+        list: bool,
     },
 
+    /// Task settings
+    #[command(subcommand)]
+    Task(TaskCommands),
+
     /// TODO management
     #[command(subcommand)]
     Todo(TodoCommands),
@@ -132,6 +282,26 @@ pub enum Commands {
         /// JJ mode only: run legacy bookmark/per-TODO workspace sync (deprecated)
         #[arg(long)]
         legacy: bool,
+
+        /// JJ mode: register the main repo checkout as the task's base worktree
+        #[arg(long)]
+        create_base_worktree: bool,
+
+        /// Remove worktrees whose TODO is done or cancelled
+        #[arg(long)]
+        prune: bool,
+
+        /// Skip the confirmation prompt for --prune
+        #[arg(short, long, requires = "prune")]
+        force: bool,
+
+        /// Print only the summary counts as JSON
+        #[arg(long, conflicts_with = "prune")]
+        json: bool,
+
+        /// Run this shell command in each newly created worktree and report pass/fail
+        #[arg(long, value_name = "COMMAND", conflicts_with = "json")]
+        verify: Option<String>,
     },
 
     /// Migrate data between workflow models
@@ -142,22 +312,60 @@ pub enum Commands {
     #[command(subcommand)]
     Repo(RepoCommands),
 
+    /// Worktree management
+    #[command(subcommand)]
+    Worktree(WorktreeCommands),
+
     /// Task alias management
     #[command(subcommand)]
     Alias(AliasCommands),
 
+    /// Import tasks from exported data
+    #[command(subcommand)]
+    Import(ImportCommands),
+
+    /// Export a task (or every task with --all) as markdown or JSON
+    Export {
+        /// Task ID or reference (defaults to current task)
+        #[arg(conflicts_with = "all")]
+        task_ref: Option<String>,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "markdown")]
+        format: ExportFormat,
+
+        /// Export every open task
+        #[arg(long)]
+        all: bool,
+
+        /// With --all, also export archived tasks
+        #[arg(long, requires = "all")]
+        include_archived: bool,
+
+        /// Write to this file (or, with --all, one file per task in this directory)
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<PathBuf>,
+    },
+
+    /// Diagnose environment and database problems
+    Doctor,
+
     /// Show help optimized for LLM agents
     LlmHelp,
 
     /// Generate shell completion script
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
     Completion {
         /// Shell to generate completions for
-        #[arg(value_enum)]
-        shell: clap_complete::Shell,
+        #[arg(value_enum, required = true)]
+        shell: Option<CompletionShell>,
 
         /// Generate dynamic completion script (with real-time data)
         #[arg(short, long)]
         dynamic: bool,
+
+        #[command(subcommand)]
+        command: Option<CompletionCommands>,
     },
 
     /// Output completion candidates (hidden, for shell completion scripts)
@@ -202,6 +410,253 @@ pub enum MigrateCommands {
     },
 }
 
+#[derive(Subcommand)]
+pub enum TaskCommands {
+    /// Change a setting on the current task
+    #[command(subcommand)]
+    Set(TaskSetCommands),
+
+    /// Mark a task active, blocked, or paused (archive with `track archive`)
+    #[command(alias = "set-status")]
+    Status {
+        /// New status
+        #[arg(value_enum)]
+        state: TaskState,
+
+        /// Target task ID (defaults to current task)
+        #[arg(short, long)]
+        task: Option<i64>,
+    },
+
+    /// Move a TODO from the current task to another task
+    MoveTodo {
+        /// TODO ID (index within the current task, as shown by `todo list`)
+        id: i64,
+
+        /// Destination task (ID, t:<ticket>, or alias)
+        #[arg(long)]
+        to_task: String,
+
+        /// Move the TODO's worktrees along with it (default: detach them)
+        #[arg(long)]
+        keep_worktree: bool,
+    },
+
+    /// Merge another task's TODOs, links, scraps and repos into a task
+    Merge {
+        /// Task to merge and then archive (ID, t:<ticket>, or alias)
+        source_ref: String,
+
+        /// Task that receives everything (ID, t:<ticket>, or alias)
+        #[arg(long, value_name = "DEST_REF")]
+        into: String,
+
+        /// Delete the source task instead of archiving it
+        #[arg(long)]
+        delete: bool,
+    },
+
+    /// Mark a task as just worked on (for `track list --sort recent`)
+    Touch {
+        /// Task ID, ticket, or alias reference (defaults to current task)
+        task_ref: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TaskSetCommands {
+    /// Make `todo add` add URLs found in the TODO text as task links
+    AutoLink {
+        /// on or off
+        #[arg(value_enum)]
+        value: Toggle,
+
+        /// Target task ID (defaults to current task)
+        #[arg(short, long)]
+        task: Option<i64>,
+    },
+
+    /// Whether `todo add` makes new TODOs require a jj-task/git workspace (default on)
+    AutoWorkspace {
+        /// on or off
+        #[arg(value_enum)]
+        value: Toggle,
+
+        /// Target task ID (defaults to current task)
+        #[arg(short, long)]
+        task: Option<i64>,
+    },
+}
+
+/// Document format for `track export`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    Markdown,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+/// Sections of the `track status` markdown view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum StatusSection {
+    Description,
+    Todos,
+    Links,
+    Repos,
+    Scraps,
+    Worktrees,
+}
+
+/// Which sections `track status` prints; everything is shown by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusSections {
+    pub description: bool,
+    pub todos: bool,
+    pub links: bool,
+    pub repos: bool,
+    pub scraps: bool,
+    pub worktrees: bool,
+}
+
+impl Default for StatusSections {
+    fn default() -> Self {
+        Self::only(&[
+            StatusSection::Description,
+            StatusSection::Todos,
+            StatusSection::Links,
+            StatusSection::Repos,
+            StatusSection::Scraps,
+            StatusSection::Worktrees,
+        ])
+    }
+}
+
+impl StatusSections {
+    /// Shows exactly `sections`.
+    pub fn only(sections: &[StatusSection]) -> Self {
+        Self {
+            description: sections.contains(&StatusSection::Description),
+            todos: sections.contains(&StatusSection::Todos),
+            links: sections.contains(&StatusSection::Links),
+            repos: sections.contains(&StatusSection::Repos),
+            scraps: sections.contains(&StatusSection::Scraps),
+            worktrees: sections.contains(&StatusSection::Worktrees),
+        }
+    }
+
+    /// Builds the set from `--only` (when non-empty) or the `--no-*` flags.
+    pub fn from_flags(
+        only: &[StatusSection],
+        no_todos: bool,
+        no_links: bool,
+        no_scraps: bool,
+        no_worktrees: bool,
+    ) -> Self {
+        if !only.is_empty() {
+            return Self::only(only);
+        }
+        Self {
+            todos: !no_todos,
+            links: !no_links,
+            scraps: !no_scraps,
+            worktrees: !no_worktrees,
+            ..Self::default()
+        }
+    }
+}
+
+/// Ordering for `track list`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ListSort {
+    Created,
+    Recent,
+}
+
+impl ListSort {
+    pub fn order(self) -> TaskOrder {
+        match self {
+            ListSort::Created => TaskOrder::Created,
+            ListSort::Recent => TaskOrder::Recent,
+        }
+    }
+}
+
+/// Open task states settable with `track task status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TaskState {
+    Active,
+    Blocked,
+    Paused,
+}
+
+impl TaskState {
+    pub fn status(self) -> TaskStatus {
+        match self {
+            TaskState::Active => TaskStatus::Active,
+            TaskState::Blocked => TaskStatus::Blocked,
+            TaskState::Paused => TaskStatus::Paused,
+        }
+    }
+}
+
+/// Closed TODO states settable with `track todo set-status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TodoState {
+    Done,
+    Cancelled,
+}
+
+impl TodoState {
+    pub fn status(self) -> TodoStatus {
+        match self {
+            TodoState::Done => TodoStatus::Done,
+            TodoState::Cancelled => TodoStatus::Cancelled,
+        }
+    }
+}
+
+/// Worktree states accepted by `track worktree list --status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum WorktreeState {
+    Active,
+    Merged,
+    Removed,
+    All,
+}
+
+impl WorktreeState {
+    /// The status to filter on; `None` for `all`.
+    pub fn status(self) -> Option<WorktreeStatus> {
+        match self {
+            WorktreeState::Active => Some(WorktreeStatus::Active),
+            WorktreeState::Merged => Some(WorktreeStatus::Merged),
+            WorktreeState::Removed => Some(WorktreeStatus::Removed),
+            WorktreeState::All => None,
+        }
+    }
+}
+
+/// On/off value for boolean settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Toggle {
+    On,
+    Off,
+}
+
+impl Toggle {
+    pub fn is_on(self) -> bool {
+        self == Toggle::On
+    }
+}
+
 #[derive(Subcommand)]
 pub enum TodoCommands {
     /// Add a new TODO
@@ -216,29 +671,139 @@ pub enum TodoCommands {
         /// Research/planning TODO that does not need a jj-task or git worktree
         #[arg(long, conflicts_with = "worktree")]
         no_workspace: bool,
+
+        /// Require a jj-task/git workspace even when the task's auto-workspace is off
+        #[arg(long, conflicts_with = "no_workspace")]
+        workspace: bool,
+
+        /// Insert before the current TODO (make it the next one to work on)
+        #[arg(long, conflicts_with = "after_current")]
+        top: bool,
+
+        /// Insert right after the current TODO
+        #[arg(long)]
+        after_current: bool,
+
+        /// Add a task link alongside the TODO (repeatable)
+        #[arg(long = "link", value_name = "URL")]
+        links: Vec<String>,
+
+        /// Planning estimate in points or hours
+        #[arg(long, value_name = "N")]
+        estimate: Option<f64>,
+
+        /// Due date: YYYY-MM-DD, today, tomorrow, or a span ahead (3d, 2w)
+        #[arg(long, value_name = "DATE")]
+        due: Option<String>,
+
+        /// Add http(s) URLs found in the TODO text as task links, skipping existing ones
+        #[arg(long)]
+        auto_link: bool,
+
+        /// Do not add links from the TODO text, overriding the task's auto-link default
+        #[arg(long, conflicts_with = "auto_link")]
+        no_auto_link: bool,
     },
 
     /// List TODOs
-    List,
+    List {
+        /// Only show TODOs completed since a span (3d, 12h, 2w) or date (YYYY-MM-DD)
+        #[arg(long, value_name = "WHEN")]
+        completed_since: Option<String>,
+
+        /// Only show TODOs that have or requested a worktree, with branch and path
+        #[arg(long, conflicts_with = "completed_since")]
+        worktree_only: bool,
+
+        /// Show at most N TODOs (N >= 1)
+        #[arg(
+            long,
+            value_name = "N",
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+            conflicts_with_all = ["completed_since", "worktree_only"]
+        )]
+        limit: Option<usize>,
+
+        /// Skip the first N TODOs
+        #[arg(long, value_name = "N", default_value_t = 0, conflicts_with_all = ["completed_since", "worktree_only"])]
+        offset: usize,
+
+        /// Fit the table into N columns, cutting long content (default: terminal width)
+        #[arg(long, value_name = "N")]
+        width: Option<usize>,
+
+        /// Never cut long content
+        #[arg(long, conflicts_with = "width")]
+        no_truncate: bool,
+    },
 
     /// Update TODO status
     Update {
-        /// TODO ID
+        /// TODO ID (index within the current task, as shown by `todo list`)
         id: i64,
 
         /// New status (done or cancelled; reopen to pending is not allowed)
         status: String,
     },
 
+    /// Mark every pending TODO matching a filter as done or cancelled
+    SetStatus {
+        /// New status
+        #[arg(value_enum)]
+        status: TodoState,
+
+        /// Only TODOs whose content contains this text (case-insensitive)
+        #[arg(long, value_name = "TEXT", required_unless_present = "all_pending")]
+        matching: Option<String>,
+
+        /// Every pending TODO in the current task
+        #[arg(long, conflicts_with = "matching")]
+        all_pending: bool,
+
+        /// Allow updating more than five TODOs at once
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Set or clear a TODO's due date
+    Due {
+        /// TODO ID (index within the current task, as shown by `todo list`)
+        id: i64,
+
+        /// Due date: YYYY-MM-DD, today, tomorrow, or a span ahead (3d, 2w)
+        #[arg(required_unless_present = "clear")]
+        date: Option<String>,
+
+        /// Remove the due date
+        #[arg(long, conflicts_with = "date")]
+        clear: bool,
+    },
+
     /// Complete a TODO (merges worktree if exists)
     Done {
-        /// TODO ID
+        /// TODO ID (index within the current task, as shown by `todo list`)
         id: i64,
+
+        /// Commit uncommitted workspace changes with this message before merging
+        #[arg(long, value_name = "MESSAGE")]
+        commit: Option<String>,
+
+        /// Also close the task's GitHub/GitLab ticket issue (best-effort)
+        #[arg(long)]
+        close_issue: bool,
+
+        /// Push the task bookmark to the git remote after merging (best-effort)
+        #[arg(long)]
+        push: bool,
+
+        /// Record a closing note as a scrap attached to this TODO
+        #[arg(long, value_name = "TEXT")]
+        note: Option<String>,
     },
 
     /// Create or show worktrees for a TODO in the current repo
     Workspace {
-        /// TODO ID
+        /// TODO ID (index within the current task, as shown by `todo list`)
         id: i64,
 
         /// Recreate worktrees from the latest bookmark
@@ -252,11 +817,19 @@ pub enum TodoCommands {
         /// Operate on all registered repos for the task
         #[arg(long)]
         all: bool,
+
+        /// With --all, stop at the first repo that fails instead of continuing
+        #[arg(long, requires = "all")]
+        strict: bool,
+
+        /// Branch name for a new workspace (prefixed with the task's ticket ID)
+        #[arg(long, value_name = "NAME")]
+        branch: Option<String>,
     },
 
     /// Delete a TODO
     Delete {
-        /// TODO ID
+        /// TODO ID (index within the current task, as shown by `todo list`)
         id: i64,
 
         /// Skip confirmation prompt
@@ -266,7 +839,13 @@ pub enum TodoCommands {
 
     /// Move a TODO to the front (make it the next todo to work on)
     Next {
-        /// TODO ID
+        /// TODO ID (index within the current task, as shown by `todo list`)
+        id: i64,
+    },
+
+    /// Show a TODO's full content, timestamps, worktrees, and scraps
+    Show {
+        /// TODO ID (index within the current task, as shown by `todo list`)
         id: i64,
     },
 }
@@ -283,12 +862,33 @@ pub enum LinkCommands {
     },
 
     /// List links
-    List,
+    List {
+        /// Output in JSON format
+        #[arg(long)]
+        json: bool,
+    },
 
     /// Delete a link
     Delete {
         /// Link index (1-based)
-        index: usize,
+        index: i64,
+    },
+
+    /// Move a link to another position in the task's list
+    Move {
+        /// Link index (1-based)
+        index: i64,
+
+        /// Target position (index of the link whose slot it takes)
+        #[arg(long)]
+        to: i64,
+    },
+
+    /// Open every link of the current task in the browser
+    OpenAll {
+        /// Print the links instead of opening them
+        #[arg(long)]
+        dry_run: bool,
     },
 }
 
@@ -298,10 +898,30 @@ pub enum ScrapCommands {
     Add {
         /// Scrap content
         content: String,
+
+        /// Pin the scrap so it is listed before the chronological log
+        #[arg(long)]
+        pin: bool,
     },
 
-    /// List scraps
-    List,
+    /// List scraps (pinned first)
+    List {
+        /// Output in JSON format (includes rendered content_html)
+        #[arg(short, long)]
+        json: bool,
+    },
+
+    /// Pin a scrap as a reference note
+    Pin {
+        /// Scrap ID (index within the current task)
+        index: i64,
+    },
+
+    /// Unpin a scrap, returning it to the chronological log
+    Unpin {
+        /// Scrap ID (index within the current task)
+        index: i64,
+    },
 }
 
 #[derive(Subcommand)]
@@ -311,9 +931,13 @@ pub enum RepoCommands {
         /// Repository path (defaults to current directory)
         path: Option<String>,
 
-        /// Base branch to use (defaults to current branch)
+        /// Base branch to use (defaults to the bookmark on @ or @-, then the checked-out git branch)
         #[arg(short, long)]
         base: Option<String>,
+
+        /// Pin the task branch point to this commit instead of a branch head
+        #[arg(long, value_name = "HASH", conflicts_with = "base")]
+        base_commit: Option<String>,
     },
 
     /// List repositories
@@ -321,11 +945,133 @@ pub enum RepoCommands {
 
     /// Remove a repository
     Remove {
-        /// Repository ID
+        /// Repository index (as shown by `track repo list`) or path
+        repo_ref: String,
+
+        /// Also remove the task's worktrees created from this repository
+        #[arg(long)]
+        worktrees: bool,
+
+        /// Skip the confirmation prompt for --worktrees
+        #[arg(short, long, requires = "worktrees")]
+        force: bool,
+    },
+
+    /// Fetch open/merged/closed state for the current task's PR and issue links
+    SyncLinks,
+}
+
+#[derive(Subcommand)]
+pub enum WorktreeCommands {
+    /// List worktrees for the current task
+    List {
+        /// List worktrees of every task, flagging paths that no longer exist
+        #[arg(long)]
+        all_tasks: bool,
+
+        /// Show the disk usage of each worktree directory (walks every file; can be slow)
+        #[arg(long)]
+        size: bool,
+
+        /// Only show worktrees in this state (merged/removed ones are kept as history)
+        #[arg(long, value_enum, default_value = "active")]
+        status: WorktreeState,
+    },
+
+    /// Create a worktree (jj workspace) in one of the task's repositories
+    Add {
+        /// Repository index or path
+        repo_ref: String,
+
+        /// Branch (bookmark) name; derived from the task and TODO when omitted
+        #[arg(long)]
+        branch: Option<String>,
+
+        /// TODO index (as shown by `track todo list`) to associate the worktree with
+        #[arg(long)]
+        todo: Option<i64>,
+
+        /// Revision to branch from instead of the task bookmark
+        #[arg(long, value_name = "REF")]
+        from: Option<String>,
+
+        /// Attach to the bookmark if it already exists instead of failing
+        #[arg(long, conflicts_with = "from")]
+        use_existing: bool,
+
+        /// Register the repo checkout as the task's base worktree if the task has none
+        #[arg(long, conflicts_with_all = ["branch", "todo", "from", "use_existing"])]
+        base_worktree: bool,
+    },
+
+    /// Attach a repository link (e.g. pull request URL) to a worktree
+    Link {
+        /// Worktree ID
+        id: i64,
+
+        /// URL to attach (required unless --detect)
+        #[arg(required_unless_present = "detect")]
+        url: Option<String>,
+
+        /// Detect the pull request URL from the worktree branch and origin remote
+        #[arg(long, conflicts_with = "url")]
+        detect: bool,
+
+        /// Link kind; classified from the URL (pr, issue, ci, ...) when omitted
+        #[arg(long)]
+        kind: Option<String>,
+    },
+
+    /// Remove a worktree (forgets the jj workspace and deletes its directory;
+    /// the record is kept as `removed` history)
+    Remove {
+        /// Worktree ID
+        id: i64,
+
+        /// Remove the base worktree even while TODO worktrees still depend on it,
+        /// and retire the record even if the directory cannot be deleted
+        #[arg(short, long)]
+        force: bool,
+
+        /// Delete the record instead of keeping it as history (also works on
+        /// merged/removed worktrees)
+        #[arg(long)]
+        purge: bool,
+    },
+
+    /// Rename a worktree's branch (bookmark)
+    Rename {
+        /// Worktree ID
+        id: i64,
+
+        /// New branch name
+        new_branch: String,
+
+        /// Also move the worktree directory to match the new branch name
+        #[arg(long)]
+        move_dir: bool,
+    },
+
+    /// Check whether a worktree's branch is already merged into its base
+    MergeStatus {
+        /// Worktree ID
         id: i64,
     },
 }
 
+#[derive(Subcommand)]
+pub enum ImportCommands {
+    /// Recreate a task from `track status --json` output
+    Json {
+        /// Path to the JSON file (use - for stdin)
+        file: String,
+
+        /// Drop the ticket ID instead of failing when another task already uses it
+        #[arg(long)]
+        skip_conflicting_ticket: bool,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum AliasCommands {
     /// Set an alias for the current task
@@ -352,9 +1098,10 @@ pub enum AliasCommands {
 
 #[derive(Subcommand)]
 pub enum ConfigCommands {
-    /// Set a configuration value (e.g. vcs-mode jj|git)
+    /// Set a configuration value (vcs-mode jj|git, notify-webhook <url>,
+    /// time-zone local|utc, time-format <strftime>)
     Set {
-        /// Configuration key (e.g. vcs-mode)
+        /// Configuration key (vcs-mode, notify-webhook, time-zone or time-format)
         key: String,
 
         /// Configuration value
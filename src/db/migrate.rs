@@ -4,6 +4,15 @@ use crate::models::{TaskStatus, TodoStatus};
 use crate::utils::Result;
 use rusqlite::Connection;
 
+/// CHECK constraint listing every valid task status.
+pub(crate) fn task_status_check() -> String {
+    let values: Vec<String> = TaskStatus::ALL
+        .iter()
+        .map(|status| format!("'{status}'"))
+        .collect();
+    format!("CHECK (status IN ({}))", values.join(", "))
+}
+
 /// Adds CHECK constraints on task/todo status columns for existing databases.
 pub(crate) fn migrate_status_check_constraints(conn: &Connection) -> Result<()> {
     let tasks_sql: String = conn
@@ -20,9 +29,8 @@ pub(crate) fn migrate_status_check_constraints(conn: &Connection) -> Result<()>
 
     let invalid_tasks: i64 = conn.query_row(
         &format!(
-            "SELECT COUNT(*) FROM tasks WHERE status NOT IN ('{}', '{}')",
-            TaskStatus::ACTIVE,
-            TaskStatus::ARCHIVED
+            "SELECT COUNT(*) FROM tasks WHERE NOT ({})",
+            task_status_check().trim_start_matches("CHECK ")
         ),
         [],
         |row| row.get(0),
@@ -49,11 +57,7 @@ pub(crate) fn migrate_status_check_constraints(conn: &Connection) -> Result<()>
         });
     }
 
-    let task_check = format!(
-        "CHECK (status IN ('{}', '{}'))",
-        TaskStatus::ACTIVE,
-        TaskStatus::ARCHIVED
-    );
+    let task_check = task_status_check();
     let todo_check = format!(
         "CHECK (status IN ('{}', '{}', '{}'))",
         TodoStatus::PENDING,
@@ -109,6 +113,51 @@ pub(crate) fn migrate_status_check_constraints(conn: &Connection) -> Result<()>
     Ok(())
 }
 
+/// Widens the tasks CHECK constraint from active/archived to every status in
+/// [`TaskStatus::ALL`].
+///
+/// SQLite cannot alter a constraint in place, so the table is rebuilt from its
+/// own stored definition; columns and indexes are carried over as-is.
+fn migrate_task_status_values(conn: &Connection) -> Result<()> {
+    let legacy_check = format!(
+        "CHECK (status IN ('{}', '{}'))",
+        TaskStatus::ACTIVE,
+        TaskStatus::ARCHIVED
+    );
+    let tasks_sql: String = conn.query_row(
+        "SELECT sql FROM sqlite_master WHERE type='table' AND name='tasks'",
+        [],
+        |row| row.get(0),
+    )?;
+    if !tasks_sql.contains(&legacy_check) {
+        return Ok(());
+    }
+
+    let columns = tasks_sql
+        .find('(')
+        .map(|start| &tasks_sql[start..])
+        .unwrap_or_default()
+        .replace(&legacy_check, &task_status_check());
+    let index_sqls: Vec<String> = conn
+        .prepare("SELECT sql FROM sqlite_master WHERE type='index' AND tbl_name='tasks' AND sql IS NOT NULL")?
+        .query_map([], |row| row.get(0))?
+        .collect::<std::result::Result<_, _>>()?;
+
+    let mut batch = format!(
+        "CREATE TABLE tasks_new {columns};
+        INSERT INTO tasks_new SELECT * FROM tasks;
+        DROP TABLE tasks;
+        ALTER TABLE tasks_new RENAME TO tasks;\n"
+    );
+    for index_sql in index_sqls {
+        batch.push_str(&index_sql);
+        batch.push_str(";\n");
+    }
+    conn.execute_batch(&batch)?;
+
+    Ok(())
+}
+
 pub fn migrate_schema(conn: &Connection) -> Result<()> {
     // Migrate git_items table to worktrees (for existing databases)
     let git_items_exists: i64 = conn.query_row(
@@ -492,6 +541,101 @@ pub fn migrate_schema(conn: &Connection) -> Result<()> {
     )?;
 
     migrate_status_check_constraints(conn)?;
+    migrate_task_status_values(conn)?;
+
+    // Check for estimate column in todos (after the CHECK rebuild, which drops unknown columns)
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('todos') WHERE name='estimate'",
+        [],
+        |row| row.get(0),
+    )?;
+
+    if count == 0 {
+        conn.execute("ALTER TABLE todos ADD COLUMN estimate REAL", [])?;
+    }
+
+    // Check for state column in repo_links (PR/issue state from `repo sync-links`)
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('repo_links') WHERE name='state'",
+        [],
+        |row| row.get(0),
+    )?;
+
+    if count == 0 {
+        conn.execute("ALTER TABLE repo_links ADD COLUMN state TEXT", [])?;
+    }
+
+    // Check for due_date column in todos (`todo add --due`, `todo due`)
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('todos') WHERE name='due_date'",
+        [],
+        |row| row.get(0),
+    )?;
+
+    if count == 0 {
+        conn.execute("ALTER TABLE todos ADD COLUMN due_date TEXT", [])?;
+    }
+
+    // Check for pinned column in scraps (`scrap add --pin`, `scrap pin`)
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('scraps') WHERE name='pinned'",
+        [],
+        |row| row.get(0),
+    )?;
+
+    if count == 0 {
+        conn.execute(
+            "ALTER TABLE scraps ADD COLUMN pinned INTEGER NOT NULL DEFAULT 0",
+            [],
+        )?;
+    }
+
+    // Check for last_active_at column in tasks (`track list --sort recent`)
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('tasks') WHERE name='last_active_at'",
+        [],
+        |row| row.get(0),
+    )?;
+
+    if count == 0 {
+        conn.execute("ALTER TABLE tasks ADD COLUMN last_active_at TEXT", [])?;
+    }
+
+    // Check for auto_link column in tasks (`task set auto-link`)
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('tasks') WHERE name='auto_link'",
+        [],
+        |row| row.get(0),
+    )?;
+
+    if count == 0 {
+        conn.execute(
+            "ALTER TABLE tasks ADD COLUMN auto_link INTEGER NOT NULL DEFAULT 0",
+            [],
+        )?;
+        // Earlier builds kept the setting in app_state as `auto_link:<task id>`
+        conn.execute_batch(
+            "UPDATE tasks SET auto_link = 1 WHERE id IN (
+                 SELECT CAST(substr(key, 11) AS INTEGER) FROM app_state
+                 WHERE key LIKE 'auto_link:%' AND value = 'on'
+             );
+             DELETE FROM app_state WHERE key LIKE 'auto_link:%';",
+        )?;
+    }
+
+    // Check for auto_workspace column in tasks (`task set auto-workspace`)
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('tasks') WHERE name='auto_workspace'",
+        [],
+        |row| row.get(0),
+    )?;
+
+    if count == 0 {
+        conn.execute(
+            "ALTER TABLE tasks ADD COLUMN auto_workspace INTEGER NOT NULL DEFAULT 1",
+            [],
+        )?;
+    }
 
     Ok(())
 }
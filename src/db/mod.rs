@@ -5,15 +5,23 @@
 //! and Git repository information.
 
 use crate::models::{TaskStatus, TodoStatus, VcsMode};
+use crate::utils::time::{self, TimeStyle};
 use crate::utils::Result;
 use directories::ProjectDirs;
 use rusqlite::{params, Connection, OptionalExtension};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 pub mod migrate;
 pub mod row_mapping;
 
+/// Environment variable overriding the default database location.
+pub const DB_PATH_ENV: &str = "TRACK_DB_PATH";
+
+/// app_state keys for timestamp display (`track config set time-zone|time-format`).
+pub const TIME_ZONE_KEY: &str = "time_zone";
+pub const TIME_FORMAT_KEY: &str = "time_format";
+
 /// Revision numbers for each section, used for change detection.
 ///
 /// Each section has a revision number that is incremented whenever
@@ -46,8 +54,8 @@ pub struct Database {
 impl Database {
     /// Creates a new database instance with the default file location.
     ///
-    /// The database file is stored in the platform-specific data directory.
-    /// The schema is automatically initialized if the database is new.
+    /// The location is `TRACK_DB_PATH` when set, otherwise the platform-specific
+    /// data directory. The schema is automatically initialized if the database is new.
     ///
     /// # Errors
     ///
@@ -56,14 +64,34 @@ impl Database {
     /// - The database file cannot be created or opened
     /// - Schema initialization fails
     pub fn new() -> Result<Self> {
-        let db_path = Self::get_db_path()?;
+        Self::open(Self::get_db_path()?)
+    }
+
+    /// Opens the database at `path` (`--db`), or the default location when `None`.
+    pub fn open_with_override(path: Option<&Path>) -> Result<Self> {
+        Self::open(Self::resolve_path(path)?)
+    }
+
+    /// Resolves the database file location for an optional `--db` override.
+    pub fn resolve_path(path: Option<&Path>) -> Result<PathBuf> {
+        match path {
+            Some(path) => Ok(path.to_path_buf()),
+            None => Self::get_db_path(),
+        }
+    }
+
+    /// Opens (or creates) a database file at an explicit path.
+    ///
+    /// Parent directories are created as needed.
+    pub fn open(db_path: impl AsRef<Path>) -> Result<Self> {
+        let db_path = db_path.as_ref();
 
         // Create parent directory if it doesn't exist
-        if let Some(parent) = db_path.parent() {
+        if let Some(parent) = db_path.parent().filter(|p| !p.as_os_str().is_empty()) {
             std::fs::create_dir_all(parent)?;
         }
 
-        let conn = Connection::open(&db_path)?;
+        let conn = Connection::open(db_path)?;
         Self::configure_connection(&conn)?;
         let db = Database { conn };
         db.initialize_schema()?;
@@ -101,6 +129,10 @@ impl Database {
     }
 
     fn get_db_path() -> Result<PathBuf> {
+        if let Some(path) = std::env::var_os(DB_PATH_ENV).filter(|p| !p.is_empty()) {
+            return Ok(PathBuf::from(path));
+        }
+
         let proj_dirs = ProjectDirs::from("", "", "track")
             .ok_or(crate::utils::TrackError::DataDirectoryUnavailable)?;
 
@@ -118,7 +150,7 @@ impl Database {
             CREATE TABLE IF NOT EXISTS tasks (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
                 name TEXT NOT NULL,
-                status TEXT NOT NULL DEFAULT '{task_active}' CHECK (status IN ('{task_active}', '{task_archived}')),
+                status TEXT NOT NULL DEFAULT '{task_active}' {task_check},
                 ticket_id TEXT UNIQUE,
                 ticket_url TEXT,
                 created_at TEXT NOT NULL
@@ -133,6 +165,8 @@ impl Database {
                 requires_workspace INTEGER NOT NULL DEFAULT 1,
                 created_at TEXT NOT NULL,
                 completed_at TEXT,
+                estimate REAL,
+                due_date TEXT,
                 FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
             );
 
@@ -174,6 +208,7 @@ impl Database {
                 url TEXT NOT NULL,
                 kind TEXT NOT NULL,
                 created_at TEXT NOT NULL,
+                state TEXT,
                 FOREIGN KEY (worktree_id) REFERENCES worktrees(id) ON DELETE CASCADE
             );
 
@@ -193,7 +228,7 @@ impl Database {
             CREATE INDEX IF NOT EXISTS idx_task_repos_task_id ON task_repos(task_id);
             "#,
             task_active = TaskStatus::ACTIVE,
-            task_archived = TaskStatus::ARCHIVED,
+            task_check = migrate::task_status_check(),
             todo_pending = TodoStatus::PENDING,
             todo_done = TodoStatus::DONE,
             todo_cancelled = TodoStatus::CANCELLED,
@@ -206,6 +241,14 @@ impl Database {
         Ok(())
     }
 
+    /// Returns the database file path, or `None` for in-memory databases.
+    pub fn path(&self) -> Option<PathBuf> {
+        self.conn
+            .path()
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+    }
+
     /// Returns a reference to the underlying SQLite connection.
     pub fn get_connection(&self) -> &Connection {
         &self.conn
@@ -279,6 +322,24 @@ impl Database {
         }
     }
 
+    /// Returns the timestamp display style: the `time-zone` / `time-format`
+    /// config, with `TRACK_TZ` (`local` or `utc`) overriding the zone.
+    pub fn get_time_style(&self) -> Result<TimeStyle> {
+        let zone = std::env::var("TRACK_TZ")
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .or(self.get_app_state(TIME_ZONE_KEY)?);
+        Ok(TimeStyle {
+            utc: match zone {
+                Some(zone) => time::parse_time_zone(&zone)?,
+                None => false,
+            },
+            format: self
+                .get_app_state(TIME_FORMAT_KEY)?
+                .filter(|pattern| !pattern.is_empty()),
+        })
+    }
+
     /// Persists the VCS backend preference.
     pub fn set_vcs_mode(&self, mode: VcsMode) -> Result<()> {
         self.set_app_state(VcsMode::KEY, mode.as_str())
@@ -317,6 +378,28 @@ impl Database {
         Ok(())
     }
 
+    /// Stamps `last_active_at` on a task with the current time.
+    ///
+    /// Called on switches and next to every task-scoped `increment_rev`: edits of
+    /// the task or its TODOs, links, scraps, repos, worktrees and their repo
+    /// links. Unknown task IDs are ignored.
+    pub fn touch_task(&self, task_id: i64) -> Result<()> {
+        self.conn.execute(
+            "UPDATE tasks SET last_active_at = ?1 WHERE id = ?2",
+            params![chrono::Utc::now().to_rfc3339(), task_id],
+        )?;
+        Ok(())
+    }
+
+    /// [`touch_task`](Self::touch_task) for the task owning a worktree record.
+    pub fn touch_worktree_task(&self, worktree_id: i64) -> Result<()> {
+        self.conn.execute(
+            "UPDATE tasks SET last_active_at = ?1 WHERE id = (SELECT task_id FROM worktrees WHERE id = ?2)",
+            params![chrono::Utc::now().to_rfc3339(), worktree_id],
+        )?;
+        Ok(())
+    }
+
     /// Increments the revision number for a section and returns the new value.
     ///
     /// # Arguments
@@ -413,6 +496,20 @@ mod tests {
         assert_eq!(timeout, 5000);
     }
 
+    #[test]
+    fn test_open_with_override_creates_parent_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested/client-a.db");
+
+        let db = Database::open_with_override(Some(&path)).unwrap();
+        db.set_app_state("k", "v").unwrap();
+        drop(db);
+
+        assert!(path.exists());
+        let reopened = Database::open(&path).unwrap();
+        assert_eq!(reopened.get_app_state("k").unwrap().as_deref(), Some("v"));
+    }
+
     #[test]
     fn test_app_state_get_set() {
         let db = Database::new_in_memory().unwrap();
@@ -434,6 +531,29 @@ mod tests {
         assert_eq!(value, Some("new_value".to_string()));
     }
 
+    #[test]
+    fn test_migration_moves_auto_link_out_of_app_state() {
+        let db = Database::new_in_memory().unwrap();
+        let conn = db.get_connection();
+        conn.execute_batch(
+            "INSERT INTO tasks (name, status, created_at) VALUES ('On', 'active', '2026-01-01T00:00:00+00:00'), ('Off', 'active', '2026-01-01T00:00:00+00:00');
+             ALTER TABLE tasks DROP COLUMN auto_link;
+             INSERT INTO app_state (key, value) VALUES ('auto_link:1', 'on'), ('auto_link:2', 'off');",
+        )
+        .unwrap();
+
+        migrate::migrate_schema(conn).unwrap();
+        let flags: Vec<bool> = conn
+            .prepare("SELECT auto_link FROM tasks ORDER BY id")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_eq!(flags, vec![true, false]);
+        assert_eq!(db.get_app_state("auto_link:1").unwrap(), None);
+    }
+
     #[test]
     fn test_vcs_mode_defaults_to_jj() {
         let db = Database::new_in_memory().unwrap();
@@ -1,7 +1,7 @@
 //! Shared SQLite row parsing helpers for domain entities.
 
-use crate::models::{Task, TaskStatus, Todo, TodoStatus};
-use chrono::{DateTime, Utc};
+use crate::models::{Task, TaskStatus, Todo, TodoStatus, WorktreeStatus};
+use chrono::{DateTime, NaiveDate, Utc};
 use rusqlite::{types::Type, Row};
 use std::str::FromStr;
 
@@ -12,6 +12,12 @@ pub fn parse_datetime(value: String) -> rusqlite::Result<DateTime<Utc>> {
         .map_err(|e| rusqlite::Error::FromSqlConversionFailure(0, Type::Text, Box::new(e)))
 }
 
+/// Parses a `YYYY-MM-DD` date stored as TEXT.
+pub fn parse_date(value: String) -> rusqlite::Result<NaiveDate> {
+    NaiveDate::parse_from_str(&value, "%Y-%m-%d")
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(0, Type::Text, Box::new(e)))
+}
+
 /// Parses a task status column value.
 pub fn parse_task_status(value: String) -> rusqlite::Result<TaskStatus> {
     TaskStatus::from_str(&value).map_err(|_| rusqlite::Error::InvalidQuery)
@@ -22,6 +28,11 @@ pub fn parse_todo_status(value: String) -> rusqlite::Result<TodoStatus> {
     TodoStatus::from_str(&value).map_err(|_| rusqlite::Error::InvalidQuery)
 }
 
+/// Parses a worktree status column value.
+pub fn parse_worktree_status(value: String) -> rusqlite::Result<WorktreeStatus> {
+    WorktreeStatus::from_str(&value).map_err(|_| rusqlite::Error::InvalidQuery)
+}
+
 /// Maps a tasks table row into a [`Task`].
 pub fn row_to_task(row: &Row<'_>) -> rusqlite::Result<Task> {
     Ok(Task {
@@ -34,6 +45,10 @@ pub fn row_to_task(row: &Row<'_>) -> rusqlite::Result<Task> {
         alias: row.get(6)?,
         is_today_task: row.get::<_, i64>(7)? != 0,
         created_at: parse_datetime(row.get(8)?)?,
+        last_active_at: row
+            .get::<_, Option<String>>(9)?
+            .map(parse_datetime)
+            .transpose()?,
     })
 }
 
@@ -52,5 +67,10 @@ pub fn row_to_todo(row: &Row<'_>) -> rusqlite::Result<Todo> {
             .get::<_, Option<String>>(8)?
             .map(parse_datetime)
             .transpose()?,
+        estimate: row.get(9)?,
+        due_date: row
+            .get::<_, Option<String>>(10)?
+            .map(parse_date)
+            .transpose()?,
     })
 }
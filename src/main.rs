@@ -6,19 +6,23 @@ use track::webui;
 /// Application entry point
 fn main() {
     let cli = Cli::parse();
+    init_logging(cli.verbose);
+    if cli.no_color {
+        track::utils::color::disable();
+    }
 
     // Handle webui command separately (requires async runtime)
     if let Commands::Webui { port, open } = cli.command {
         let rt = tokio::runtime::Runtime::new().expect("Failed to create tokio runtime");
-        if let Err(e) = rt.block_on(webui::start_server(port, open)) {
+        if let Err(e) = rt.block_on(webui::start_server(port, open, cli.db.as_deref())) {
             eprintln!("Error: {}", e);
             process::exit(1);
         }
         return;
     }
 
-    let handler = match CommandHandler::new() {
-        Ok(h) => h,
+    let handler = match CommandHandler::new(cli.db.as_deref()) {
+        Ok(h) => h.with_assume_yes(cli.yes),
         Err(e) => {
             eprintln!("Error: {}", e);
             process::exit(1);
@@ -30,3 +34,19 @@ fn main() {
         process::exit(1);
     }
 }
+
+/// Route debug logs to stderr when `-v` is given (`-vv` enables trace).
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => return,
+        1 => tracing::Level::DEBUG,
+        _ => tracing::Level::TRACE,
+    };
+
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(std::io::stderr)
+        .with_target(false)
+        .without_time()
+        .init();
+}
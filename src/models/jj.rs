@@ -54,6 +54,7 @@ mod tests {
             alias: alias.map(str::to_string),
             is_today_task: false,
             created_at: Utc::now(),
+            last_active_at: None,
         }
     }
 
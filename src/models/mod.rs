@@ -3,20 +3,24 @@
 //! This module defines the core data structures used throughout the application,
 //! including tasks, TODOs, links, scraps, and JJ-related items.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::Serialize;
 
 mod jj;
 mod status;
+mod task_list_filter;
+mod template_vars;
 mod todo_action;
 mod todo_add_options;
 mod vcs_mode;
 mod workflow;
 
 pub use jj::{jj_slug, sanitize_jj_slug};
-pub use status::{TaskStatus, TodoStatus};
+pub use status::{TaskStatus, TodoStatus, WorktreeStatus};
+pub use task_list_filter::{TaskListFilter, TaskOrder};
+pub use template_vars::{fill_template_vars, TemplateVar};
 pub use todo_action::TodoAction;
-pub use todo_add_options::TodoAddOptions;
+pub use todo_add_options::{TodoAddOptions, TodoPosition};
 pub use vcs_mode::VcsMode;
 pub use workflow::{
     build_git_context, build_jj_context, build_next_action, build_workflow_checklist,
@@ -121,6 +125,45 @@ fn render_markdown_with_links(content: &str) -> String {
         .to_string()
 }
 
+/// Renders markdown as plain terminal text: list bullets, `[x]` task markers,
+/// and link targets shown after the link text.
+fn render_markdown_plain(content: &str) -> String {
+    use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+    let mut out = String::new();
+    let mut link_stack: Vec<(String, usize)> = Vec::new();
+
+    for event in Parser::new_ext(content, Options::ENABLE_TASKLISTS) {
+        match event {
+            Event::Text(text) | Event::Code(text) => out.push_str(&text),
+            Event::SoftBreak | Event::HardBreak => out.push('\n'),
+            Event::TaskListMarker(done) => out.push_str(if done { "[x] " } else { "[ ] " }),
+            Event::Start(Tag::Item) => out.push_str("- "),
+            Event::Start(Tag::Link { dest_url, .. }) => {
+                link_stack.push((dest_url.to_string(), out.len()));
+            }
+            Event::End(TagEnd::Link) => {
+                if let Some((url, start)) = link_stack.pop() {
+                    if out[start..] != url {
+                        out.push_str(&format!(" ({url})"));
+                    }
+                }
+            }
+            Event::End(TagEnd::Item) => out.push('\n'),
+            Event::End(TagEnd::Paragraph | TagEnd::Heading(_) | TagEnd::CodeBlock) => {
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push('\n');
+            }
+            Event::End(TagEnd::List(_)) => out.push('\n'),
+            _ => {}
+        }
+    }
+
+    out.trim_end().to_string()
+}
+
 /// Represents a development task.
 ///
 /// A task is the primary organizational unit in track. Each task can have multiple TODOs,
@@ -136,6 +179,8 @@ pub struct Task {
     pub alias: Option<String>,
     pub is_today_task: bool,
     pub created_at: DateTime<Utc>,
+    /// Last switch or mutation; `None` until the task is touched after creation
+    pub last_active_at: Option<DateTime<Utc>>,
 }
 
 /// Represents a TODO item within a task.
@@ -163,6 +208,12 @@ pub struct Todo {
     #[allow(dead_code)]
     pub created_at: DateTime<Utc>,
     pub completed_at: Option<DateTime<Utc>>,
+    /// Planning estimate (points or hours)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<f64>,
+    /// Deadline set with `todo add --due` or `todo due`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<NaiveDate>,
 }
 
 impl Todo {
@@ -175,6 +226,46 @@ impl Todo {
     pub fn content_html(&self) -> String {
         render_markdown_with_links(&self.content)
     }
+
+    /// Renders the todo content from markdown to plain text for the terminal.
+    pub fn content_text(&self) -> String {
+        render_markdown_plain(&self.content)
+    }
+
+    /// Whether the TODO is still pending after its due date has passed.
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.status == TodoStatus::Pending && self.due_date.is_some_and(|due| due < today)
+    }
+}
+
+/// Sum of TODO estimates for a task.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EstimateRollup {
+    /// Estimates of pending and done TODOs (cancelled work is excluded)
+    pub total: f64,
+    /// Estimates of pending TODOs
+    pub remaining: f64,
+}
+
+impl EstimateRollup {
+    /// Returns `None` when no TODO carries an estimate.
+    pub fn from_todos(todos: &[Todo]) -> Option<Self> {
+        if todos.iter().all(|todo| todo.estimate.is_none()) {
+            return None;
+        }
+
+        let sum = |keep: fn(TodoStatus) -> bool| -> f64 {
+            todos
+                .iter()
+                .filter(|todo| keep(todo.status))
+                .filter_map(|todo| todo.estimate)
+                .sum()
+        };
+        Some(Self {
+            total: sum(|status| status != TodoStatus::Cancelled),
+            remaining: sum(|status| status == TodoStatus::Pending),
+        })
+    }
 }
 
 /// Represents a link associated with a task.
@@ -198,6 +289,13 @@ pub struct Link {
     pub created_at: DateTime<Utc>,
 }
 
+impl Link {
+    /// Kind of the URL (`pr`, `issue`, `ci`, `repo`, `doc` or `link`).
+    pub fn kind(&self) -> &'static str {
+        crate::utils::determine_link_kind(&self.url)
+    }
+}
+
 /// Represents a scrap (work note) for a task.
 ///
 /// Scraps are chronological notes that capture progress, decisions, and findings
@@ -216,6 +314,8 @@ pub struct Scrap {
     pub created_at: DateTime<Utc>,
     /// The task_index of the active (oldest pending) todo when this scrap was created
     pub active_todo_id: Option<i64>,
+    /// Reference note listed ahead of the chronological log
+    pub pinned: bool,
 }
 
 impl Scrap {
@@ -228,6 +328,19 @@ impl Scrap {
     pub fn content_html(&self) -> String {
         render_markdown_with_links(&self.content)
     }
+
+    /// Fields of the WebUI scraps card, shared with `track scrap list --json`.
+    /// `format_time` renders `created_at` for the caller.
+    pub fn card_json(&self, format_time: impl Fn(DateTime<Utc>) -> String) -> serde_json::Value {
+        serde_json::json!({
+            "scrap_id": self.scrap_id,
+            "content": self.content,
+            "content_html": self.content_html(),
+            "created_at": format_time(self.created_at),
+            "active_todo_id": self.active_todo_id,
+            "pinned": self.pinned,
+        })
+    }
 }
 
 /// Represents a JJ workspace associated with a task or TODO.
@@ -241,8 +354,7 @@ pub struct Worktree {
     pub path: String,
     pub branch: String,
     pub base_repo: Option<String>,
-    #[allow(dead_code)]
-    pub status: String,
+    pub status: WorktreeStatus,
     #[allow(dead_code)]
     pub created_at: DateTime<Utc>,
     #[allow(dead_code)]
@@ -251,6 +363,15 @@ pub struct Worktree {
     pub is_base: bool,
 }
 
+impl Worktree {
+    /// True for a record pointing at the repository's own checkout (a base
+    /// registered with `--create-base-worktree` or `--base-worktree`) rather
+    /// than a workspace track created.
+    pub fn is_main_checkout(&self) -> bool {
+        self.base_repo.as_deref() == Some(self.path.as_str())
+    }
+}
+
 /// Represents a remote repository link for a worktree.
 ///
 /// RepoLinks store URLs to remote repositories (e.g., GitHub, GitLab)
@@ -265,6 +386,9 @@ pub struct RepoLink {
     pub kind: String,
     #[allow(dead_code)]
     pub created_at: DateTime<Utc>,
+    /// Forge state (`open`, `merged`, `closed`) recorded by `track repo sync-links`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
 }
 
 /// Represents a repository associated with a task.
@@ -294,6 +418,72 @@ mod tests {
     use super::*;
     use chrono::Utc;
 
+    #[test]
+    fn test_estimate_rollup_excludes_cancelled() {
+        let todo = |status, estimate| Todo {
+            id: 1,
+            task_id: 1,
+            task_index: 1,
+            content: "Item".to_string(),
+            status,
+            worktree_requested: false,
+            requires_workspace: true,
+            created_at: Utc::now(),
+            completed_at: None,
+            estimate,
+            due_date: None,
+        };
+
+        assert_eq!(
+            EstimateRollup::from_todos(&[todo(TodoStatus::Pending, None)]),
+            None
+        );
+
+        let rollup = EstimateRollup::from_todos(&[
+            todo(TodoStatus::Pending, Some(3.0)),
+            todo(TodoStatus::Pending, None),
+            todo(TodoStatus::Done, Some(2.5)),
+            todo(TodoStatus::Cancelled, Some(8.0)),
+        ])
+        .unwrap();
+        assert_eq!(rollup.total, 5.5);
+        assert_eq!(rollup.remaining, 3.0);
+    }
+
+    #[test]
+    fn test_todo_is_overdue_only_while_pending() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        let todo = |status, due_date| Todo {
+            id: 1,
+            task_id: 1,
+            task_index: 1,
+            content: "Item".to_string(),
+            status,
+            worktree_requested: false,
+            requires_workspace: true,
+            created_at: Utc::now(),
+            completed_at: None,
+            estimate: None,
+            due_date,
+        };
+
+        assert!(todo(TodoStatus::Pending, today.pred_opt()).is_overdue(today));
+        assert!(!todo(TodoStatus::Pending, Some(today)).is_overdue(today));
+        assert!(!todo(TodoStatus::Pending, None).is_overdue(today));
+        assert!(!todo(TodoStatus::Done, today.pred_opt()).is_overdue(today));
+    }
+
+    #[test]
+    fn test_render_markdown_plain() {
+        let text = render_markdown_plain(
+            "# Plan\n\nSee [docs](https://example.com) and <https://x.dev>.\n\n- [x] one\n- [ ] `two`",
+        );
+        assert_eq!(
+            text,
+            "Plan\n\nSee docs (https://example.com) and https://x.dev.\n\n- [x] one\n- [ ] two"
+        );
+    }
+
     #[test]
     fn test_scrap_content_html_plain_text() {
         let scrap = Scrap {
@@ -303,6 +493,7 @@ mod tests {
             content: "This is a plain text scrap.".to_string(),
             created_at: Utc::now(),
             active_todo_id: None,
+            pinned: false,
         };
         let html = scrap.content_html();
         assert!(html.contains("<p>This is a plain text scrap.</p>"));
@@ -317,6 +508,7 @@ mod tests {
             content: "# Heading\n\nThis is **bold** and *italic*.".to_string(),
             created_at: Utc::now(),
             active_todo_id: None,
+            pinned: false,
         };
         let html = scrap.content_html();
         assert!(html.contains("<h1>Heading</h1>"));
@@ -333,6 +525,7 @@ mod tests {
             content: "Inline `code` and:\n\n```rust\nfn main() {}\n```".to_string(),
             created_at: Utc::now(),
             active_todo_id: None,
+            pinned: false,
         };
         let html = scrap.content_html();
         assert!(html.contains("<code>code</code>"));
@@ -349,6 +542,7 @@ mod tests {
             content: "- Item 1\n- Item 2\n- Item 3".to_string(),
             created_at: Utc::now(),
             active_todo_id: None,
+            pinned: false,
         };
         let html = scrap.content_html();
         assert!(html.contains("<ul>"));
@@ -367,6 +561,7 @@ mod tests {
             content: "[Example](https://example.com)".to_string(),
             created_at: Utc::now(),
             active_todo_id: None,
+            pinned: false,
         };
         let html = scrap.content_html();
         assert!(html.contains("href=\"https://example.com\""));
@@ -383,6 +578,7 @@ mod tests {
             content: "<script>alert('x')</script><b>safe</b>".to_string(),
             created_at: Utc::now(),
             active_todo_id: None,
+            pinned: false,
         };
         let html = scrap.content_html();
         assert!(!html.contains("<script>"));
@@ -399,6 +595,7 @@ mod tests {
             content: "Check out https://example.com for more info.".to_string(),
             created_at: Utc::now(),
             active_todo_id: None,
+            pinned: false,
         };
         let html = scrap.content_html();
         assert!(html.contains("target=\"_blank\""));
@@ -414,6 +611,7 @@ mod tests {
             content: "See https://example.com and http://test.org".to_string(),
             created_at: Utc::now(),
             active_todo_id: None,
+            pinned: false,
         };
         let html = scrap.content_html();
         assert!(html.contains("target=\"_blank\""));
@@ -430,6 +628,7 @@ mod tests {
             content: "Visit https://example.com/path?query=1, it's great!".to_string(),
             created_at: Utc::now(),
             active_todo_id: None,
+            pinned: false,
         };
         let html = scrap.content_html();
         // The comma should not be part of the link
@@ -446,6 +645,7 @@ mod tests {
             content: "Check [my site](https://example.com) and also https://test.com".to_string(),
             created_at: Utc::now(),
             active_todo_id: None,
+            pinned: false,
         };
         let html = scrap.content_html();
         // Markdown link should work normally
@@ -467,6 +667,8 @@ mod tests {
             requires_workspace: true,
             created_at: Utc::now(),
             completed_at: None,
+            estimate: None,
+            due_date: None,
         };
         let html = todo.content_html();
         assert!(html.contains("<p>This is a plain text todo.</p>"));
@@ -484,6 +686,8 @@ mod tests {
             requires_workspace: true,
             created_at: Utc::now(),
             completed_at: None,
+            estimate: None,
+            due_date: None,
         };
         let html = todo.content_html();
         assert!(html.contains("target=\"_blank\""));
@@ -500,6 +704,8 @@ mod tests {
             status: TodoStatus::Pending,
             created_at: Utc::now(),
             completed_at: None,
+            estimate: None,
+            due_date: None,
             worktree_requested: false,
             requires_workspace: true,
         };
@@ -519,6 +725,8 @@ mod tests {
             status: TodoStatus::Pending,
             created_at: Utc::now(),
             completed_at: None,
+            estimate: None,
+            due_date: None,
             worktree_requested: false,
             requires_workspace: true,
         };
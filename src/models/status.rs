@@ -3,36 +3,51 @@ use std::str::FromStr;
 
 /// Status of a task.
 ///
-/// Tasks can be either active (currently being worked on) or archived (completed or abandoned).
+/// Tasks are active (currently being worked on), blocked or paused (set aside
+/// but still listed), or archived (completed or abandoned).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum TaskStatus {
     /// Task is currently active and can be worked on
     Active,
+    /// Task is waiting on something outside the user's control
+    Blocked,
+    /// Task has been set aside for now
+    Paused,
     /// Task has been archived and is no longer active
     Archived,
 }
 
 impl TaskStatus {
     pub const ACTIVE: &'static str = "active";
+    pub const BLOCKED: &'static str = "blocked";
+    pub const PAUSED: &'static str = "paused";
     pub const ARCHIVED: &'static str = "archived";
 
+    /// Every status string, in declaration order (used for schema CHECK constraints).
+    pub const ALL: [&'static str; 4] = [Self::ACTIVE, Self::BLOCKED, Self::PAUSED, Self::ARCHIVED];
+
     /// Converts the status to its string representation.
     pub fn as_str(&self) -> &str {
         match self {
             TaskStatus::Active => Self::ACTIVE,
+            TaskStatus::Blocked => Self::BLOCKED,
+            TaskStatus::Paused => Self::PAUSED,
             TaskStatus::Archived => Self::ARCHIVED,
         }
     }
 
+    /// Whether the task is still open (anything but archived).
+    pub fn is_open(self) -> bool {
+        self != TaskStatus::Archived
+    }
+
     /// Returns whether a transition from `self` to `target` is allowed.
+    ///
+    /// Open tasks move freely between active, blocked, paused and archived;
+    /// archived tasks stay archived.
     pub fn can_transition_to(self, target: Self) -> bool {
-        matches!(
-            (self, target),
-            (TaskStatus::Active, TaskStatus::Active)
-                | (TaskStatus::Active, TaskStatus::Archived)
-                | (TaskStatus::Archived, TaskStatus::Archived)
-        )
+        self.is_open() || target == TaskStatus::Archived
     }
 }
 
@@ -42,6 +57,8 @@ impl FromStr for TaskStatus {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             Self::ACTIVE => Ok(TaskStatus::Active),
+            Self::BLOCKED => Ok(TaskStatus::Blocked),
+            Self::PAUSED => Ok(TaskStatus::Paused),
             Self::ARCHIVED => Ok(TaskStatus::Archived),
             _ => Err(format!("Invalid TaskStatus: {}", s)),
         }
@@ -118,13 +135,70 @@ impl FromStr for TodoStatus {
     }
 }
 
+/// Status of a worktree record.
+///
+/// Worktrees are active while their directory is in use; merged and removed
+/// records are kept as history once the directory is gone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorktreeStatus {
+    /// Worktree directory exists and can be worked in
+    Active,
+    /// Worktree's changes were merged and its directory cleaned up
+    Merged,
+    /// Worktree was removed without merging
+    Removed,
+}
+
+impl WorktreeStatus {
+    pub const ACTIVE: &'static str = "active";
+    pub const MERGED: &'static str = "merged";
+    pub const REMOVED: &'static str = "removed";
+
+    /// Converts the status to its string representation.
+    pub fn as_str(&self) -> &str {
+        match self {
+            WorktreeStatus::Active => Self::ACTIVE,
+            WorktreeStatus::Merged => Self::MERGED,
+            WorktreeStatus::Removed => Self::REMOVED,
+        }
+    }
+}
+
+impl FromStr for WorktreeStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            Self::ACTIVE => Ok(WorktreeStatus::Active),
+            Self::MERGED => Ok(WorktreeStatus::Merged),
+            Self::REMOVED => Ok(WorktreeStatus::Removed),
+            _ => Err(format!("Invalid WorktreeStatus: {}", s)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_worktree_status_round_trip() {
+        for status in [
+            WorktreeStatus::Active,
+            WorktreeStatus::Merged,
+            WorktreeStatus::Removed,
+        ] {
+            assert_eq!(status.as_str().parse::<WorktreeStatus>(), Ok(status));
+        }
+        assert!("deleted".parse::<WorktreeStatus>().is_err());
+    }
+
     #[test]
     fn test_task_status_as_str() {
         assert_eq!(TaskStatus::Active.as_str(), "active");
+        assert_eq!(TaskStatus::Blocked.as_str(), "blocked");
+        assert_eq!(TaskStatus::Paused.as_str(), "paused");
         assert_eq!(TaskStatus::Archived.as_str(), "archived");
     }
 
@@ -138,6 +212,9 @@ mod tests {
             "archived".parse::<TaskStatus>(),
             Ok(TaskStatus::Archived)
         ));
+        for status in TaskStatus::ALL {
+            assert_eq!(status.parse::<TaskStatus>().unwrap().as_str(), status);
+        }
         assert!("invalid".parse::<TaskStatus>().is_err());
     }
 
@@ -146,6 +223,11 @@ mod tests {
         assert!(TaskStatus::Active.can_transition_to(TaskStatus::Archived));
         assert!(!TaskStatus::Archived.can_transition_to(TaskStatus::Active));
         assert!(TaskStatus::Archived.can_transition_to(TaskStatus::Archived));
+        assert!(TaskStatus::Active.can_transition_to(TaskStatus::Blocked));
+        assert!(TaskStatus::Blocked.can_transition_to(TaskStatus::Paused));
+        assert!(TaskStatus::Paused.can_transition_to(TaskStatus::Active));
+        assert!(TaskStatus::Paused.can_transition_to(TaskStatus::Archived));
+        assert!(!TaskStatus::Archived.can_transition_to(TaskStatus::Blocked));
     }
 
     #[test]
@@ -0,0 +1,33 @@
+/// Which tasks `TaskService::list_tasks` returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskListFilter {
+    /// Open tasks: active, blocked, and paused (default).
+    #[default]
+    Active,
+    /// Archived tasks only.
+    Archived,
+    /// Open and archived tasks.
+    All,
+}
+
+impl TaskListFilter {
+    pub fn from_flags(all: bool, archived_only: bool) -> Self {
+        if archived_only {
+            Self::Archived
+        } else if all {
+            Self::All
+        } else {
+            Self::Active
+        }
+    }
+}
+
+/// Order of the tasks returned by `TaskService::list_tasks_ordered`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskOrder {
+    /// Newest created first (default).
+    #[default]
+    Created,
+    /// Most recently active first; untouched tasks fall back to their creation time.
+    Recent,
+}
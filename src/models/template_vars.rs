@@ -0,0 +1,97 @@
+use regex::{Captures, Regex};
+use std::str::FromStr;
+
+/// A `NAME=VALUE` pair substituted into `{{NAME}}` placeholders of template TODOs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateVar {
+    pub name: String,
+    pub value: String,
+}
+
+impl FromStr for TemplateVar {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected NAME=VALUE, got '{s}'"))?;
+        let name = name.trim();
+        if name.is_empty() || !name.chars().all(is_var_char) {
+            return Err(format!(
+                "invalid variable name '{name}': use letters, digits, '_' or '-'"
+            ));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            value: value.to_string(),
+        })
+    }
+}
+
+fn is_var_char(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || ch == '_' || ch == '-'
+}
+
+/// Replaces `{{name}}` placeholders (whitespace inside the braces is allowed) with the
+/// matching variable. Placeholders without a value are left intact and their names
+/// are returned so the caller can warn about them.
+pub fn fill_template_vars(content: &str, vars: &[TemplateVar]) -> (String, Vec<String>) {
+    let placeholder = Regex::new(r"\{\{\s*([A-Za-z0-9_-]+)\s*\}\}").unwrap();
+    let mut unfilled = Vec::new();
+
+    let filled = placeholder.replace_all(content, |caps: &Captures| {
+        let name = &caps[1];
+        match vars.iter().find(|var| var.name == name) {
+            Some(var) => var.value.clone(),
+            None => {
+                if !unfilled.iter().any(|n| n == name) {
+                    unfilled.push(name.to_string());
+                }
+                caps[0].to_string()
+            }
+        }
+    });
+
+    (filled.into_owned(), unfilled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn var(name: &str, value: &str) -> TemplateVar {
+        TemplateVar {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_name_value_pairs() {
+        assert_eq!(
+            "version=1.2=rc".parse::<TemplateVar>().unwrap(),
+            var("version", "1.2=rc")
+        );
+        assert_eq!("empty=".parse::<TemplateVar>().unwrap(), var("empty", ""));
+        assert!("version".parse::<TemplateVar>().is_err());
+        assert!("=1.2".parse::<TemplateVar>().is_err());
+        assert!("bad name=x".parse::<TemplateVar>().is_err());
+    }
+
+    #[test]
+    fn fills_known_placeholders_and_reports_unfilled() {
+        let (filled, unfilled) = fill_template_vars(
+            "Release {{version}} to {{ env }}, then tag {{version}} ({{owner}})",
+            &[var("version", "1.2"), var("unused", "x")],
+        );
+        assert_eq!(filled, "Release 1.2 to {{ env }}, then tag 1.2 ({{owner}})");
+        assert_eq!(unfilled, vec!["env".to_string(), "owner".to_string()]);
+    }
+
+    #[test]
+    fn leaves_content_without_placeholders_untouched() {
+        let (filled, unfilled) = fill_template_vars("Plain {braces} text", &[var("a", "b")]);
+        assert_eq!(filled, "Plain {braces} text");
+        assert!(unfilled.is_empty());
+    }
+}
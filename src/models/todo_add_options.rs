@@ -1,10 +1,16 @@
+use chrono::NaiveDate;
+
 /// Options when creating a new TODO.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct TodoAddOptions {
     /// Legacy per-TODO worktree flag (deprecated; use jj-task per task instead).
     pub worktree_requested: bool,
     /// When true (default), a jj-task/git workspace is required before execute.
     pub requires_workspace: bool,
+    /// Planning estimate (points or hours); must be finite and non-negative.
+    pub estimate: Option<f64>,
+    /// Deadline for the TODO.
+    pub due_date: Option<NaiveDate>,
 }
 
 impl Default for TodoAddOptions {
@@ -12,6 +18,8 @@ impl Default for TodoAddOptions {
         Self {
             worktree_requested: false,
             requires_workspace: true,
+            estimate: None,
+            due_date: None,
         }
     }
 }
@@ -21,6 +29,40 @@ impl TodoAddOptions {
         Self {
             worktree_requested: worktree,
             requires_workspace: !no_workspace,
+            estimate: None,
+            due_date: None,
+        }
+    }
+
+    pub fn with_estimate(self, estimate: Option<f64>) -> Self {
+        Self { estimate, ..self }
+    }
+
+    pub fn with_due_date(self, due_date: Option<NaiveDate>) -> Self {
+        Self { due_date, ..self }
+    }
+}
+
+/// Where a new TODO is placed in the task's ordering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TodoPosition {
+    /// Append after the last TODO (default).
+    #[default]
+    End,
+    /// Insert before the current (oldest pending) TODO, making it the next one.
+    Top,
+    /// Insert right after the current (oldest pending) TODO.
+    AfterCurrent,
+}
+
+impl TodoPosition {
+    pub fn from_flags(top: bool, after_current: bool) -> Self {
+        if top {
+            Self::Top
+        } else if after_current {
+            Self::AfterCurrent
+        } else {
+            Self::End
         }
     }
 }
@@ -603,6 +603,7 @@ mod tests {
             alias: None,
             is_today_task: false,
             created_at: Utc::now(),
+            last_active_at: None,
         }
     }
 
@@ -617,6 +618,8 @@ mod tests {
             requires_workspace: true,
             created_at: Utc::now(),
             completed_at: None,
+            estimate: None,
+            due_date: None,
         }
     }
 
@@ -631,6 +634,8 @@ mod tests {
             requires_workspace: false,
             created_at: Utc::now(),
             completed_at: None,
+            estimate: None,
+            due_date: None,
         }
     }
 
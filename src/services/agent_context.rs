@@ -44,7 +44,7 @@ pub fn build_agent_extensions(
             let lifecycle = workspace_lifecycle(todo, worktrees);
             let bookmark = if todo.worktree_requested {
                 worktree_service
-                    .get_todo_branch_name(task.id, task.ticket_id.as_deref(), todo.task_index)
+                    .get_todo_branch_name(None, task.id, task.ticket_id.as_deref(), todo.task_index)
                     .ok()
             } else {
                 None
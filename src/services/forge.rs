@@ -0,0 +1,459 @@
+//! Pull request / issue state lookups against GitHub and GitLab.
+//!
+//! Used by `track repo sync-links` to annotate repository links with their
+//! current state, and by `track todo done --close-issue` to close the task's
+//! ticket issue. Tokens are optional: `GITHUB_TOKEN` (or `GH_TOKEN`) and
+//! `GITLAB_TOKEN` are sent when set, otherwise requests go out anonymously
+//! and only public projects resolve. `GITLAB_TOKEN` only goes to gitlab.com
+//! and the self-hosted instances listed in `GITLAB_HOST` (comma-separated),
+//! since any URL with a `/-/issues/N` path looks like GitLab.
+
+use crate::utils::{http, Result, TrackError};
+
+/// A pull request, merge request, or issue on a supported forge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ForgeRef {
+    GitHub {
+        owner: String,
+        repo: String,
+        number: u64,
+        pull: bool,
+    },
+    GitLab {
+        /// `https://<host>` the project lives on
+        origin: String,
+        /// Full project path, e.g. `group/subgroup/project`
+        project: String,
+        number: u64,
+        merge_request: bool,
+    },
+}
+
+impl ForgeRef {
+    /// Recognizes GitHub `/pull/N` and `/issues/N` URLs and GitLab
+    /// `/-/merge_requests/N` and `/-/issues/N` URLs.
+    pub fn parse(url: &str) -> Option<Self> {
+        let rest = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))?;
+        let rest = rest.split(['?', '#']).next()?.trim_end_matches('/');
+        let (host, path) = rest.split_once('/')?;
+
+        if host == "github.com" {
+            let parts: Vec<&str> = path.split('/').collect();
+            if let [owner, repo, kind, number, ..] = parts.as_slice() {
+                let pull = match *kind {
+                    "pull" => true,
+                    "issues" => false,
+                    _ => return None,
+                };
+                return Some(ForgeRef::GitHub {
+                    owner: owner.to_string(),
+                    repo: repo.to_string(),
+                    number: number.parse().ok()?,
+                    pull,
+                });
+            }
+            return None;
+        }
+
+        let (project, tail) = path.split_once("/-/")?;
+        let (kind, number) = tail.split_once('/')?;
+        let merge_request = match kind {
+            "merge_requests" => true,
+            "issues" => false,
+            _ => return None,
+        };
+        let number = number.split('/').next()?.parse().ok()?;
+        Some(ForgeRef::GitLab {
+            origin: format!("https://{host}"),
+            project: project.to_string(),
+            number,
+            merge_request,
+        })
+    }
+
+    /// Resolves a task ticket to a forge reference: the ticket URL when it is a
+    /// recognizable forge URL, else an `owner/repo/N` ticket ID as a GitHub issue.
+    pub fn from_ticket(ticket_id: Option<&str>, ticket_url: Option<&str>) -> Option<Self> {
+        if let Some(forge_ref) = ticket_url.and_then(Self::parse) {
+            return Some(forge_ref);
+        }
+
+        let parts: Vec<&str> = ticket_id?.split('/').collect();
+        match parts.as_slice() {
+            [owner, repo, number] if !owner.is_empty() && !repo.is_empty() => {
+                Some(ForgeRef::GitHub {
+                    owner: owner.to_string(),
+                    repo: repo.to_string(),
+                    number: number.parse().ok()?,
+                    pull: false,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Web URL of the pull request, merge request, or issue.
+    pub fn web_url(&self) -> String {
+        match self {
+            ForgeRef::GitHub {
+                owner,
+                repo,
+                number,
+                pull,
+            } => {
+                let kind = if *pull { "pull" } else { "issues" };
+                format!("https://github.com/{owner}/{repo}/{kind}/{number}")
+            }
+            ForgeRef::GitLab {
+                origin,
+                project,
+                number,
+                merge_request,
+            } => {
+                let kind = if *merge_request {
+                    "merge_requests"
+                } else {
+                    "issues"
+                };
+                format!("{origin}/{project}/-/{kind}/{number}")
+            }
+        }
+    }
+}
+
+/// Fetches PR/issue states; API base URLs are injectable for tests.
+pub struct ForgeClient {
+    github_api: String,
+    gitlab_api: Option<String>,
+}
+
+impl Default for ForgeClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ForgeClient {
+    pub fn new() -> Self {
+        Self {
+            github_api: "https://api.github.com".to_string(),
+            gitlab_api: None,
+        }
+    }
+
+    /// Points both forges at fixed API roots (GitLab normally uses `<origin>/api/v4`).
+    pub fn with_api_urls(github_api: &str, gitlab_api: &str) -> Self {
+        Self {
+            github_api: github_api.trim_end_matches('/').to_string(),
+            gitlab_api: Some(gitlab_api.trim_end_matches('/').to_string()),
+        }
+    }
+
+    /// Returns `open`, `merged`, or `closed`.
+    pub fn fetch_state(&self, forge_ref: &ForgeRef) -> Result<String> {
+        match forge_ref {
+            ForgeRef::GitHub {
+                owner,
+                repo,
+                number,
+                pull,
+            } => {
+                let kind = if *pull { "pulls" } else { "issues" };
+                let url = format!("{}/repos/{owner}/{repo}/{kind}/{number}", self.github_api);
+                let token = env_token(&["GITHUB_TOKEN", "GH_TOKEN"]).map(|t| format!("Bearer {t}"));
+                let mut headers = vec![("Accept", "application/vnd.github+json")];
+                if let Some(token) = &token {
+                    headers.push(("Authorization", token.as_str()));
+                }
+
+                let body = http::get_json(&url, &headers)?;
+                if body["merged"].as_bool() == Some(true) {
+                    return Ok("merged".to_string());
+                }
+                normalize_state(&body["state"])
+            }
+            ForgeRef::GitLab {
+                origin,
+                project,
+                number,
+                merge_request,
+            } => {
+                let api = self
+                    .gitlab_api
+                    .clone()
+                    .unwrap_or_else(|| format!("{origin}/api/v4"));
+                let kind = if *merge_request {
+                    "merge_requests"
+                } else {
+                    "issues"
+                };
+                let url = format!(
+                    "{api}/projects/{}/{kind}/{number}",
+                    project.replace('/', "%2F")
+                );
+                let token = gitlab_token(origin);
+                let mut headers = Vec::new();
+                if let Some(token) = &token {
+                    headers.push(("PRIVATE-TOKEN", token.as_str()));
+                }
+
+                normalize_state(&http::get_json(&url, &headers)?["state"])
+            }
+        }
+    }
+
+    /// Closes an issue. Pull and merge requests are rejected rather than closed,
+    /// as are GitLab issues on hosts that are not gitlab.com or in `GITLAB_HOST`.
+    pub fn close_issue(&self, forge_ref: &ForgeRef) -> Result<()> {
+        match forge_ref {
+            ForgeRef::GitHub {
+                owner,
+                repo,
+                number,
+                pull: false,
+            } => {
+                let url = format!("{}/repos/{owner}/{repo}/issues/{number}", self.github_api);
+                let token = env_token(&["GITHUB_TOKEN", "GH_TOKEN"]).map(|t| format!("Bearer {t}"));
+                let mut headers = vec![("Accept", "application/vnd.github+json")];
+                if let Some(token) = &token {
+                    headers.push(("Authorization", token.as_str()));
+                }
+
+                http::send_json(
+                    "PATCH",
+                    &url,
+                    &headers,
+                    &serde_json::json!({ "state": "closed" }),
+                )
+            }
+            ForgeRef::GitLab {
+                origin,
+                project,
+                number,
+                merge_request: false,
+            } => {
+                let configured = std::env::var("GITLAB_HOST").ok();
+                if !is_trusted_gitlab_origin(origin, configured.as_deref()) {
+                    return Err(TrackError::Http(format!(
+                        "refusing to close {}: {origin} is not gitlab.com or listed in GITLAB_HOST",
+                        forge_ref.web_url()
+                    )));
+                }
+                let api = self
+                    .gitlab_api
+                    .clone()
+                    .unwrap_or_else(|| format!("{origin}/api/v4"));
+                let url = format!(
+                    "{api}/projects/{}/issues/{number}",
+                    project.replace('/', "%2F")
+                );
+                let token = gitlab_token(origin);
+                let mut headers = Vec::new();
+                if let Some(token) = &token {
+                    headers.push(("PRIVATE-TOKEN", token.as_str()));
+                }
+
+                http::send_json(
+                    "PUT",
+                    &url,
+                    &headers,
+                    &serde_json::json!({ "state_event": "close" }),
+                )
+            }
+            _ => Err(TrackError::Http(format!(
+                "{} is a pull/merge request, not an issue",
+                forge_ref.web_url()
+            ))),
+        }
+    }
+}
+
+fn env_token(names: &[&str]) -> Option<String> {
+    names
+        .iter()
+        .find_map(|name| std::env::var(name).ok().filter(|value| !value.is_empty()))
+}
+
+/// `GITLAB_TOKEN`, but only for an origin the user trusts with it.
+fn gitlab_token(origin: &str) -> Option<String> {
+    let configured = std::env::var("GITLAB_HOST").ok();
+    if is_trusted_gitlab_origin(origin, configured.as_deref()) {
+        env_token(&["GITLAB_TOKEN"])
+    } else {
+        None
+    }
+}
+
+/// True for gitlab.com and the hosts in `configured` (a `GITLAB_HOST` value:
+/// comma-separated host names, optionally with an `https://` prefix).
+fn is_trusted_gitlab_origin(origin: &str, configured: Option<&str>) -> bool {
+    let host_of = |value: &str| {
+        value
+            .trim()
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .trim_end_matches('/')
+            .to_ascii_lowercase()
+    };
+    let host = host_of(origin);
+    host == "gitlab.com"
+        || configured
+            .unwrap_or_default()
+            .split(',')
+            .map(host_of)
+            .any(|trusted| !trusted.is_empty() && trusted == host)
+}
+
+/// Maps forge-specific state names (`opened`, `locked`, ...) onto open/merged/closed.
+fn normalize_state(state: &serde_json::Value) -> Result<String> {
+    let state = match state.as_str() {
+        Some("open") | Some("opened") => "open",
+        Some("merged") => "merged",
+        Some("closed") | Some("locked") => "closed",
+        other => {
+            return Err(TrackError::Http(format!(
+                "unexpected state in forge response: {}",
+                other.unwrap_or("missing")
+            )))
+        }
+    };
+    Ok(state.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::http::test_server::serve;
+
+    /// Answers a single request with `body` and returns the base URL.
+    fn serve_once(body: &'static str) -> String {
+        serve(vec![(200, body)]).0
+    }
+
+    #[test]
+    fn parses_github_pull_and_issue_urls() {
+        assert_eq!(
+            ForgeRef::parse("https://github.com/o/r/pull/12/files"),
+            Some(ForgeRef::GitHub {
+                owner: "o".to_string(),
+                repo: "r".to_string(),
+                number: 12,
+                pull: true,
+            })
+        );
+        assert_eq!(
+            ForgeRef::parse("https://github.com/o/r/issues/3#issuecomment-1"),
+            Some(ForgeRef::GitHub {
+                owner: "o".to_string(),
+                repo: "r".to_string(),
+                number: 3,
+                pull: false,
+            })
+        );
+        assert_eq!(ForgeRef::parse("https://github.com/o/r/pulls?q=x"), None);
+    }
+
+    #[test]
+    fn parses_gitlab_merge_request_urls() {
+        assert_eq!(
+            ForgeRef::parse("https://gitlab.example.com/g/sub/p/-/merge_requests/7/diffs"),
+            Some(ForgeRef::GitLab {
+                origin: "https://gitlab.example.com".to_string(),
+                project: "g/sub/p".to_string(),
+                number: 7,
+                merge_request: true,
+            })
+        );
+        assert_eq!(ForgeRef::parse("https://example.com/docs/page"), None);
+    }
+
+    #[test]
+    fn resolves_ticket_url_before_ticket_id() {
+        assert_eq!(
+            ForgeRef::from_ticket(Some("o/r/9"), None),
+            Some(ForgeRef::GitHub {
+                owner: "o".to_string(),
+                repo: "r".to_string(),
+                number: 9,
+                pull: false,
+            })
+        );
+        let gitlab =
+            ForgeRef::from_ticket(Some("g/p/9"), Some("https://gitlab.com/g/p/-/issues/10"))
+                .unwrap();
+        assert_eq!(gitlab.web_url(), "https://gitlab.com/g/p/-/issues/10");
+        assert_eq!(ForgeRef::from_ticket(Some("PROJ-123"), None), None);
+        assert_eq!(
+            ForgeRef::from_ticket(Some("PROJ-123"), Some("https://jira.example.com/PROJ-123")),
+            None
+        );
+    }
+
+    #[test]
+    fn close_issue_patches_github_and_rejects_pulls() {
+        let github = serve_once("{}");
+        let client = ForgeClient::with_api_urls(&github, "http://unused");
+        let issue = ForgeRef::parse("https://github.com/o/r/issues/2").unwrap();
+        client.close_issue(&issue).unwrap();
+
+        let pull = ForgeRef::parse("https://github.com/o/r/pull/2").unwrap();
+        assert!(client.close_issue(&pull).is_err());
+    }
+
+    #[test]
+    fn gitlab_token_only_goes_to_trusted_hosts() {
+        assert!(is_trusted_gitlab_origin("https://gitlab.com", None));
+        assert!(!is_trusted_gitlab_origin("https://evil.example.com", None));
+        assert!(is_trusted_gitlab_origin(
+            "https://gitlab.corp.example",
+            Some("git.other.example, https://GitLab.corp.example/")
+        ));
+        assert!(!is_trusted_gitlab_origin(
+            "https://gitlab.corp.example.evil.com",
+            Some("gitlab.corp.example")
+        ));
+        assert!(!is_trusted_gitlab_origin(
+            "https://evil.example.com",
+            Some("")
+        ));
+    }
+
+    #[test]
+    fn close_issue_refuses_untrusted_gitlab_hosts() {
+        // The API URL would accept the request; the origin alone decides
+        let gitlab = serve_once("{}");
+        let client = ForgeClient::with_api_urls("http://unused", &gitlab);
+        let issue = ForgeRef::parse("https://evil.example.com/g/p/-/issues/1").unwrap();
+        let err = client.close_issue(&issue).unwrap_err();
+        assert!(err.to_string().contains("GITLAB_HOST"), "{err}");
+
+        client
+            .close_issue(&ForgeRef::parse("https://gitlab.com/g/p/-/issues/1").unwrap())
+            .unwrap();
+    }
+
+    #[test]
+    fn normalizes_forge_states() {
+        assert_eq!(normalize_state(&"opened".into()).unwrap(), "open");
+        assert_eq!(normalize_state(&"locked".into()).unwrap(), "closed");
+        assert!(normalize_state(&serde_json::Value::Null).is_err());
+    }
+
+    #[test]
+    fn fetch_state_reports_merged_github_pulls() {
+        let github = serve_once(r#"{"state":"closed","merged":true}"#);
+        let client = ForgeClient::with_api_urls(&github, "http://unused");
+        let forge_ref = ForgeRef::parse("https://github.com/o/r/pull/1").unwrap();
+        assert_eq!(client.fetch_state(&forge_ref).unwrap(), "merged");
+    }
+
+    #[test]
+    fn fetch_state_reads_gitlab_issue_state() {
+        let gitlab = serve_once(r#"{"state":"opened"}"#);
+        let client = ForgeClient::with_api_urls("http://unused", &gitlab);
+        let forge_ref = ForgeRef::parse("https://gitlab.com/g/p/-/issues/4").unwrap();
+        assert_eq!(client.fetch_state(&forge_ref).unwrap(), "open");
+    }
+}
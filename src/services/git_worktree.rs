@@ -1,4 +1,4 @@
-use crate::utils::{Result, TrackError};
+use crate::utils::{CommandExt, Result, TrackError};
 use std::path::Path;
 use std::process::Command;
 
@@ -19,7 +19,7 @@ pub fn git_worktree_path(repo_path: &str, slug: &str) -> String {
 pub fn is_git_repository(repo_path: &str) -> bool {
     Command::new("git")
         .args(["-C", repo_path, "rev-parse", "--git-dir"])
-        .output()
+        .logged_output()
         .map(|output| output.status.success())
         .unwrap_or(false)
 }
@@ -28,10 +28,30 @@ pub fn git_worktree_exists(path: &str) -> bool {
     Path::new(path).is_dir()
 }
 
+/// True when `path` is a checked-out git worktree (has a `.git` entry), as
+/// opposed to a stray directory that `git worktree add` would refuse.
+fn is_checked_out_worktree(path: &str) -> bool {
+    Path::new(path).join(".git").exists()
+}
+
+/// Name of the branch checked out in `repo_path`, or `None` when HEAD is
+/// detached or the path is not a git repository.
+pub fn current_git_branch(repo_path: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["-C", repo_path, "rev-parse", "--abbrev-ref", "HEAD"])
+        .logged_output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!branch.is_empty() && branch != "HEAD").then_some(branch)
+}
+
 pub fn branch_exists(repo_path: &str, branch: &str) -> Result<bool> {
     let output = Command::new("git")
         .args(["-C", repo_path, "show-ref", "--verify", "--quiet", branch])
-        .output()?;
+        .logged_output()?;
     Ok(output.status.success())
 }
 
@@ -42,7 +62,7 @@ pub fn create_git_worktree(repo_path: &str, slug: &str, base_ref: &str) -> Resul
     }
 
     let worktree_path = git_worktree_path(repo_path, slug);
-    if git_worktree_exists(&worktree_path) {
+    if is_checked_out_worktree(&worktree_path) {
         return Ok(worktree_path);
     }
 
@@ -54,16 +74,19 @@ pub fn create_git_worktree(repo_path: &str, slug: &str, base_ref: &str) -> Resul
 
     let fetch = Command::new("git")
         .args(["-C", repo_path, "fetch", "--all", "--prune"])
-        .output()?;
+        .logged_output()?;
     if !fetch.status.success() {
         let stderr = String::from_utf8_lossy(&fetch.stderr);
-        return Err(TrackError::Git(format!("git fetch failed: {stderr}")));
+        return Err(TrackError::Git(format!(
+            "git fetch --all --prune failed in {repo_path}: {}",
+            stderr.trim()
+        )));
     }
 
     let create = if branch_exists(repo_path, &format!("refs/heads/{branch}"))? {
         Command::new("git")
             .args(["-C", repo_path, "worktree", "add", &worktree_path, &branch])
-            .output()?
+            .logged_output()?
     } else {
         Command::new("git")
             .args([
@@ -76,13 +99,17 @@ pub fn create_git_worktree(repo_path: &str, slug: &str, base_ref: &str) -> Resul
                 &worktree_path,
                 base_ref,
             ])
-            .output()?
+            .logged_output()?
     };
 
     if !create.status.success() {
         let stderr = String::from_utf8_lossy(&create.stderr);
+        if stderr.contains("already exists") && Path::new(&worktree_path).exists() {
+            return Err(TrackError::WorktreeExists(worktree_path));
+        }
         return Err(TrackError::Git(format!(
-            "git worktree add failed: {stderr}"
+            "git worktree add failed (repo: {repo_path}, path: {worktree_path}, branch: {branch}, base: {base_ref}): {}",
+            stderr.trim()
         )));
     }
 
@@ -92,7 +119,7 @@ pub fn create_git_worktree(repo_path: &str, slug: &str, base_ref: &str) -> Resul
 pub fn repo_has_uncommitted_changes(repo_path: &str) -> Result<bool> {
     let output = Command::new("git")
         .args(["-C", repo_path, "status", "--porcelain"])
-        .output()?;
+        .logged_output()?;
     if !output.status.success() {
         return Err(TrackError::FailedRepoStatusCheck(repo_path.to_string()));
     }
@@ -103,7 +130,7 @@ pub fn repo_has_uncommitted_changes(repo_path: &str) -> Result<bool> {
 pub fn base_repo_has_changes(repo_path: &str, slug: &str) -> Result<bool> {
     let output = Command::new("git")
         .args(["-C", repo_path, "status", "--porcelain"])
-        .output()?;
+        .logged_output()?;
     if !output.status.success() {
         return Err(TrackError::FailedRepoStatusCheck(repo_path.to_string()));
     }
@@ -132,6 +159,88 @@ fn is_task_worktree_path(path: &str, slug: &str) -> bool {
 mod tests {
     use super::*;
 
+    fn git(repo: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args([
+                "-c",
+                "user.name=track",
+                "-c",
+                "user.email=track@example.com",
+            ])
+            .args(args)
+            .output()
+            .expect("failed to run git");
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn create_git_worktree_into_non_empty_dir_reports_existing() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = temp_dir.path();
+        git(repo, &["init", "-q"]);
+        git(repo, &["commit", "-q", "--allow-empty", "-m", "init"]);
+
+        let repo_path = repo.to_str().unwrap();
+        let target = git_worktree_path(repo_path, "proj-1");
+        std::fs::create_dir_all(&target).unwrap();
+        std::fs::write(Path::new(&target).join("stray.txt"), "x").unwrap();
+
+        let result = create_git_worktree(repo_path, "proj-1", "HEAD");
+        assert!(matches!(result, Err(TrackError::WorktreeExists(path)) if path == target));
+    }
+
+    #[test]
+    fn create_git_worktree_reuses_checked_out_worktree() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = temp_dir.path();
+        git(repo, &["init", "-q"]);
+        git(repo, &["commit", "-q", "--allow-empty", "-m", "init"]);
+
+        let repo_path = repo.to_str().unwrap();
+        let first = create_git_worktree(repo_path, "proj-3", "HEAD").unwrap();
+        assert!(git_worktree_exists(&first));
+        assert_eq!(
+            create_git_worktree(repo_path, "proj-3", "HEAD").unwrap(),
+            first
+        );
+    }
+
+    #[test]
+    fn create_git_worktree_error_names_command_inputs() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = temp_dir.path();
+        git(repo, &["init", "-q"]);
+        git(repo, &["commit", "-q", "--allow-empty", "-m", "init"]);
+
+        let repo_path = repo.to_str().unwrap();
+        let err = create_git_worktree(repo_path, "proj-2", "no-such-ref").unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains(repo_path));
+        assert!(message.contains("track/proj-2"));
+        assert!(message.contains("no-such-ref"));
+    }
+
+    #[test]
+    fn current_git_branch_ignores_detached_head() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = temp_dir.path();
+        git(repo, &["init", "-q", "-b", "develop"]);
+        git(repo, &["commit", "-q", "--allow-empty", "-m", "init"]);
+
+        let repo_path = repo.to_str().unwrap();
+        assert_eq!(current_git_branch(repo_path).as_deref(), Some("develop"));
+
+        git(repo, &["checkout", "-q", "--detach"]);
+        assert_eq!(current_git_branch(repo_path), None);
+    }
+
     #[test]
     fn git_branch_and_path() {
         assert_eq!(git_branch_name("proj-123"), "track/proj-123");
@@ -2,6 +2,7 @@
 
 mod map;
 
+use crate::utils::CommandExt;
 use map::load_map;
 use serde::Serialize;
 use std::path::{Path, PathBuf};
@@ -11,7 +12,7 @@ use std::process::Command;
 pub fn repo_key(repo_path: &str) -> String {
     let output = Command::new("git")
         .args(["-C", repo_path, "remote", "get-url", "origin"])
-        .output();
+        .logged_output();
 
     if let Ok(output) = output {
         if output.status.success() {
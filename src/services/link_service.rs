@@ -38,6 +38,7 @@ impl<'a> LinkService<'a> {
             )?;
 
             let link_id = conn.last_insert_rowid();
+            self.db.touch_task(task_id)?;
             self.db.increment_rev("links")?;
             self.get_link(link_id)
         })
@@ -85,7 +86,48 @@ impl<'a> LinkService<'a> {
         Ok(links)
     }
 
-    fn validate_url(&self, url: &str) -> Result<()> {
+    /// Moves the link at `from` into the slot currently held by link `to`.
+    ///
+    /// Links in between shift by one; indices outside that range are untouched.
+    pub fn move_link(&self, task_id: i64, from: i64, to: i64) -> Result<Link> {
+        self.db.with_transaction(|| {
+            let links = self.list_links(task_id)?;
+            let position = |index: i64| {
+                links
+                    .iter()
+                    .position(|link| link.task_index == index)
+                    .ok_or(TrackError::LinkIndexNotFound(index))
+            };
+            let from_pos = position(from)?;
+            let to_pos = position(to)?;
+
+            let (lo, hi) = (from_pos.min(to_pos), from_pos.max(to_pos));
+            let slots: Vec<i64> = links[lo..=hi].iter().map(|link| link.task_index).collect();
+            let mut reordered: Vec<i64> = links[lo..=hi].iter().map(|link| link.id).collect();
+            let moved_id = reordered.remove(from_pos - lo);
+            reordered.insert(to_pos - lo, moved_id);
+
+            // Two passes through negative indices keep (task_id, task_index) unique.
+            let conn = self.db.get_connection();
+            for (link_id, slot) in reordered.iter().zip(&slots) {
+                conn.execute(
+                    "UPDATE links SET task_index = ?1 WHERE id = ?2",
+                    params![-slot, link_id],
+                )?;
+            }
+            conn.execute(
+                "UPDATE links SET task_index = -task_index WHERE task_id = ?1 AND task_index < 0",
+                params![task_id],
+            )?;
+
+            self.db.touch_task(task_id)?;
+            self.db.increment_rev("links")?;
+            self.get_link(moved_id)
+        })
+    }
+
+    /// Rejects URLs that are not http(s).
+    pub fn validate_url(&self, url: &str) -> Result<()> {
         if url.starts_with("http://") || url.starts_with("https://") {
             Ok(())
         } else {
@@ -96,12 +138,17 @@ impl<'a> LinkService<'a> {
     /// Delete a link by its ID
     pub fn delete_link(&self, link_id: i64) -> Result<()> {
         let conn = self.db.get_connection();
-        let affected = conn.execute("DELETE FROM links WHERE id = ?1", params![link_id])?;
-
-        if affected == 0 {
-            return Err(TrackError::LinkNotFound(link_id));
-        }
+        let task_id: i64 = conn
+            .query_row(
+                "SELECT task_id FROM links WHERE id = ?1",
+                params![link_id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .ok_or(TrackError::LinkNotFound(link_id))?;
+        conn.execute("DELETE FROM links WHERE id = ?1", params![link_id])?;
 
+        self.db.touch_task(task_id)?;
         self.db.increment_rev("links")?;
         Ok(())
     }
@@ -117,6 +164,55 @@ impl<'a> ScrapService<'a> {
     }
 
     pub fn add_scrap(&self, task_id: i64, content: &str) -> Result<Scrap> {
+        self.add_scrap_with_pin(task_id, content, false)
+    }
+
+    /// Adds a scrap, optionally pinned so it is listed ahead of unpinned ones.
+    pub fn add_scrap_with_pin(&self, task_id: i64, content: &str, pinned: bool) -> Result<Scrap> {
+        self.insert_scrap(task_id, content, pinned, None)
+    }
+
+    /// Adds a scrap attached to the TODO with task-scoped index `todo_index`,
+    /// regardless of which TODO is currently active.
+    pub fn add_scrap_for_todo(
+        &self,
+        task_id: i64,
+        content: &str,
+        todo_index: i64,
+    ) -> Result<Scrap> {
+        self.insert_scrap(task_id, content, false, Some(todo_index))
+    }
+
+    /// [`add_scrap_for_todo`](Self::add_scrap_for_todo) for callers that already
+    /// hold a DB transaction.
+    pub(crate) fn add_scrap_for_todo_in_tx(
+        &self,
+        task_id: i64,
+        content: &str,
+        todo_index: i64,
+    ) -> Result<Scrap> {
+        self.insert_scrap_in_tx(task_id, content, false, Some(todo_index))
+    }
+
+    fn insert_scrap(
+        &self,
+        task_id: i64,
+        content: &str,
+        pinned: bool,
+        todo_index: Option<i64>,
+    ) -> Result<Scrap> {
+        // Use transaction to make SELECT MAX + INSERT atomic
+        self.db
+            .with_transaction(|| self.insert_scrap_in_tx(task_id, content, pinned, todo_index))
+    }
+
+    fn insert_scrap_in_tx(
+        &self,
+        task_id: i64,
+        content: &str,
+        pinned: bool,
+        todo_index: Option<i64>,
+    ) -> Result<Scrap> {
         if content.trim().is_empty() {
             return Err(TrackError::EmptyScrapContent);
         }
@@ -124,41 +220,42 @@ impl<'a> ScrapService<'a> {
         let now = Utc::now().to_rfc3339();
         let content = content.to_string();
 
-        // Use transaction to make SELECT MAX + INSERT atomic
-        self.db.with_transaction(|| {
-            let conn = self.db.get_connection();
+        let conn = self.db.get_connection();
 
-            // Get the next task_index for this task
-            let next_index: i64 = conn.query_row(
-                "SELECT COALESCE(MAX(task_index), 0) + 1 FROM scraps WHERE task_id = ?1",
-                params![task_id],
-                |row| row.get(0),
-            )?;
+        // Get the next task_index for this task
+        let next_index: i64 = conn.query_row(
+            "SELECT COALESCE(MAX(task_index), 0) + 1 FROM scraps WHERE task_id = ?1",
+            params![task_id],
+            |row| row.get(0),
+        )?;
 
-            // Find the active todo (oldest pending todo) at the time of scrap creation
-            let active_query = format!(
-                "SELECT task_index FROM todos WHERE task_id = ?1 AND status = '{}' ORDER BY task_index ASC LIMIT 1",
-                TodoStatus::PENDING
-            );
-            let active_todo_id: Option<i64> = conn
+        // Find the active todo (oldest pending todo) at the time of scrap creation
+        let active_query = format!(
+            "SELECT task_index FROM todos WHERE task_id = ?1 AND status = '{}' ORDER BY task_index ASC LIMIT 1",
+            TodoStatus::PENDING
+        );
+        let active_todo_id: Option<i64> = match todo_index {
+            Some(index) => Some(index),
+            None => conn
                 .query_row(&active_query, params![task_id], |row| row.get(0))
-                .optional()?;
+                .optional()?,
+        };
 
-            conn.execute(
-                "INSERT INTO scraps (task_id, task_index, content, created_at, active_todo_id) VALUES (?1, ?2, ?3, ?4, ?5)",
-                params![task_id, next_index, content, now, active_todo_id],
-            )?;
+        conn.execute(
+            "INSERT INTO scraps (task_id, task_index, content, created_at, active_todo_id, pinned) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![task_id, next_index, content, now, active_todo_id, pinned],
+        )?;
 
-            let scrap_id = conn.last_insert_rowid();
-            self.db.increment_rev("scraps")?;
-            self.get_scrap(scrap_id)
-        })
+        let scrap_id = conn.last_insert_rowid();
+        self.db.touch_task(task_id)?;
+        self.db.increment_rev("scraps")?;
+        self.get_scrap(scrap_id)
     }
 
     pub fn get_scrap(&self, scrap_id: i64) -> Result<Scrap> {
         let conn = self.db.get_connection();
         let mut stmt = conn.prepare(
-            "SELECT id, task_id, task_index, content, created_at, active_todo_id FROM scraps WHERE id = ?1",
+            "SELECT id, task_id, task_index, content, created_at, active_todo_id, pinned FROM scraps WHERE id = ?1",
         )?;
 
         let scrap = stmt.query_row(params![scrap_id], |row| {
@@ -169,16 +266,39 @@ impl<'a> ScrapService<'a> {
                 content: row.get(3)?,
                 created_at: parse_datetime(row.get::<_, String>(4)?)?,
                 active_todo_id: row.get(5)?,
+                pinned: row.get(6)?,
             })
         })?;
 
         Ok(scrap)
     }
 
+    /// Pins or unpins the scrap with task-scoped index `scrap_index`.
+    pub fn set_pinned(&self, task_id: i64, scrap_index: i64, pinned: bool) -> Result<Scrap> {
+        let conn = self.db.get_connection();
+        let scrap_id: i64 = conn
+            .query_row(
+                "SELECT id FROM scraps WHERE task_id = ?1 AND task_index = ?2",
+                params![task_id, scrap_index],
+                |row| row.get(0),
+            )
+            .optional()?
+            .ok_or(TrackError::ScrapIndexNotFound(scrap_index))?;
+
+        conn.execute(
+            "UPDATE scraps SET pinned = ?1 WHERE id = ?2",
+            params![pinned, scrap_id],
+        )?;
+        self.db.touch_task(task_id)?;
+        self.db.increment_rev("scraps")?;
+        self.get_scrap(scrap_id)
+    }
+
+    /// Lists a task's scraps: pinned ones first, each group oldest first.
     pub fn list_scraps(&self, task_id: i64) -> Result<Vec<Scrap>> {
         let conn = self.db.get_connection();
         let mut stmt = conn.prepare(
-            "SELECT id, task_id, task_index, content, created_at, active_todo_id FROM scraps WHERE task_id = ?1 ORDER BY created_at ASC"
+            "SELECT id, task_id, task_index, content, created_at, active_todo_id, pinned FROM scraps WHERE task_id = ?1 ORDER BY pinned DESC, created_at ASC"
         )?;
 
         let scraps = stmt
@@ -190,6 +310,7 @@ impl<'a> ScrapService<'a> {
                     content: row.get(3)?,
                     created_at: parse_datetime(row.get::<_, String>(4)?)?,
                     active_todo_id: row.get(5)?,
+                    pinned: row.get(6)?,
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -229,16 +350,18 @@ impl<'a> ScrapService<'a> {
         let conn = self.db.get_connection();
 
         let mut stmt = conn.prepare(
-            "SELECT content, active_todo_id FROM scraps WHERE task_id = ?1 AND active_todo_id IS NOT NULL ORDER BY created_at ASC"
+            "SELECT content, active_todo_id, pinned FROM scraps WHERE task_id = ?1 AND active_todo_id IS NOT NULL ORDER BY created_at ASC"
         )?;
 
-        let linked_scraps: Vec<(String, i64)> = stmt
-            .query_map(params![from_task_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+        let linked_scraps: Vec<(String, i64, bool)> = stmt
+            .query_map(params![from_task_id], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get::<_, i64>(2)? != 0))
+            })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         let has_scraps = !linked_scraps.is_empty();
 
-        for (content, old_todo_index) in linked_scraps {
+        for (content, old_todo_index, pinned) in linked_scraps {
             if let Some(&new_todo_index) = todo_mapping.get(&old_todo_index) {
                 let next_index: i64 = conn.query_row(
                     "SELECT COALESCE(MAX(task_index), 0) + 1 FROM scraps WHERE task_id = ?1",
@@ -249,13 +372,14 @@ impl<'a> ScrapService<'a> {
                 let now = Utc::now().to_rfc3339();
 
                 conn.execute(
-                    "INSERT INTO scraps (task_id, task_index, content, created_at, active_todo_id) VALUES (?1, ?2, ?3, ?4, ?5)",
-                    params![to_task_id, next_index, content, now, new_todo_index],
+                    "INSERT INTO scraps (task_id, task_index, content, created_at, active_todo_id, pinned) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    params![to_task_id, next_index, content, now, new_todo_index, pinned],
                 )?;
             }
         }
 
         if has_scraps {
+            self.db.touch_task(to_task_id)?;
             self.db.increment_rev("scraps")?;
         }
 
@@ -282,6 +406,108 @@ mod tests {
     }
 
     // LinkService tests
+    #[test]
+    fn test_add_link_assigns_stable_task_scoped_index() {
+        let db = setup_db();
+        let task_a = create_test_task(&db);
+        let task_b = create_test_task(&db);
+        let service = LinkService::new(&db);
+
+        let a: Vec<Link> = (1..=3)
+            .map(|n| {
+                service
+                    .add_link(task_a, &format!("https://a{n}.example"), None)
+                    .unwrap()
+            })
+            .collect();
+        let b = service.add_link(task_b, "https://b.example", None).unwrap();
+        assert_eq!(
+            a.iter().map(|link| link.task_index).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(b.task_index, 1);
+
+        service.delete_link(a[1].id).unwrap();
+        let next = service
+            .add_link(task_a, "https://a4.example", None)
+            .unwrap();
+        assert_eq!(next.task_index, 4);
+
+        let indices: Vec<i64> = service
+            .list_links(task_a)
+            .unwrap()
+            .into_iter()
+            .map(|link| link.task_index)
+            .collect();
+        assert_eq!(indices, vec![1, 3, 4]);
+    }
+
+    #[test]
+    fn test_move_link_touches_task() {
+        let db = setup_db();
+        let task_id = create_test_task(&db);
+        let service = LinkService::new(&db);
+        service
+            .add_link(task_id, "https://a.example", None)
+            .unwrap();
+        service
+            .add_link(task_id, "https://b.example", None)
+            .unwrap();
+        db.get_connection()
+            .execute("UPDATE tasks SET last_active_at = NULL", [])
+            .unwrap();
+
+        service.move_link(task_id, 2, 1).unwrap();
+        let task = TaskService::new(&db).get_task(task_id).unwrap();
+        assert!(task.last_active_at.is_some());
+    }
+
+    #[test]
+    fn test_move_link_shifts_links_in_between() {
+        let db = setup_db();
+        let task_id = create_test_task(&db);
+        let service = LinkService::new(&db);
+        for name in ["a", "b", "c", "d"] {
+            service
+                .add_link(task_id, &format!("https://{name}.example"), Some(name))
+                .unwrap();
+        }
+
+        let moved = service.move_link(task_id, 1, 3).unwrap();
+        assert_eq!(moved.title, "a");
+        assert_eq!(moved.task_index, 3);
+
+        let order: Vec<(i64, String)> = service
+            .list_links(task_id)
+            .unwrap()
+            .into_iter()
+            .map(|link| (link.task_index, link.title))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                (1, "b".to_string()),
+                (2, "c".to_string()),
+                (3, "a".to_string()),
+                (4, "d".to_string()),
+            ]
+        );
+
+        service.move_link(task_id, 4, 1).unwrap();
+        let titles: Vec<String> = service
+            .list_links(task_id)
+            .unwrap()
+            .into_iter()
+            .map(|link| link.title)
+            .collect();
+        assert_eq!(titles, vec!["d", "b", "c", "a"]);
+
+        assert!(matches!(
+            service.move_link(task_id, 1, 9),
+            Err(TrackError::LinkIndexNotFound(9))
+        ));
+    }
+
     #[test]
     fn test_add_link_success() {
         let db = setup_db();
@@ -452,6 +678,9 @@ mod tests {
 
         let todo = todo_service.add_todo(from_task, "Todo", false).unwrap();
         scrap_service.add_scrap(from_task, "Linked scrap").unwrap();
+        scrap_service
+            .add_scrap_with_pin(from_task, "Pinned scrap", true)
+            .unwrap();
 
         let mut mapping = HashMap::new();
         mapping.insert(todo.task_index, 1);
@@ -461,8 +690,12 @@ mod tests {
             .unwrap();
 
         let copied = scrap_service.list_scraps(to_task).unwrap();
-        assert_eq!(copied.len(), 1);
-        assert_eq!(copied[0].content, "Linked scrap");
-        assert_eq!(copied[0].active_todo_id, Some(1));
+        assert_eq!(copied.len(), 2);
+        // Pinned scraps are listed first and keep their pin
+        assert_eq!(copied[0].content, "Pinned scrap");
+        assert!(copied[0].pinned);
+        assert_eq!(copied[1].content, "Linked scrap");
+        assert!(!copied[1].pinned);
+        assert_eq!(copied[1].active_todo_id, Some(1));
     }
 }
@@ -5,16 +5,24 @@
 //! operations related to its domain and interacts with the database layer.
 
 pub mod agent_context;
+pub mod forge;
 pub mod git_worktree;
 pub mod jj_task;
 pub mod link_service;
+pub mod notify_service;
 pub mod repo_service;
+pub mod stats_service;
 pub mod task_service;
 pub mod todo_service;
 pub mod worktree_service;
 
+pub use forge::{ForgeClient, ForgeRef};
 pub use link_service::{LinkService, ScrapService};
+pub use notify_service::NotifyService;
 pub use repo_service::RepoService;
+pub use stats_service::{ActivityStats, StatsService};
 pub use task_service::TaskService;
-pub use todo_service::TodoService;
-pub use worktree_service::{is_legacy_worktree, LegacyWorktreeCleanupOutcome, WorktreeService};
+pub use todo_service::{TodoCounts, TodoService};
+pub use worktree_service::{
+    is_legacy_worktree, LegacyWorktreeCleanupOutcome, MergeStatus, TaskWorktree, WorktreeService,
+};
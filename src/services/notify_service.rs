@@ -0,0 +1,110 @@
+use crate::db::Database;
+use crate::models::{Task, Todo};
+use crate::utils::{http, Result};
+
+/// App-state key holding the completion webhook URL.
+pub const NOTIFY_WEBHOOK_KEY: &str = "notify_webhook";
+
+/// Opt-in webhook notifications (e.g. Slack incoming webhooks).
+pub struct NotifyService<'a> {
+    db: &'a Database,
+}
+
+impl<'a> NotifyService<'a> {
+    pub fn new(db: &'a Database) -> Self {
+        NotifyService { db }
+    }
+
+    /// Returns the configured webhook URL, if any.
+    pub fn webhook_url(&self) -> Result<Option<String>> {
+        Ok(self
+            .db
+            .get_app_state(NOTIFY_WEBHOOK_KEY)?
+            .filter(|url| !url.trim().is_empty()))
+    }
+
+    /// Sets the webhook URL; an empty value disables notifications.
+    pub fn set_webhook_url(&self, url: &str) -> Result<()> {
+        self.db.set_app_state(NOTIFY_WEBHOOK_KEY, url.trim())
+    }
+
+    /// Posts a TODO completion message. Returns `false` when no webhook is configured.
+    pub fn todo_done(&self, task: &Task, todo: &Todo, branch: Option<&str>) -> Result<bool> {
+        let Some(url) = self.webhook_url()? else {
+            return Ok(false);
+        };
+        http::post_json(&url, &todo_done_payload(task, todo, branch))?;
+        Ok(true)
+    }
+}
+
+/// Builds the completion payload; `text` keeps it readable by Slack-style webhooks.
+pub(crate) fn todo_done_payload(
+    task: &Task,
+    todo: &Todo,
+    branch: Option<&str>,
+) -> serde_json::Value {
+    let ticket = task.ticket_id.as_deref();
+    let label = match ticket {
+        Some(ticket) => format!("[{ticket}] {}", task.name),
+        None => task.name.clone(),
+    };
+
+    serde_json::json!({
+        "text": format!("✅ {label}: TODO #{} done — {}", todo.task_index, todo.content),
+        "task": { "id": task.id, "name": task.name, "ticket": ticket },
+        "todo": { "index": todo.task_index, "content": todo.content },
+        "branch": branch,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::{TaskService, TodoService};
+
+    #[test]
+    fn webhook_is_opt_in_and_clearable() {
+        let db = Database::new_in_memory().unwrap();
+        let service = NotifyService::new(&db);
+        assert_eq!(service.webhook_url().unwrap(), None);
+
+        service.set_webhook_url("https://hooks.example/x").unwrap();
+        assert_eq!(
+            service.webhook_url().unwrap().as_deref(),
+            Some("https://hooks.example/x")
+        );
+
+        service.set_webhook_url("").unwrap();
+        assert_eq!(service.webhook_url().unwrap(), None);
+
+        // No webhook configured: nothing is sent.
+        let task = TaskService::new(&db)
+            .create_task("Task", None, None, None)
+            .unwrap();
+        let todo = TodoService::new(&db)
+            .add_todo(task.id, "Item", false)
+            .unwrap();
+        assert!(!service.todo_done(&task, &todo, None).unwrap());
+    }
+
+    #[test]
+    fn todo_done_payload_includes_ticket_and_branch() {
+        let db = Database::new_in_memory().unwrap();
+        let task = TaskService::new(&db)
+            .create_task("Login", None, Some("PROJ-7"), None)
+            .unwrap();
+        let todo = TodoService::new(&db)
+            .add_todo(task.id, "Add form", false)
+            .unwrap();
+
+        let payload = todo_done_payload(&task, &todo, Some("PROJ-7-todo-1"));
+        assert_eq!(
+            payload["text"],
+            "✅ [PROJ-7] Login: TODO #1 done — Add form"
+        );
+        assert_eq!(payload["task"]["ticket"], "PROJ-7");
+        assert_eq!(payload["todo"]["content"], "Add form");
+        assert_eq!(payload["branch"], "PROJ-7-todo-1");
+    }
+}
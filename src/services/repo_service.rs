@@ -64,6 +64,7 @@ impl<'a> RepoService<'a> {
 
             let id = self.db.get_connection().last_insert_rowid();
 
+            self.db.touch_task(task_id)?;
             self.db.increment_rev("repos")?;
             Ok(TaskRepo {
                 id,
@@ -103,17 +104,49 @@ impl<'a> RepoService<'a> {
         Ok(repos)
     }
 
+    /// Resolve a repository of a task by index or path.
+    ///
+    /// Numeric references are treated as the task-scoped repository index;
+    /// anything else is resolved as a path and matched against `repo_path`.
+    pub fn resolve_repo(&self, task_id: i64, reference: &str) -> Result<TaskRepo> {
+        let repos = self.list_repos(task_id)?;
+
+        if let Ok(index) = reference.parse::<i64>() {
+            return repos
+                .into_iter()
+                .find(|repo| repo.task_index == index)
+                .ok_or(TrackError::TaskRepoIndexNotFound(index));
+        }
+
+        let candidate = self
+            .resolve_absolute_path(reference)
+            .map(|path| path.canonicalize().unwrap_or(path))
+            .ok();
+
+        repos
+            .into_iter()
+            .find(|repo| {
+                let stored = PathBuf::from(&repo.repo_path);
+                let stored = stored.canonicalize().unwrap_or(stored);
+                candidate.as_ref() == Some(&stored) || repo.repo_path == reference
+            })
+            .ok_or_else(|| TrackError::TaskRepoReferenceNotFound(reference.to_string()))
+    }
+
     /// Remove a repository registration
     pub fn remove_repo(&self, repo_id: i64) -> Result<()> {
-        let rows_affected = self
-            .db
-            .get_connection()
-            .execute("DELETE FROM task_repos WHERE id = ?1", params![repo_id])?;
-
-        if rows_affected == 0 {
-            return Err(TrackError::TaskRepoNotFound(repo_id));
-        }
+        let conn = self.db.get_connection();
+        let task_id: i64 = conn
+            .query_row(
+                "SELECT task_id FROM task_repos WHERE id = ?1",
+                params![repo_id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .ok_or(TrackError::TaskRepoNotFound(repo_id))?;
+        conn.execute("DELETE FROM task_repos WHERE id = ?1", params![repo_id])?;
 
+        self.db.touch_task(task_id)?;
         self.db.increment_rev("repos")?;
         Ok(())
     }
@@ -175,6 +208,41 @@ mod tests {
         std::fs::remove_dir_all(&temp_dir).ok();
     }
 
+    #[test]
+    fn test_resolve_repo_by_index_and_path() {
+        let db = setup_db();
+        let task_service = TaskService::new(&db);
+        let repo_service = RepoService::new(&db);
+
+        let task = task_service
+            .create_task("Test Task", None, None, None)
+            .unwrap();
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(temp_dir.path().join(".jj")).unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+        let repo = repo_service.add_repo(task.id, path, None, None).unwrap();
+
+        let by_index = repo_service.resolve_repo(task.id, "1").unwrap();
+        assert_eq!(by_index.id, repo.id);
+
+        let by_path = repo_service.resolve_repo(task.id, path).unwrap();
+        assert_eq!(by_path.id, repo.id);
+
+        let trailing = format!("{path}/");
+        let by_trailing = repo_service.resolve_repo(task.id, &trailing).unwrap();
+        assert_eq!(by_trailing.id, repo.id);
+
+        assert!(matches!(
+            repo_service.resolve_repo(task.id, "2"),
+            Err(TrackError::TaskRepoIndexNotFound(2))
+        ));
+        assert!(matches!(
+            repo_service.resolve_repo(task.id, "/nonexistent/repo"),
+            Err(TrackError::TaskRepoReferenceNotFound(_))
+        ));
+    }
+
     #[test]
     fn test_add_repo_not_git() {
         let db = setup_db();
@@ -0,0 +1,159 @@
+use crate::db::Database;
+use crate::utils::Result;
+use serde::Serialize;
+
+/// Aggregate activity counts across all tasks.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ActivityStats {
+    pub tasks_active: i64,
+    pub tasks_archived: i64,
+    pub todos_pending: i64,
+    pub todos_done: i64,
+    pub todos_cancelled: i64,
+    pub avg_todos_per_task: f64,
+    pub estimate_pending: f64,
+    pub estimate_done: f64,
+    pub estimate_cancelled: f64,
+    pub worktrees: i64,
+    pub scraps: i64,
+}
+
+impl ActivityStats {
+    pub fn total_tasks(&self) -> i64 {
+        self.tasks_active + self.tasks_archived
+    }
+
+    pub fn total_todos(&self) -> i64 {
+        self.todos_pending + self.todos_done + self.todos_cancelled
+    }
+}
+
+pub struct StatsService<'a> {
+    db: &'a Database,
+}
+
+impl<'a> StatsService<'a> {
+    pub fn new(db: &'a Database) -> Self {
+        StatsService { db }
+    }
+
+    /// Collect activity counts with aggregate queries (read-only).
+    pub fn collect(&self) -> Result<ActivityStats> {
+        let conn = self.db.get_connection();
+
+        let (tasks_active, tasks_archived): (i64, i64) = conn.query_row(
+            "SELECT COALESCE(SUM(status != 'archived'), 0), COALESCE(SUM(status = 'archived'), 0) FROM tasks",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+
+        let (todos_pending, todos_done, todos_cancelled): (i64, i64, i64) = conn.query_row(
+            "SELECT COALESCE(SUM(status = 'pending'), 0), COALESCE(SUM(status = 'done'), 0), COALESCE(SUM(status = 'cancelled'), 0) FROM todos",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )?;
+
+        let (estimate_pending, estimate_done, estimate_cancelled): (f64, f64, f64) = conn.query_row(
+            "SELECT COALESCE(SUM(CASE WHEN status = 'pending' THEN estimate END), 0.0), COALESCE(SUM(CASE WHEN status = 'done' THEN estimate END), 0.0), COALESCE(SUM(CASE WHEN status = 'cancelled' THEN estimate END), 0.0) FROM todos",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )?;
+
+        let worktrees: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM worktrees WHERE status = 'active'",
+            [],
+            |row| row.get(0),
+        )?;
+        let scraps: i64 = conn.query_row("SELECT COUNT(*) FROM scraps", [], |row| row.get(0))?;
+
+        let mut stats = ActivityStats {
+            tasks_active,
+            tasks_archived,
+            todos_pending,
+            todos_done,
+            todos_cancelled,
+            avg_todos_per_task: 0.0,
+            estimate_pending,
+            estimate_done,
+            estimate_cancelled,
+            worktrees,
+            scraps,
+        };
+
+        if stats.total_tasks() > 0 {
+            stats.avg_todos_per_task = stats.total_todos() as f64 / stats.total_tasks() as f64;
+        }
+
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TodoAddOptions;
+    use crate::services::{ScrapService, TaskService, TodoService};
+
+    fn setup_db() -> Database {
+        Database::new_in_memory().unwrap()
+    }
+
+    #[test]
+    fn test_collect_empty_database() {
+        let db = setup_db();
+        let stats = StatsService::new(&db).collect().unwrap();
+
+        assert_eq!(stats, ActivityStats::default());
+    }
+
+    #[test]
+    fn test_collect_counts_across_tasks() {
+        let db = setup_db();
+        let task_service = TaskService::new(&db);
+        let todo_service = TodoService::new(&db);
+
+        let t1 = task_service.create_task("T1", None, None, None).unwrap();
+        let t2 = task_service.create_task("T2", None, None, None).unwrap();
+
+        let a = todo_service.add_todo(t1.id, "A", false).unwrap();
+        todo_service.add_todo(t1.id, "B", false).unwrap();
+        let c = todo_service.add_todo(t2.id, "C", false).unwrap();
+        todo_service.update_status(a.id, "done").unwrap();
+        todo_service.update_status(c.id, "cancelled").unwrap();
+
+        ScrapService::new(&db).add_scrap(t1.id, "note").unwrap();
+        task_service.archive_task(t2.id).unwrap();
+
+        let stats = StatsService::new(&db).collect().unwrap();
+        assert_eq!(stats.tasks_active, 1);
+        assert_eq!(stats.tasks_archived, 1);
+        assert_eq!(stats.todos_pending, 1);
+        assert_eq!(stats.todos_done, 1);
+        assert_eq!(stats.todos_cancelled, 1);
+        assert_eq!(stats.avg_todos_per_task, 1.5);
+        assert_eq!(stats.worktrees, 0);
+        assert_eq!(stats.scraps, 1);
+    }
+
+    #[test]
+    fn test_collect_sums_estimates_by_status() {
+        let db = setup_db();
+        let task = TaskService::new(&db)
+            .create_task("T", None, None, None)
+            .unwrap();
+        let todo_service = TodoService::new(&db);
+        let estimate = |n| TodoAddOptions::default().with_estimate(Some(n));
+
+        todo_service.add_todo(task.id, "A", estimate(3.0)).unwrap();
+        todo_service.add_todo(task.id, "B", false).unwrap();
+        let c = todo_service.add_todo(task.id, "C", estimate(2.0)).unwrap();
+        let d = todo_service.add_todo(task.id, "D", estimate(0.5)).unwrap();
+        todo_service.update_status(c.id, "done").unwrap();
+        todo_service.update_status(d.id, "cancelled").unwrap();
+
+        let stats = StatsService::new(&db).collect().unwrap();
+        assert_eq!(stats.estimate_pending, 3.0);
+        assert_eq!(stats.estimate_done, 2.0);
+        assert_eq!(stats.estimate_cancelled, 0.5);
+    }
+}
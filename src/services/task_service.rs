@@ -1,10 +1,14 @@
 use crate::db::row_mapping::row_to_task;
 use crate::db::Database;
-use crate::models::{Task, TaskStatus};
+use crate::models::{Task, TaskListFilter, TaskOrder, TaskStatus};
+use crate::services::{ForgeRef, LinkService};
 use crate::utils::{Result, TrackError};
 use chrono::Utc;
 use rusqlite::{params, OptionalExtension};
 
+/// Longest task description accepted, in characters (guards against pasting whole files).
+pub const MAX_DESCRIPTION_LEN: usize = 10_000;
+
 /// Service for managing development tasks.
 ///
 /// TaskService provides operations for creating, retrieving, updating, and archiving tasks.
@@ -44,20 +48,39 @@ impl<'a> TaskService<'a> {
         description: Option<&str>,
         ticket_id: Option<&str>,
         ticket_url: Option<&str>,
+    ) -> Result<Task> {
+        let task = self.insert_task(name, description, ticket_id, ticket_url)?;
+        self.db.set_current_task_id(task.id)?;
+        Ok(task)
+    }
+
+    /// Creates a new task without changing the current task.
+    ///
+    /// Validation and errors are the same as [`Self::create_task`].
+    pub fn insert_task(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        ticket_id: Option<&str>,
+        ticket_url: Option<&str>,
     ) -> Result<Task> {
         if name.trim().is_empty() {
             return Err(TrackError::EmptyTaskName);
         }
 
-        // Validate ticket ID format if provided
-        if let Some(ticket) = ticket_id {
-            self.validate_ticket_format(ticket)?;
+        let (ticket_id, ticket_url) = match ticket_id {
+            Some(ticket) => {
+                let (ticket, derived_url) = normalize_ticket(ticket);
+                self.validate_ticket_format(&ticket)?;
 
-            // Check for duplicate ticket
-            if let Some(existing_id) = self.find_task_by_ticket(ticket)? {
-                return Err(TrackError::DuplicateTicket(ticket.to_string(), existing_id));
+                // Check for duplicate ticket
+                if let Some(existing_id) = self.find_task_by_ticket(&ticket)? {
+                    return Err(TrackError::DuplicateTicket(ticket, existing_id));
+                }
+                (Some(ticket), ticket_url.map(str::to_string).or(derived_url))
             }
-        }
+            None => (None, ticket_url.map(str::to_string)),
+        };
 
         let now = Utc::now().to_rfc3339();
         let conn = self.db.get_connection();
@@ -68,9 +91,7 @@ impl<'a> TaskService<'a> {
         )?;
 
         let task_id = conn.last_insert_rowid();
-
-        // Set as current task
-        self.db.set_current_task_id(task_id)?;
+        self.db.increment_rev("task")?;
 
         self.get_task(task_id)
     }
@@ -87,7 +108,7 @@ impl<'a> TaskService<'a> {
     pub fn get_task(&self, task_id: i64) -> Result<Task> {
         let conn = self.db.get_connection();
         let mut stmt = conn.prepare(
-            "SELECT id, name, description, status, ticket_id, ticket_url, alias, is_today_task, created_at FROM tasks WHERE id = ?1"
+            "SELECT id, name, description, status, ticket_id, ticket_url, alias, is_today_task, created_at, last_active_at FROM tasks WHERE id = ?1"
         )?;
 
         let task = stmt
@@ -97,25 +118,38 @@ impl<'a> TaskService<'a> {
         Ok(task)
     }
 
-    /// Lists all tasks, optionally including archived tasks.
+    /// Lists tasks matching the given status filter.
     ///
     /// # Arguments
     ///
-    /// * `include_archived` - If true, includes archived tasks in the results
+    /// * `filter` - Active only, archived only, or all tasks
     ///
     /// # Returns
     ///
     /// A vector of tasks ordered by creation date (newest first).
-    pub fn list_tasks(&self, include_archived: bool) -> Result<Vec<Task>> {
+    pub fn list_tasks(&self, filter: TaskListFilter) -> Result<Vec<Task>> {
+        self.list_tasks_ordered(filter, TaskOrder::Created)
+    }
+
+    /// Lists tasks matching `filter` in the given order.
+    pub fn list_tasks_ordered(
+        &self,
+        filter: TaskListFilter,
+        order: TaskOrder,
+    ) -> Result<Vec<Task>> {
         let conn = self.db.get_connection();
-        let query = if include_archived {
-            "SELECT id, name, description, status, ticket_id, ticket_url, alias, is_today_task, created_at FROM tasks ORDER BY created_at DESC".to_string()
-        } else {
-            format!(
-                "SELECT id, name, description, status, ticket_id, ticket_url, alias, is_today_task, created_at FROM tasks WHERE status = '{}' ORDER BY created_at DESC",
-                TaskStatus::ACTIVE
-            )
+        let where_clause = match filter {
+            TaskListFilter::Active => format!("WHERE status != '{}'", TaskStatus::ARCHIVED),
+            TaskListFilter::Archived => format!("WHERE status = '{}'", TaskStatus::ARCHIVED),
+            TaskListFilter::All => String::new(),
+        };
+        let order_by = match order {
+            TaskOrder::Created => "created_at DESC",
+            TaskOrder::Recent => "COALESCE(last_active_at, created_at) DESC, created_at DESC",
         };
+        let query = format!(
+            "SELECT id, name, description, status, ticket_id, ticket_url, alias, is_today_task, created_at, last_active_at FROM tasks {where_clause} ORDER BY {order_by}"
+        );
 
         let mut stmt = conn.prepare(&query)?;
         let tasks = stmt
@@ -125,6 +159,17 @@ impl<'a> TaskService<'a> {
         Ok(tasks)
     }
 
+    /// Records activity on a task now, moving it up in `track list --sort recent`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TrackError::TaskNotFound` if the task does not exist.
+    pub fn touch_task(&self, task_id: i64) -> Result<Task> {
+        self.get_task(task_id)?;
+        self.db.touch_task(task_id)?;
+        self.get_task(task_id)
+    }
+
     /// Switches to a different task, making it the current active task.
     ///
     /// # Arguments
@@ -144,7 +189,8 @@ impl<'a> TaskService<'a> {
         }
 
         self.db.set_current_task_id(task_id)?;
-        Ok(task)
+        self.db.touch_task(task_id)?;
+        self.get_task(task_id)
     }
 
     /// Archives a task, marking it as completed or abandoned.
@@ -177,10 +223,40 @@ impl<'a> TaskService<'a> {
             }
         }
 
+        self.db.touch_task(task_id)?;
         self.db.increment_rev("task")?;
         Ok(())
     }
 
+    /// Moves a task between active, blocked and paused.
+    ///
+    /// Archiving goes through [`TaskService::archive_task`] so the current
+    /// task is cleared; archived tasks cannot be reopened.
+    pub fn set_status(&self, task_id: i64, status: TaskStatus) -> Result<Task> {
+        if status == TaskStatus::Archived {
+            self.archive_task(task_id)?;
+            return self.get_task(task_id);
+        }
+
+        let task = self.get_task(task_id)?;
+        if !task.status.can_transition_to(status) {
+            return Err(TrackError::InvalidStatusTransition {
+                from: task.status.as_str().to_string(),
+                to: status.as_str().to_string(),
+            });
+        }
+
+        let conn = self.db.get_connection();
+        conn.execute(
+            "UPDATE tasks SET status = ?1 WHERE id = ?2",
+            params![status.as_str(), task_id],
+        )?;
+
+        self.db.touch_task(task_id)?;
+        self.db.increment_rev("task")?;
+        self.get_task(task_id)
+    }
+
     /// Links a ticket to an existing task.
     ///
     /// # Arguments
@@ -194,16 +270,16 @@ impl<'a> TaskService<'a> {
     /// Returns an error if:
     /// - The ticket ID format is invalid
     /// - Another task is already linked to this ticket
-    pub fn link_ticket(&self, task_id: i64, ticket_id: &str, url: &str) -> Result<()> {
-        self.validate_ticket_format(ticket_id)?;
+    ///
+    /// Returns the ticket ID as stored, after [`normalize_ticket`].
+    pub fn link_ticket(&self, task_id: i64, ticket_id: &str, url: &str) -> Result<String> {
+        let (ticket_id, _) = normalize_ticket(ticket_id);
+        self.validate_ticket_format(&ticket_id)?;
 
         // Check for duplicate ticket (excluding current task)
-        if let Some(existing_id) = self.find_task_by_ticket(ticket_id)? {
+        if let Some(existing_id) = self.find_task_by_ticket(&ticket_id)? {
             if existing_id != task_id {
-                return Err(TrackError::DuplicateTicket(
-                    ticket_id.to_string(),
-                    existing_id,
-                ));
+                return Err(TrackError::DuplicateTicket(ticket_id, existing_id));
             }
         }
 
@@ -213,6 +289,28 @@ impl<'a> TaskService<'a> {
             params![ticket_id, url, task_id],
         )?;
 
+        self.db.touch_task(task_id)?;
+        self.db.increment_rev("task")?;
+        Ok(ticket_id)
+    }
+
+    /// Replaces the ticket URL of a task that already has a ticket, keeping its ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task does not exist, has no ticket, or the URL is not http(s).
+    pub fn set_ticket_url(&self, task_id: i64, url: &str) -> Result<()> {
+        LinkService::new(self.db).validate_url(url)?;
+        if self.get_task(task_id)?.ticket_id.is_none() {
+            return Err(TrackError::NoTicketLinked(task_id));
+        }
+
+        self.db.get_connection().execute(
+            "UPDATE tasks SET ticket_url = ?1 WHERE id = ?2",
+            params![url, task_id],
+        )?;
+
+        self.db.touch_task(task_id)?;
         self.db.increment_rev("task")?;
         Ok(())
     }
@@ -229,12 +327,20 @@ impl<'a> TaskService<'a> {
     /// Returns an error if:
     /// - The task does not exist
     /// - The task is archived
+    /// - The description is longer than [`MAX_DESCRIPTION_LEN`] characters
     pub fn set_description(&self, task_id: i64, description: &str) -> Result<()> {
         // Validate task exists and is active
         let task = self.get_task(task_id)?;
         if task.status == TaskStatus::Archived {
             return Err(TrackError::TaskArchived(task_id));
         }
+        let len = description.chars().count();
+        if len > MAX_DESCRIPTION_LEN {
+            return Err(TrackError::DescriptionTooLong {
+                len,
+                max: MAX_DESCRIPTION_LEN,
+            });
+        }
 
         let conn = self.db.get_connection();
         conn.execute(
@@ -242,10 +348,23 @@ impl<'a> TaskService<'a> {
             params![description, task_id],
         )?;
 
+        self.db.touch_task(task_id)?;
         self.db.increment_rev("task")?;
         Ok(())
     }
 
+    /// Appends `text` on a new line after the existing description (or sets it when empty).
+    ///
+    /// Returns the resulting description. The same checks as [`Self::set_description`] apply.
+    pub fn append_description(&self, task_id: i64, text: &str) -> Result<String> {
+        let description = match self.get_task(task_id)?.description {
+            Some(existing) if !existing.is_empty() => format!("{existing}\n{text}"),
+            _ => text.to_string(),
+        };
+        self.set_description(task_id, &description)?;
+        Ok(description)
+    }
+
     /// Resolves a task reference to a task ID.
     ///
     /// Accepts a numeric task ID, a ticket reference prefixed with "t:", or an alias.
@@ -263,12 +382,21 @@ impl<'a> TaskService<'a> {
     /// Returns an error if the reference is invalid or no matching task is found.
     pub fn resolve_task_id(&self, reference: &str) -> Result<i64> {
         // Priority 1: If it starts with "t:", it's a ticket reference
+        // (normalized, so `t:owner/repo#7` and issue URLs match the stored form)
         if let Some(ticket_id) = reference.strip_prefix("t:") {
+            let (normalized, _) = normalize_ticket(ticket_id);
             return self
-                .find_task_by_ticket(ticket_id)?
+                .find_task_by_ticket(&normalized)?
                 .ok_or_else(|| TrackError::TaskReferenceNotFound(format!("t:{ticket_id}")));
         }
 
+        // An explicit "a:" prefix only matches aliases
+        if let Some(alias) = reference.strip_prefix("a:") {
+            return self
+                .get_task_by_alias(alias)?
+                .ok_or_else(|| TrackError::TaskReferenceNotFound(format!("a:{alias}")));
+        }
+
         // Priority 2: Try to parse as numeric task ID
         if let Ok(task_id) = reference.parse::<i64>() {
             return Ok(task_id);
@@ -313,6 +441,7 @@ impl<'a> TaskService<'a> {
                     return Err(TrackError::AliasInUse {
                         alias: alias.to_string(),
                         task_id: existing_id,
+                        task_name: self.get_task(existing_id)?.name,
                     });
                 }
             }
@@ -324,6 +453,7 @@ impl<'a> TaskService<'a> {
             params![alias, task_id],
         )?;
 
+        self.db.touch_task(task_id)?;
         self.db.increment_rev("task")?;
         Ok(())
     }
@@ -339,6 +469,66 @@ impl<'a> TaskService<'a> {
             "UPDATE tasks SET alias = NULL WHERE id = ?1",
             params![task_id],
         )?;
+        self.db.touch_task(task_id)?;
+        self.db.increment_rev("task")?;
+        Ok(())
+    }
+
+    /// Returns whether `todo add` turns URLs in new TODOs into task links.
+    ///
+    /// An explicit `--auto-link` / `--no-auto-link` (`flag`) wins over the
+    /// task's `auto-link` default, which is off unless set.
+    pub fn auto_link(&self, task_id: i64, flag: Option<bool>) -> Result<bool> {
+        match flag {
+            Some(enabled) => Ok(enabled),
+            None => self.task_setting(task_id, "auto_link"),
+        }
+    }
+
+    /// Sets the per-task `auto-link` default used by `todo add`.
+    pub fn set_auto_link(&self, task_id: i64, enabled: bool) -> Result<()> {
+        self.set_task_setting(task_id, "auto_link", enabled)
+    }
+
+    /// Returns whether `todo add` makes new TODOs require a jj-task/git workspace.
+    ///
+    /// An explicit `--workspace` / `--no-workspace` (`flag`) wins over the
+    /// task's `auto-workspace` default, which is on unless set.
+    pub fn auto_workspace(&self, task_id: i64, flag: Option<bool>) -> Result<bool> {
+        match flag {
+            Some(enabled) => Ok(enabled),
+            None => self.task_setting(task_id, "auto_workspace"),
+        }
+    }
+
+    /// Sets the per-task `auto-workspace` default used by `todo add`.
+    pub fn set_auto_workspace(&self, task_id: i64, enabled: bool) -> Result<()> {
+        self.set_task_setting(task_id, "auto_workspace", enabled)
+    }
+
+    /// Reads a boolean per-task setting stored in `column` of `tasks`.
+    fn task_setting(&self, task_id: i64, column: &'static str) -> Result<bool> {
+        let enabled: Option<bool> = self
+            .db
+            .get_connection()
+            .query_row(
+                &format!("SELECT {column} FROM tasks WHERE id = ?1"),
+                params![task_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        enabled.ok_or(TrackError::TaskNotFound(task_id))
+    }
+
+    fn set_task_setting(&self, task_id: i64, column: &'static str, enabled: bool) -> Result<()> {
+        let updated = self.db.get_connection().execute(
+            &format!("UPDATE tasks SET {column} = ?1 WHERE id = ?2"),
+            params![enabled, task_id],
+        )?;
+        if updated == 0 {
+            return Err(TrackError::TaskNotFound(task_id));
+        }
+        self.db.increment_rev("task")?;
         Ok(())
     }
 
@@ -380,6 +570,19 @@ impl<'a> TaskService<'a> {
             ));
         }
 
+        // Other reference forms take priority in resolve_task_id, so the alias would be
+        // unreachable
+        if alias.contains(':') {
+            return Err(TrackError::InvalidAlias(
+                "Alias cannot contain ':' (reserved for t:/a: references)".to_string(),
+            ));
+        }
+        if alias.parse::<i64>().is_ok() {
+            return Err(TrackError::InvalidAlias(format!(
+                "Alias '{alias}' looks like a numeric task ID"
+            )));
+        }
+
         // Check format: only alphanumeric, hyphens, and underscores
         if !alias
             .chars()
@@ -405,7 +608,7 @@ impl<'a> TaskService<'a> {
         Ok(())
     }
 
-    fn find_task_by_ticket(&self, ticket_id: &str) -> Result<Option<i64>> {
+    pub(crate) fn find_task_by_ticket(&self, ticket_id: &str) -> Result<Option<i64>> {
         let conn = self.db.get_connection();
         let mut stmt = conn.prepare("SELECT id FROM tasks WHERE ticket_id = ?1")?;
         let result = stmt
@@ -430,6 +633,45 @@ impl<'a> TaskService<'a> {
     }
 }
 
+/// Rewrites common GitHub/GitLab ticket spellings into the canonical
+/// `owner/repo/123` form; anything else (e.g. Jira `PROJ-123`) is kept as is.
+///
+/// Accepts `owner/repo#123` and issue/PR URLs. For a URL the second value is the
+/// URL itself, usable as the ticket URL when none was given.
+pub fn normalize_ticket(ticket: &str) -> (String, Option<String>) {
+    let ticket = ticket.trim();
+
+    if ticket.starts_with("https://") || ticket.starts_with("http://") {
+        let canonical = match ForgeRef::parse(ticket) {
+            Some(ForgeRef::GitHub {
+                owner,
+                repo,
+                number,
+                ..
+            }) => Some(format!("{owner}/{repo}/{number}")),
+            Some(ForgeRef::GitLab {
+                project, number, ..
+            }) if project.split('/').count() == 2 => Some(format!("{project}/{number}")),
+            _ => None,
+        };
+        if let Some(canonical) = canonical {
+            return (canonical, Some(ticket.to_string()));
+        }
+        return (ticket.to_string(), None);
+    }
+
+    if let Some((repo, number)) = ticket.rsplit_once('#') {
+        if repo.split('/').count() == 2
+            && !number.is_empty()
+            && number.chars().all(|c| c.is_ascii_digit())
+        {
+            return (format!("{repo}/{number}"), None);
+        }
+    }
+
+    (ticket.to_string(), None)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -439,6 +681,51 @@ mod tests {
         Database::new_in_memory().unwrap()
     }
 
+    #[test]
+    fn auto_link_flag_overrides_task_default() {
+        let db = setup_db();
+        let service = TaskService::new(&db);
+        let task = service.create_task("Task", None, None, None).unwrap();
+
+        assert!(!service.auto_link(task.id, None).unwrap());
+        assert!(service.auto_link(task.id, Some(true)).unwrap());
+
+        service.set_auto_link(task.id, true).unwrap();
+        assert!(service.auto_link(task.id, None).unwrap());
+        assert!(!service.auto_link(task.id, Some(false)).unwrap());
+
+        // Stored on the task row, so it goes away with the task
+        assert_eq!(
+            db.get_app_state(&format!("auto_link:{}", task.id)).unwrap(),
+            None
+        );
+        db.get_connection()
+            .execute("DELETE FROM tasks WHERE id = ?1", params![task.id])
+            .unwrap();
+        assert!(matches!(
+            service.auto_link(task.id, None),
+            Err(TrackError::TaskNotFound(_))
+        ));
+        assert!(matches!(
+            service.set_auto_link(task.id, true),
+            Err(TrackError::TaskNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn auto_workspace_defaults_on_and_flag_overrides() {
+        let db = setup_db();
+        let service = TaskService::new(&db);
+        let task = service.create_task("Task", None, None, None).unwrap();
+
+        assert!(service.auto_workspace(task.id, None).unwrap());
+        assert!(!service.auto_workspace(task.id, Some(false)).unwrap());
+
+        service.set_auto_workspace(task.id, false).unwrap();
+        assert!(!service.auto_workspace(task.id, None).unwrap());
+        assert!(service.auto_workspace(task.id, Some(true)).unwrap());
+    }
+
     #[test]
     fn test_create_task_success() {
         let db = setup_db();
@@ -488,6 +775,55 @@ mod tests {
         assert!(matches!(result, Err(TrackError::DuplicateTicket(_, _))));
     }
 
+    #[test]
+    fn test_create_task_normalizes_github_ticket_forms() {
+        let db = setup_db();
+        let service = TaskService::new(&db);
+
+        let task = service
+            .create_task(
+                "Task 1",
+                None,
+                Some("https://github.com/owner/repo/issues/42"),
+                None,
+            )
+            .unwrap();
+        assert_eq!(task.ticket_id.as_deref(), Some("owner/repo/42"));
+        assert_eq!(
+            task.ticket_url.as_deref(),
+            Some("https://github.com/owner/repo/issues/42")
+        );
+
+        let result = service.create_task("Task 2", None, Some("owner/repo#42"), None);
+        assert!(matches!(
+            result,
+            Err(TrackError::DuplicateTicket(ticket, _)) if ticket == "owner/repo/42"
+        ));
+    }
+
+    #[test]
+    fn test_normalize_ticket() {
+        assert_eq!(
+            normalize_ticket("owner/repo#7"),
+            ("owner/repo/7".to_string(), None)
+        );
+        assert_eq!(
+            normalize_ticket("https://gitlab.com/group/proj/-/issues/9"),
+            (
+                "group/proj/9".to_string(),
+                Some("https://gitlab.com/group/proj/-/issues/9".to_string())
+            )
+        );
+        assert_eq!(
+            normalize_ticket(" PROJ-123 "),
+            ("PROJ-123".to_string(), None)
+        );
+        assert_eq!(
+            normalize_ticket("https://example.com/browse/PROJ-1"),
+            ("https://example.com/browse/PROJ-1".to_string(), None)
+        );
+    }
+
     #[test]
     fn test_get_task_success() {
         let db = setup_db();
@@ -516,7 +852,7 @@ mod tests {
         service.create_task("Task 1", None, None, None).unwrap();
         service.create_task("Task 2", None, None, None).unwrap();
 
-        let tasks = service.list_tasks(false).unwrap();
+        let tasks = service.list_tasks(TaskListFilter::Active).unwrap();
         assert_eq!(tasks.len(), 2);
     }
 
@@ -529,11 +865,100 @@ mod tests {
         service.create_task("Task 2", None, None, None).unwrap();
         service.archive_task(task1.id).unwrap();
 
-        let tasks = service.list_tasks(false).unwrap();
+        let tasks = service.list_tasks(TaskListFilter::Active).unwrap();
         assert_eq!(tasks.len(), 1);
 
-        let all_tasks = service.list_tasks(true).unwrap();
+        let all_tasks = service.list_tasks(TaskListFilter::All).unwrap();
         assert_eq!(all_tasks.len(), 2);
+
+        let archived = service.list_tasks(TaskListFilter::Archived).unwrap();
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].id, task1.id);
+    }
+
+    #[test]
+    fn test_list_tasks_recent_order_follows_activity() {
+        let db = setup_db();
+        let service = TaskService::new(&db);
+
+        let oldest = service.create_task("Oldest", None, None, None).unwrap();
+        let middle = service.create_task("Middle", None, None, None).unwrap();
+        let newest = service.create_task("Newest", None, None, None).unwrap();
+        for (id, created_at) in [
+            (oldest.id, "2026-01-01T00:00:00+00:00"),
+            (middle.id, "2026-01-02T00:00:00+00:00"),
+            (newest.id, "2026-01-03T00:00:00+00:00"),
+        ] {
+            db.get_connection()
+                .execute(
+                    "UPDATE tasks SET created_at = ?1 WHERE id = ?2",
+                    params![created_at, id],
+                )
+                .unwrap();
+        }
+        let ids = |order| -> Vec<i64> {
+            service
+                .list_tasks_ordered(TaskListFilter::Active, order)
+                .unwrap()
+                .iter()
+                .map(|task| task.id)
+                .collect()
+        };
+        assert_eq!(
+            ids(TaskOrder::Recent),
+            vec![newest.id, middle.id, oldest.id]
+        );
+
+        let touched = service.touch_task(oldest.id).unwrap();
+        assert!(touched.last_active_at.is_some());
+        assert_eq!(
+            ids(TaskOrder::Recent),
+            vec![oldest.id, newest.id, middle.id]
+        );
+
+        crate::services::TodoService::new(&db)
+            .add_todo(middle.id, "Resume", false)
+            .unwrap();
+        assert_eq!(
+            ids(TaskOrder::Recent),
+            vec![middle.id, oldest.id, newest.id]
+        );
+        assert_eq!(
+            ids(TaskOrder::Created),
+            vec![newest.id, middle.id, oldest.id]
+        );
+
+        assert!(matches!(
+            service.touch_task(999),
+            Err(TrackError::TaskNotFound(999))
+        ));
+    }
+
+    #[test]
+    fn test_set_status_keeps_blocked_and_paused_listed() {
+        let db = setup_db();
+        let service = TaskService::new(&db);
+
+        let blocked = service.create_task("Blocked", None, None, None).unwrap();
+        let paused = service.create_task("Paused", None, None, None).unwrap();
+        let archived = service.create_task("Archived", None, None, None).unwrap();
+
+        let task = service.set_status(blocked.id, TaskStatus::Blocked).unwrap();
+        assert_eq!(task.status, TaskStatus::Blocked);
+        service.set_status(paused.id, TaskStatus::Paused).unwrap();
+        service
+            .set_status(archived.id, TaskStatus::Archived)
+            .unwrap();
+
+        let open = service.list_tasks(TaskListFilter::Active).unwrap();
+        assert_eq!(open.len(), 2);
+        assert!(matches!(
+            service.set_status(archived.id, TaskStatus::Active),
+            Err(TrackError::InvalidStatusTransition { .. })
+        ));
+
+        let reopened = service.set_status(blocked.id, TaskStatus::Active).unwrap();
+        assert_eq!(reopened.status, TaskStatus::Active);
     }
 
     #[test]
@@ -591,6 +1016,41 @@ mod tests {
         assert_eq!(retrieved.ticket_id, Some("PROJ-456".to_string()));
     }
 
+    #[test]
+    fn test_set_ticket_url_keeps_ticket_id() {
+        let db = setup_db();
+        let service = TaskService::new(&db);
+
+        let task = service
+            .create_task(
+                "Task 1",
+                None,
+                Some("PROJ-1"),
+                Some("https://old.example.com"),
+            )
+            .unwrap();
+        service
+            .set_ticket_url(task.id, "https://new.example.com/PROJ-1")
+            .unwrap();
+
+        let retrieved = service.get_task(task.id).unwrap();
+        assert_eq!(retrieved.ticket_id.as_deref(), Some("PROJ-1"));
+        assert_eq!(
+            retrieved.ticket_url.as_deref(),
+            Some("https://new.example.com/PROJ-1")
+        );
+
+        assert!(matches!(
+            service.set_ticket_url(task.id, "ftp://example.com"),
+            Err(TrackError::InvalidUrl(_))
+        ));
+        let bare = service.create_task("Task 2", None, None, None).unwrap();
+        assert!(matches!(
+            service.set_ticket_url(bare.id, "https://example.com"),
+            Err(TrackError::NoTicketLinked(id)) if id == bare.id
+        ));
+    }
+
     #[test]
     fn test_link_ticket_duplicate() {
         let db = setup_db();
@@ -625,6 +1085,17 @@ mod tests {
             .unwrap();
         let resolved = service.resolve_task_id("t:PROJ-789").unwrap();
         assert_eq!(resolved, task.id);
+
+        let gh = service
+            .create_task("Task 2", None, Some("owner/repo/42"), None)
+            .unwrap();
+        assert_eq!(service.resolve_task_id("t:owner/repo#42").unwrap(), gh.id);
+        assert_eq!(
+            service
+                .resolve_task_id("t:https://github.com/owner/repo/issues/42")
+                .unwrap(),
+            gh.id
+        );
     }
 
     #[test]
@@ -682,6 +1153,32 @@ mod tests {
         assert_eq!(updated.description, Some("New description".to_string()));
     }
 
+    #[test]
+    fn test_append_description_and_length_guard() {
+        let db = setup_db();
+        let service = TaskService::new(&db);
+        let task = service.create_task("Test Task", None, None, None).unwrap();
+
+        assert_eq!(
+            service.append_description(task.id, "first").unwrap(),
+            "first"
+        );
+        service.append_description(task.id, "second").unwrap();
+        let updated = service.get_task(task.id).unwrap();
+        assert_eq!(updated.description.as_deref(), Some("first\nsecond"));
+
+        let too_long = "x".repeat(MAX_DESCRIPTION_LEN + 1);
+        let result = service.set_description(task.id, &too_long);
+        assert!(matches!(
+            result,
+            Err(TrackError::DescriptionTooLong { len, .. }) if len == MAX_DESCRIPTION_LEN + 1
+        ));
+        let result = service.append_description(task.id, &"y".repeat(MAX_DESCRIPTION_LEN));
+        assert!(matches!(result, Err(TrackError::DescriptionTooLong { .. })));
+        let unchanged = service.get_task(task.id).unwrap();
+        assert_eq!(unchanged.description.as_deref(), Some("first\nsecond"));
+    }
+
     #[test]
     fn test_set_description_archived_task() {
         let db = setup_db();
@@ -769,7 +1266,11 @@ mod tests {
 
         // Try to set the same alias on a different task
         let result = service.set_alias(task2.id, "my-alias", false);
-        assert!(result.is_err());
+        assert!(matches!(
+            result,
+            Err(TrackError::AliasInUse { task_id, ref task_name, .. })
+                if task_id == task1.id && task_name == "Task 1"
+        ));
     }
 
     #[test]
@@ -811,6 +1312,13 @@ mod tests {
 
         let resolved = service.resolve_task_id("my-alias").unwrap();
         assert_eq!(resolved, task.id);
+
+        let resolved = service.resolve_task_id("a:my-alias").unwrap();
+        assert_eq!(resolved, task.id);
+        assert!(matches!(
+            service.resolve_task_id("a:missing"),
+            Err(TrackError::TaskReferenceNotFound(r)) if r == "a:missing"
+        ));
     }
 
     #[test]
@@ -873,6 +1381,18 @@ mod tests {
         assert!(service.validate_alias("invalid.alias").is_err()); // dot
     }
 
+    #[test]
+    fn test_validate_alias_rejects_other_reference_forms() {
+        let db = setup_db();
+        let service = TaskService::new(&db);
+
+        assert!(service.validate_alias("42").is_err());
+        assert!(service.validate_alias("-7").is_err());
+        assert!(service.validate_alias("t:PROJ-1").is_err());
+        assert!(service.validate_alias("a:work").is_err());
+        assert!(service.validate_alias("42-fix").is_ok());
+    }
+
     #[test]
     fn test_validate_alias_length() {
         let db = setup_db();
@@ -1,11 +1,25 @@
 use crate::db::row_mapping::row_to_todo;
 use crate::db::Database;
-use crate::models::{Todo, TodoStatus};
+use crate::models::{TaskStatus, Todo, TodoPosition, TodoStatus};
 use crate::utils::{Result, TrackError};
-use chrono::Utc;
-use rusqlite::params;
+use chrono::{DateTime, NaiveDate, Utc};
+use rusqlite::{params, OptionalExtension};
 use std::str::FromStr;
 
+/// Number of TODOs of a task in each status.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TodoCounts {
+    pub pending: usize,
+    pub done: usize,
+    pub cancelled: usize,
+}
+
+impl TodoCounts {
+    pub fn total(&self) -> usize {
+        self.pending + self.done + self.cancelled
+    }
+}
+
 pub struct TodoService<'a> {
     db: &'a Database,
 }
@@ -20,11 +34,28 @@ impl<'a> TodoService<'a> {
         task_id: i64,
         content: &str,
         options: impl Into<crate::models::TodoAddOptions>,
+    ) -> Result<Todo> {
+        self.add_todo_at(task_id, content, options, TodoPosition::End)
+    }
+
+    /// Adds a TODO at the given position, shifting later TODOs down by one.
+    pub fn add_todo_at(
+        &self,
+        task_id: i64,
+        content: &str,
+        options: impl Into<crate::models::TodoAddOptions>,
+        position: TodoPosition,
     ) -> Result<Todo> {
         let options = options.into();
-        if content.trim().is_empty() {
+        let content = content.trim();
+        if content.is_empty() {
             return Err(TrackError::EmptyTodoContent);
         }
+        if let Some(estimate) = options.estimate {
+            if !estimate.is_finite() || estimate < 0.0 {
+                return Err(TrackError::InvalidEstimate(estimate.to_string()));
+            }
+        }
 
         let now = Utc::now().to_rfc3339();
         let content = content.to_string();
@@ -34,15 +65,33 @@ impl<'a> TodoService<'a> {
         self.db.with_transaction(|| {
             let conn = self.db.get_connection();
 
-            // Get next task_index for this task
-            let next_index: i64 = conn.query_row(
-                "SELECT COALESCE(MAX(task_index), 0) + 1 FROM todos WHERE task_id = ?1",
-                params![task_id],
+            // Current TODO is the oldest pending one
+            let current_index: Option<i64> = conn.query_row(
+                "SELECT MIN(task_index) FROM todos WHERE task_id = ?1 AND status = ?2",
+                params![task_id, TodoStatus::PENDING],
                 |row| row.get(0),
             )?;
 
+            let insert_at = match (position, current_index) {
+                (TodoPosition::Top, Some(current)) => Some(current),
+                (TodoPosition::AfterCurrent, Some(current)) => Some(current + 1),
+                _ => None,
+            };
+
+            let next_index: i64 = match insert_at {
+                Some(index) => {
+                    self.shift_indices_from(task_id, index)?;
+                    index
+                }
+                None => conn.query_row(
+                    "SELECT COALESCE(MAX(task_index), 0) + 1 FROM todos WHERE task_id = ?1",
+                    params![task_id],
+                    |row| row.get(0),
+                )?,
+            };
+
             conn.execute(
-                "INSERT INTO todos (task_id, task_index, content, status, worktree_requested, requires_workspace, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                "INSERT INTO todos (task_id, task_index, content, status, worktree_requested, requires_workspace, created_at, estimate, due_date) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
                 params![
                     task_id,
                     next_index,
@@ -50,20 +99,49 @@ impl<'a> TodoService<'a> {
                     status,
                     options.worktree_requested,
                     options.requires_workspace,
-                    now
+                    now,
+                    options.estimate,
+                    options.due_date.map(|due| due.to_string())
                 ],
             )?;
 
             let todo_id = conn.last_insert_rowid();
+            self.db.touch_task(task_id)?;
             self.db.increment_rev("todos")?;
             self.get_todo(todo_id)
         })
     }
 
+    /// Shifts `task_index` of TODOs at or after `from_index` by one.
+    ///
+    /// Must run inside a transaction. Indices go through negative temporary
+    /// values to avoid UNIQUE constraint violations; scrap references to TODO
+    /// indices are shifted along with them.
+    fn shift_indices_from(&self, task_id: i64, from_index: i64) -> Result<()> {
+        let conn = self.db.get_connection();
+        conn.execute(
+            "UPDATE todos SET task_index = -task_index - 1 WHERE task_id = ?1 AND task_index >= ?2",
+            params![task_id, from_index],
+        )?;
+        conn.execute(
+            "UPDATE todos SET task_index = -task_index WHERE task_id = ?1 AND task_index < 0",
+            params![task_id],
+        )?;
+
+        let shifted_scraps = conn.execute(
+            "UPDATE scraps SET active_todo_id = active_todo_id + 1 WHERE task_id = ?1 AND active_todo_id >= ?2",
+            params![task_id, from_index],
+        )?;
+        if shifted_scraps > 0 {
+            self.db.increment_rev("scraps")?;
+        }
+        Ok(())
+    }
+
     pub fn get_todo(&self, todo_id: i64) -> Result<Todo> {
         let conn = self.db.get_connection();
         let mut stmt = conn.prepare(
-            "SELECT id, task_id, task_index, content, status, worktree_requested, requires_workspace, created_at, completed_at FROM todos WHERE id = ?1"
+            "SELECT id, task_id, task_index, content, status, worktree_requested, requires_workspace, created_at, completed_at, estimate, due_date FROM todos WHERE id = ?1"
         )?;
 
         let todo = stmt
@@ -74,22 +152,68 @@ impl<'a> TodoService<'a> {
     }
 
     pub fn list_todos(&self, task_id: i64) -> Result<Vec<Todo>> {
+        self.list_todos_page(task_id, None, 0)
+    }
+
+    /// Lists at most `limit` TODOs (all when `None`) after skipping the first `offset`.
+    pub fn list_todos_page(
+        &self,
+        task_id: i64,
+        limit: Option<usize>,
+        offset: usize,
+    ) -> Result<Vec<Todo>> {
         let conn = self.db.get_connection();
         let mut stmt = conn.prepare(
-            "SELECT id, task_id, task_index, content, status, worktree_requested, requires_workspace, created_at, completed_at FROM todos WHERE task_id = ?1 ORDER BY task_index ASC"
+            "SELECT id, task_id, task_index, content, status, worktree_requested, requires_workspace, created_at, completed_at, estimate, due_date FROM todos WHERE task_id = ?1 ORDER BY task_index ASC LIMIT ?2 OFFSET ?3"
         )?;
 
+        // SQLite treats a negative LIMIT as "no limit".
+        let limit = limit.map_or(-1, |limit| limit as i64);
         let todos = stmt
-            .query_map(params![task_id], row_to_todo)?
+            .query_map(params![task_id, limit, offset as i64], row_to_todo)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         Ok(todos)
     }
 
+    /// Counts a task's TODOs per status.
+    pub fn count_todos(&self, task_id: i64) -> Result<TodoCounts> {
+        let conn = self.db.get_connection();
+        let mut stmt =
+            conn.prepare("SELECT status, COUNT(*) FROM todos WHERE task_id = ?1 GROUP BY status")?;
+        let rows = stmt.query_map(params![task_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as usize))
+        })?;
+
+        let mut counts = TodoCounts::default();
+        for row in rows {
+            let (status, count) = row?;
+            match TodoStatus::from_str(&status) {
+                Ok(TodoStatus::Pending) => counts.pending = count,
+                Ok(TodoStatus::Done) => counts.done = count,
+                Ok(TodoStatus::Cancelled) => counts.cancelled = count,
+                Err(_) => {}
+            }
+        }
+        Ok(counts)
+    }
+
+    /// Lists TODOs of a task completed at or after `since`, oldest completion first.
+    pub fn list_completed_since(&self, task_id: i64, since: DateTime<Utc>) -> Result<Vec<Todo>> {
+        let mut todos: Vec<Todo> = self
+            .list_todos(task_id)?
+            .into_iter()
+            .filter(|todo| todo.status == TodoStatus::Done)
+            .filter(|todo| todo.completed_at.is_some_and(|at| at >= since))
+            .collect();
+        todos.sort_by_key(|todo| todo.completed_at);
+        Ok(todos)
+    }
+
     pub fn get_todo_by_index(&self, task_id: i64, task_index: i64) -> Result<Todo> {
         let conn = self.db.get_connection();
         let mut stmt = conn.prepare(
-            "SELECT id, task_id, task_index, content, status, worktree_requested, requires_workspace, created_at, completed_at FROM todos WHERE task_id = ?1 AND task_index = ?2"
+            "SELECT id, task_id, task_index, content, status, worktree_requested, requires_workspace, created_at, completed_at, estimate, due_date FROM todos WHERE task_id = ?1 AND task_index = ?2"
         )?;
 
         let todo = stmt
@@ -142,10 +266,78 @@ impl<'a> TodoService<'a> {
             return Err(TrackError::TodoNotFound(todo_id));
         }
 
+        self.db.touch_task(todo.task_id)?;
         self.db.increment_rev("todos")?;
         Ok(())
     }
 
+    /// Pending TODOs of a task whose content contains `needle` (case-insensitive),
+    /// or every pending TODO when `needle` is `None`.
+    pub fn find_pending_todos(&self, task_id: i64, needle: Option<&str>) -> Result<Vec<Todo>> {
+        let needle = needle.map(str::to_lowercase);
+        Ok(self
+            .list_todos(task_id)?
+            .into_iter()
+            .filter(|todo| todo.status == TodoStatus::Pending)
+            .filter(|todo| {
+                needle
+                    .as_deref()
+                    .is_none_or(|needle| todo.content.to_lowercase().contains(needle))
+            })
+            .collect())
+    }
+
+    /// Marks pending TODOs of a task as done or cancelled in one transaction.
+    ///
+    /// TODOs that are no longer pending, or belong to another task, are left
+    /// alone. Returns how many TODOs changed.
+    pub fn close_todos(&self, task_id: i64, todo_ids: &[i64], status: TodoStatus) -> Result<usize> {
+        if status == TodoStatus::Pending {
+            return Err(TrackError::InvalidStatus(
+                "pending (reopen is not allowed; add a new TODO instead)".to_string(),
+            ));
+        }
+        let completed_at = (status == TodoStatus::Done).then(|| Utc::now().to_rfc3339());
+
+        let changed = self.db.with_transaction(|| {
+            let conn = self.db.get_connection();
+            let mut changed = 0;
+            for todo_id in todo_ids {
+                changed += conn.execute(
+                    "UPDATE todos SET status = ?1, completed_at = ?2 WHERE id = ?3 AND task_id = ?4 AND status = ?5",
+                    params![
+                        status.as_str(),
+                        completed_at,
+                        todo_id,
+                        task_id,
+                        TodoStatus::Pending.as_str()
+                    ],
+                )?;
+            }
+            Ok(changed)
+        })?;
+
+        if changed > 0 {
+            self.db.touch_task(task_id)?;
+            self.db.increment_rev("todos")?;
+        }
+        Ok(changed)
+    }
+
+    /// Sets or clears (`None`) the due date of a TODO.
+    pub fn set_due_date(&self, todo_id: i64, due_date: Option<NaiveDate>) -> Result<Todo> {
+        let todo = self.get_todo(todo_id)?;
+        let conn = self.db.get_connection();
+        conn.execute(
+            "UPDATE todos SET due_date = ?1 WHERE id = ?2",
+            params![due_date.map(|due| due.to_string()), todo_id],
+        )?;
+
+        self.db.touch_task(todo.task_id)?;
+        self.db.increment_rev("todos")?;
+        self.get_todo(todo_id)
+    }
+
     /// Clears legacy per-TODO `worktree_requested` flags (jj-task migration).
     pub fn clear_legacy_worktree_flags(&self, task_id: Option<i64>) -> Result<usize> {
         let conn = self.db.get_connection();
@@ -169,17 +361,100 @@ impl<'a> TodoService<'a> {
     }
 
     pub fn delete_todo(&self, todo_id: i64) -> Result<()> {
+        let task_id = self.get_todo(todo_id)?.task_id;
         let conn = self.db.get_connection();
+        // Worktrees keep their row but lose the TODO reference (ON DELETE SET NULL)
+        let detached_worktrees: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM worktrees WHERE todo_id = ?1",
+            params![todo_id],
+            |row| row.get(0),
+        )?;
         let affected = conn.execute("DELETE FROM todos WHERE id = ?1", params![todo_id])?;
 
         if affected == 0 {
             return Err(TrackError::TodoNotFound(todo_id));
         }
 
+        self.db.touch_task(task_id)?;
         self.db.increment_rev("todos")?;
+        if detached_worktrees > 0 {
+            self.db.increment_rev("worktrees")?;
+        }
         Ok(())
     }
 
+    /// Moves a TODO to another task, appending it at the end of the destination.
+    ///
+    /// Worktrees created for the TODO follow it when `keep_worktree` is set
+    /// (their branch names are left unchanged); otherwise they stay with the
+    /// source task and are detached from the TODO. Scraps stay with the source
+    /// task too; those attached to the TODO lose the attachment.
+    pub fn move_to_task(
+        &self,
+        todo_id: i64,
+        dest_task_id: i64,
+        keep_worktree: bool,
+    ) -> Result<Todo> {
+        self.db.with_transaction(|| {
+            let todo = self.get_todo(todo_id)?;
+            if todo.task_id == dest_task_id {
+                return Err(TrackError::TodoAlreadyInTask(dest_task_id));
+            }
+
+            let conn = self.db.get_connection();
+            let dest_status: String = conn
+                .query_row(
+                    "SELECT status FROM tasks WHERE id = ?1",
+                    params![dest_task_id],
+                    |row| row.get(0),
+                )
+                .optional()?
+                .ok_or(TrackError::TaskNotFound(dest_task_id))?;
+            if dest_status == TaskStatus::Archived.as_str() {
+                return Err(TrackError::TaskArchived(dest_task_id));
+            }
+
+            let next_index: i64 = conn.query_row(
+                "SELECT COALESCE(MAX(task_index), 0) + 1 FROM todos WHERE task_id = ?1",
+                params![dest_task_id],
+                |row| row.get(0),
+            )?;
+            conn.execute(
+                "UPDATE todos SET task_id = ?1, task_index = ?2 WHERE id = ?3",
+                params![dest_task_id, next_index, todo_id],
+            )?;
+
+            let moved_worktrees = if keep_worktree {
+                conn.execute(
+                    "UPDATE worktrees SET task_id = ?1 WHERE todo_id = ?2",
+                    params![dest_task_id, todo_id],
+                )?
+            } else {
+                conn.execute(
+                    "UPDATE worktrees SET todo_id = NULL WHERE todo_id = ?1",
+                    params![todo_id],
+                )?
+            };
+            if moved_worktrees > 0 {
+                self.db.increment_rev("worktrees")?;
+            }
+
+            // `active_todo_id` is a source-task index that no longer names this TODO
+            let detached_scraps = conn.execute(
+                "UPDATE scraps SET active_todo_id = NULL WHERE task_id = ?1 AND active_todo_id = ?2",
+                params![todo.task_id, todo.task_index],
+            )?;
+            if detached_scraps > 0 {
+                self.db.increment_rev("scraps")?;
+            }
+
+            self.db.touch_task(todo.task_id)?;
+            self.db.touch_task(dest_task_id)?;
+            self.db.increment_rev("todos")?;
+            self.get_todo(todo_id)
+        })
+    }
+
     /// Move a TODO to the front (make it the next todo to work on)
     ///
     /// This reorders the task_index so that the specified todo becomes the oldest pending todo.
@@ -256,6 +531,7 @@ impl<'a> TodoService<'a> {
                 )?;
             }
 
+            self.db.touch_task(task_id)?;
             self.db.increment_rev("todos")?;
             Ok(())
         })
@@ -330,6 +606,7 @@ impl<'a> TodoService<'a> {
         }
 
         if !mapping.is_empty() {
+            self.db.touch_task(to_task_id)?;
             self.db.increment_rev("todos")?;
         }
 
@@ -363,6 +640,22 @@ mod tests {
 
         let result = service.add_todo(task_id, "   ", false);
         assert!(matches!(result, Err(TrackError::EmptyTodoContent)));
+
+        let result = service.add_todo(task_id, "\n\t", false);
+        assert!(matches!(result, Err(TrackError::EmptyTodoContent)));
+    }
+
+    #[test]
+    fn test_add_todo_trims_content() {
+        let db = setup_db();
+        let task_id = create_test_task(&db);
+        let service = TodoService::new(&db);
+
+        let todo = service
+            .add_todo(task_id, "  Write tests \n", false)
+            .unwrap();
+        assert_eq!(todo.content, "Write tests");
+        assert_eq!(service.get_todo(todo.id).unwrap().content, "Write tests");
     }
 
     #[test]
@@ -377,6 +670,22 @@ mod tests {
         assert!(!todo.worktree_requested);
     }
 
+    #[test]
+    fn test_add_todo_with_estimate() {
+        let db = setup_db();
+        let task_id = create_test_task(&db);
+        let service = TodoService::new(&db);
+
+        let options = crate::models::TodoAddOptions::default().with_estimate(Some(2.5));
+        let todo = service.add_todo(task_id, "Estimated", options).unwrap();
+        assert_eq!(todo.estimate, Some(2.5));
+        assert_eq!(service.get_todo(todo.id).unwrap().estimate, Some(2.5));
+
+        let negative = crate::models::TodoAddOptions::default().with_estimate(Some(-1.0));
+        let result = service.add_todo(task_id, "Negative", negative);
+        assert!(matches!(result, Err(TrackError::InvalidEstimate(_))));
+    }
+
     #[test]
     fn test_add_todo_no_workspace() {
         let db = setup_db();
@@ -441,6 +750,45 @@ mod tests {
         assert_eq!(todos[1].content, "TODO 2");
     }
 
+    #[test]
+    fn test_list_todos_page_and_counts() {
+        let db = setup_db();
+        let task_id = create_test_task(&db);
+        let service = TodoService::new(&db);
+
+        for n in 1..=5 {
+            service
+                .add_todo(task_id, &format!("TODO {n}"), false)
+                .unwrap();
+        }
+        let first = service.get_todo_by_index(task_id, 1).unwrap();
+        service.mark_done(first.id).unwrap();
+
+        let page: Vec<i64> = service
+            .list_todos_page(task_id, Some(2), 1)
+            .unwrap()
+            .iter()
+            .map(|todo| todo.task_index)
+            .collect();
+        assert_eq!(page, vec![2, 3]);
+        assert_eq!(service.list_todos_page(task_id, None, 3).unwrap().len(), 2);
+        assert!(service
+            .list_todos_page(task_id, Some(2), 10)
+            .unwrap()
+            .is_empty());
+
+        let counts = service.count_todos(task_id).unwrap();
+        assert_eq!(
+            counts,
+            TodoCounts {
+                pending: 4,
+                done: 1,
+                cancelled: 0
+            }
+        );
+        assert_eq!(counts.total(), 5);
+    }
+
     #[test]
     fn test_update_status_success() {
         let db = setup_db();
@@ -692,6 +1040,83 @@ mod tests {
         assert_eq!(todos[1].status, TodoStatus::Pending);
     }
 
+    #[test]
+    fn test_add_todo_at_after_current() {
+        let db = setup_db();
+        let task_id = create_test_task(&db);
+        let service = TodoService::new(&db);
+
+        let todo1 = service.add_todo(task_id, "TODO 1", false).unwrap();
+        service.add_todo(task_id, "TODO 2", false).unwrap();
+        service.add_todo(task_id, "TODO 3", false).unwrap();
+        service.update_status(todo1.id, "done").unwrap();
+
+        // Current TODO is #2, so the new one lands at #3
+        let added = service
+            .add_todo_at(task_id, "Follow-up", false, TodoPosition::AfterCurrent)
+            .unwrap();
+        assert_eq!(added.task_index, 3);
+
+        let contents: Vec<_> = service
+            .list_todos(task_id)
+            .unwrap()
+            .into_iter()
+            .map(|todo| (todo.task_index, todo.content))
+            .collect();
+        assert_eq!(
+            contents,
+            vec![
+                (1, "TODO 1".to_string()),
+                (2, "TODO 2".to_string()),
+                (3, "Follow-up".to_string()),
+                (4, "TODO 3".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_add_todo_at_top_shifts_scrap_references() {
+        let db = setup_db();
+        let task_id = create_test_task(&db);
+        let service = TodoService::new(&db);
+
+        service.add_todo(task_id, "TODO 1", false).unwrap();
+        service.add_todo(task_id, "TODO 2", false).unwrap();
+        let scrap = crate::services::ScrapService::new(&db)
+            .add_scrap(task_id, "note on TODO 1")
+            .unwrap();
+        assert_eq!(scrap.active_todo_id, Some(1));
+
+        let added = service
+            .add_todo_at(task_id, "Urgent", false, TodoPosition::Top)
+            .unwrap();
+        assert_eq!(added.task_index, 1);
+
+        let todos = service.list_todos(task_id).unwrap();
+        assert_eq!(todos[1].content, "TODO 1");
+        assert_eq!(todos[1].task_index, 2);
+
+        let scraps = crate::services::ScrapService::new(&db)
+            .list_scraps(task_id)
+            .unwrap();
+        assert_eq!(scraps[0].active_todo_id, Some(2));
+    }
+
+    #[test]
+    fn test_add_todo_at_without_pending_appends() {
+        let db = setup_db();
+        let task_id = create_test_task(&db);
+        let service = TodoService::new(&db);
+
+        let todo1 = service.add_todo(task_id, "TODO 1", false).unwrap();
+        service.update_status(todo1.id, "done").unwrap();
+
+        let added = service
+            .add_todo_at(task_id, "Next", false, TodoPosition::AfterCurrent)
+            .unwrap();
+        assert_eq!(added.task_index, 2);
+    }
+
     #[test]
     fn test_move_to_next_done_todo_fails() {
         let db = setup_db();
@@ -727,4 +1152,155 @@ mod tests {
         assert_eq!(copied.len(), 1);
         assert_eq!(copied[0].content, "Keep");
     }
+
+    #[test]
+    fn test_list_completed_since_filters_by_completion_time() {
+        let db = setup_db();
+        let task_id = create_test_task(&db);
+        let service = TodoService::new(&db);
+
+        let old = service.add_todo(task_id, "Old", false).unwrap();
+        let recent = service.add_todo(task_id, "Recent", false).unwrap();
+        service.add_todo(task_id, "Open", false).unwrap();
+        service.mark_done(old.id).unwrap();
+        service.mark_done(recent.id).unwrap();
+
+        let week_ago = (Utc::now() - chrono::Duration::days(7)).to_rfc3339();
+        db.get_connection()
+            .execute(
+                "UPDATE todos SET completed_at = ?1 WHERE id = ?2",
+                params![week_ago, old.id],
+            )
+            .unwrap();
+
+        let since = Utc::now() - chrono::Duration::days(3);
+        let todos = service.list_completed_since(task_id, since).unwrap();
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].content, "Recent");
+    }
+
+    #[test]
+    fn test_move_to_task_appends_in_destination() {
+        let db = setup_db();
+        let source = create_test_task(&db);
+        let dest = create_test_task(&db);
+        let service = TodoService::new(&db);
+
+        service.add_todo(dest, "Existing", false).unwrap();
+        let todo = service.add_todo(source, "Relocate", false).unwrap();
+
+        let moved = service.move_to_task(todo.id, dest, false).unwrap();
+        assert_eq!(moved.task_id, dest);
+        assert_eq!(moved.task_index, 2);
+        assert!(service.list_todos(source).unwrap().is_empty());
+
+        let result = service.move_to_task(todo.id, dest, false);
+        assert!(matches!(result, Err(TrackError::TodoAlreadyInTask(id)) if id == dest));
+        assert!(matches!(
+            service.move_to_task(todo.id, 9999, false),
+            Err(TrackError::TaskNotFound(9999))
+        ));
+    }
+
+    #[test]
+    fn test_move_to_task_detaches_source_scraps() {
+        let db = setup_db();
+        let source = create_test_task(&db);
+        let dest = create_test_task(&db);
+        let service = TodoService::new(&db);
+        let scraps = crate::services::ScrapService::new(&db);
+
+        let todo = service.add_todo(source, "Relocate", false).unwrap();
+        let attached = scraps
+            .add_scrap_for_todo(source, "note", todo.task_index)
+            .unwrap();
+
+        service.move_to_task(todo.id, dest, false).unwrap();
+        let attached = scraps.get_scrap(attached.id).unwrap();
+        assert_eq!(attached.task_id, source);
+        assert_eq!(attached.active_todo_id, None);
+    }
+
+    #[test]
+    fn test_move_to_task_detaches_or_keeps_worktree() {
+        let db = setup_db();
+        let source = create_test_task(&db);
+        let dest = create_test_task(&db);
+        let service = TodoService::new(&db);
+        let conn = db.get_connection();
+
+        let first = service.add_todo(source, "Detach", false).unwrap();
+        let second = service.add_todo(source, "Keep", false).unwrap();
+        for (todo, path) in [(&first, "/tmp/wt-a"), (&second, "/tmp/wt-b")] {
+            conn.execute(
+                "INSERT INTO worktrees (task_id, path, branch, status, created_at, todo_id, is_base) VALUES (?1, ?2, 'b', 'active', ?3, ?4, 0)",
+                params![source, path, Utc::now().to_rfc3339(), todo.id],
+            )
+            .unwrap();
+        }
+
+        service.move_to_task(first.id, dest, false).unwrap();
+        service.move_to_task(second.id, dest, true).unwrap();
+
+        let owner = |path: &str| -> (i64, Option<i64>) {
+            conn.query_row(
+                "SELECT task_id, todo_id FROM worktrees WHERE path = ?1",
+                params![path],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap()
+        };
+        assert_eq!(owner("/tmp/wt-a"), (source, None));
+        assert_eq!(owner("/tmp/wt-b"), (dest, Some(second.id)));
+    }
+
+    #[test]
+    fn test_close_todos_updates_only_matching_pending() {
+        let db = Database::new_in_memory().unwrap();
+        let task_id = create_test_task(&db);
+        let other_task = create_test_task(&db);
+        let service = TodoService::new(&db);
+
+        let docs = service.add_todo(task_id, "Write docs", false).unwrap();
+        let done = service
+            .add_todo(task_id, "Update DOCS index", false)
+            .unwrap();
+        service.mark_done(done.id).unwrap();
+        let code = service.add_todo(task_id, "Write code", false).unwrap();
+        let foreign = service.add_todo(other_task, "Other docs", false).unwrap();
+
+        let matched = service.find_pending_todos(task_id, Some("docs")).unwrap();
+        assert_eq!(
+            matched.iter().map(|todo| todo.id).collect::<Vec<_>>(),
+            vec![docs.id]
+        );
+        assert_eq!(service.find_pending_todos(task_id, None).unwrap().len(), 2);
+
+        let changed = service
+            .close_todos(
+                task_id,
+                &[docs.id, done.id, foreign.id],
+                TodoStatus::Cancelled,
+            )
+            .unwrap();
+        assert_eq!(changed, 1);
+        assert_eq!(
+            service.get_todo(docs.id).unwrap().status,
+            TodoStatus::Cancelled
+        );
+        assert_eq!(service.get_todo(done.id).unwrap().status, TodoStatus::Done);
+        assert_eq!(
+            service.get_todo(foreign.id).unwrap().status,
+            TodoStatus::Pending
+        );
+
+        service
+            .close_todos(task_id, &[code.id], TodoStatus::Done)
+            .unwrap();
+        assert!(service.get_todo(code.id).unwrap().completed_at.is_some());
+        assert!(matches!(
+            service.close_todos(task_id, &[code.id], TodoStatus::Pending),
+            Err(TrackError::InvalidStatus(_))
+        ));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::utils::{Result, TrackError};
+use crate::utils::{CommandExt, Result, TrackError};
 use std::path::Path;
 use std::process::Command;
 
@@ -14,7 +14,7 @@ pub fn bookmark_exists(repo_path: &str, bookmark: &str) -> Result<bool> {
     let output = Command::new("jj")
         .current_dir(repo_path)
         .args(["-R", repo_path, "bookmark", "list", bookmark])
-        .output()?;
+        .logged_output()?;
 
     if !output.status.success() {
         return Ok(false);
@@ -43,7 +43,7 @@ pub fn create_workspace(
             "-r",
             base_revset,
         ])
-        .output()?;
+        .logged_output()?;
 
     if !output.status.success() {
         let error = String::from_utf8_lossy(&output.stderr);
@@ -61,7 +61,7 @@ pub fn create_workspace(
             "-r",
             "@",
         ])
-        .output()?;
+        .logged_output()?;
 
     if !bookmark_output.status.success() {
         let error = String::from_utf8_lossy(&bookmark_output.stderr);
@@ -79,7 +79,7 @@ pub fn create_workspace_for_existing_bookmark(
     let output = Command::new("jj")
         .current_dir(repo_path)
         .args(["-R", repo_path, "workspace", "add", worktree_path])
-        .output()?;
+        .logged_output()?;
 
     if !output.status.success() {
         let error = String::from_utf8_lossy(&output.stderr);
@@ -89,7 +89,7 @@ pub fn create_workspace_for_existing_bookmark(
     let edit_output = Command::new("jj")
         .current_dir(worktree_path)
         .args(["-R", worktree_path, "edit", bookmark])
-        .output()?;
+        .logged_output()?;
 
     if !edit_output.status.success() {
         let error = String::from_utf8_lossy(&edit_output.stderr);
@@ -108,7 +108,7 @@ pub fn remove_workspace(repo_path: &str, worktree_path: &str) -> Result<()> {
     let output = Command::new("jj")
         .current_dir(repo_path)
         .args(["-R", repo_path, "workspace", "forget", workspace_name])
-        .output()?;
+        .logged_output()?;
 
     if !output.status.success() {
         let error = String::from_utf8_lossy(&output.stderr);
@@ -126,16 +126,74 @@ pub fn has_uncommitted_changes(path: &str) -> Result<bool> {
     let output = Command::new("jj")
         .current_dir(path)
         .args(["-R", path, "diff", "--summary"])
-        .output()?;
+        .logged_output()?;
 
     Ok(!output.stdout.is_empty())
 }
 
+pub fn rename_bookmark(repo_path: &str, old: &str, new: &str) -> Result<()> {
+    let output = Command::new("jj")
+        .current_dir(repo_path)
+        .args(["-R", repo_path, "bookmark", "rename", old, new])
+        .logged_output()?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let error = String::from_utf8_lossy(&output.stderr);
+    Err(TrackError::Jj(format!("Bookmark rename failed: {}", error)))
+}
+
+/// Moves a workspace directory and renames the jj workspace to match its new directory name.
+pub fn move_workspace(old_path: &str, new_path: &str) -> Result<()> {
+    std::fs::rename(old_path, new_path)?;
+
+    let workspace_name = Path::new(new_path)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| TrackError::PathResolutionFailed(new_path.to_string()))?;
+
+    let output = Command::new("jj")
+        .current_dir(new_path)
+        .args(["-R", new_path, "workspace", "rename", workspace_name])
+        .logged_output()?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+
+    // Put the directory back so the stored path stays valid.
+    std::fs::rename(new_path, old_path)?;
+    let error = String::from_utf8_lossy(&output.stderr);
+    Err(TrackError::Jj(format!(
+        "Workspace rename failed: {}",
+        error
+    )))
+}
+
+/// Commits all working-copy changes at `path` with `message` (`jj commit`).
+///
+/// The bookmark on `@` stays on the committed change; `@` becomes a new empty change.
+pub fn commit_all(path: &str, message: &str) -> Result<()> {
+    let output = Command::new("jj")
+        .current_dir(path)
+        .args(["-R", path, "commit", "-m", message])
+        .logged_output()?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let error = String::from_utf8_lossy(&output.stderr);
+    Err(TrackError::Jj(format!("Commit failed: {}", error)))
+}
+
 fn update_stale_workspace(path: &str) -> Result<()> {
     let output = Command::new("jj")
         .current_dir(path)
         .args(["-R", path, "workspace", "update-stale"])
-        .output()?;
+        .logged_output()?;
 
     if output.status.success() {
         return Ok(());
@@ -145,6 +203,76 @@ fn update_stale_workspace(path: &str) -> Result<()> {
     Err(TrackError::Jj(error.to_string()))
 }
 
+/// Quotes a bookmark name for use as a revset symbol (names may contain `/`).
+pub fn revset_symbol(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Counts the commits reachable from `bookmark` that the `base` revset does
+/// not contain; zero means `bookmark` is already merged into `base`.
+pub fn commits_not_in(repo_path: &str, bookmark: &str, base: &str) -> Result<usize> {
+    if !bookmark_exists(repo_path, bookmark)? {
+        return Err(TrackError::BookmarkNotFound {
+            bookmark: bookmark.to_string(),
+            repo_path: repo_path.to_string(),
+        });
+    }
+
+    let revset = format!("::{} ~ ::({})", revset_symbol(bookmark), base);
+    let output = Command::new("jj")
+        .current_dir(repo_path)
+        .args([
+            "-R",
+            repo_path,
+            "log",
+            "--no-graph",
+            "-r",
+            &revset,
+            "-T",
+            "commit_id ++ \"\\n\"",
+        ])
+        .logged_output()?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(TrackError::Jj(error.to_string()));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .count())
+}
+
+/// Pushes `bookmark` to the repo's git remote (`jj git push --bookmark`).
+///
+/// Fails with [`TrackError::NoGitRemote`] when the repo has no remote configured.
+pub fn push_bookmark(repo_path: &str, bookmark: &str) -> Result<()> {
+    let remotes = Command::new("jj")
+        .current_dir(repo_path)
+        .args(["-R", repo_path, "git", "remote", "list"])
+        .logged_output()?;
+    if !remotes.status.success() {
+        let error = String::from_utf8_lossy(&remotes.stderr);
+        return Err(TrackError::Jj(error.to_string()));
+    }
+    if String::from_utf8_lossy(&remotes.stdout).trim().is_empty() {
+        return Err(TrackError::NoGitRemote(repo_path.to_string()));
+    }
+
+    let output = Command::new("jj")
+        .current_dir(repo_path)
+        .args(["-R", repo_path, "git", "push", "--bookmark", bookmark])
+        .logged_output()?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let error = String::from_utf8_lossy(&output.stderr);
+    Err(TrackError::Jj(format!("Push failed: {}", error)))
+}
+
 pub fn integrate_todo_bookmark(
     target_path: &str,
     todo_bookmark: &str,
@@ -163,7 +291,7 @@ pub fn integrate_todo_bookmark(
             "-d",
             task_bookmark,
         ])
-        .output()?;
+        .logged_output()?;
 
     if !rebase_output.status.success() {
         let error = String::from_utf8_lossy(&rebase_output.stderr);
@@ -181,7 +309,7 @@ pub fn integrate_todo_bookmark(
             "-t",
             todo_bookmark,
         ])
-        .output()?;
+        .logged_output()?;
 
     if !move_output.status.success() {
         let error = String::from_utf8_lossy(&move_output.stderr);
@@ -191,7 +319,7 @@ pub fn integrate_todo_bookmark(
     let edit_output = Command::new("jj")
         .current_dir(target_path)
         .args(["-R", target_path, "edit", task_bookmark])
-        .output()?;
+        .logged_output()?;
 
     if !edit_output.status.success() {
         let error = String::from_utf8_lossy(&edit_output.stderr);
@@ -277,4 +405,57 @@ mod tests {
         std::fs::write(temp_dir.path().join("test.txt"), "mod").unwrap();
         assert!(has_uncommitted_changes(path).unwrap());
     }
+
+    #[test]
+    fn push_bookmark_without_remote_reports_no_git_remote() {
+        if !jj_available() {
+            eprintln!("Skipping test: jj binary not available");
+            return;
+        }
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+        init_jj_repo(path);
+
+        assert!(matches!(
+            push_bookmark(path, "task/1"),
+            Err(TrackError::NoGitRemote(_))
+        ));
+    }
+
+    #[test]
+    fn commits_not_in_counts_unmerged_commits() {
+        if !jj_available() {
+            eprintln!("Skipping test: jj binary not available");
+            return;
+        }
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().to_str().unwrap();
+        init_jj_repo(path);
+        let bookmark = |name: &str| {
+            let output = Command::new("jj")
+                .args(["-R", path, "bookmark", "create", name, "-r", "@"])
+                .output()
+                .expect("failed to run jj bookmark create");
+            assert!(output.status.success());
+        };
+
+        std::fs::write(temp_dir.path().join("base.txt"), "base").unwrap();
+        describe_change(path, "base");
+        bookmark("task/t-1");
+        new_change(path);
+        std::fs::write(temp_dir.path().join("todo.txt"), "todo").unwrap();
+        describe_change(path, "todo");
+        bookmark("t-1-todo-1");
+
+        let task = revset_symbol("task/t-1");
+        let todo = revset_symbol("t-1-todo-1");
+        assert_eq!(commits_not_in(path, "t-1-todo-1", &task).unwrap(), 1);
+        assert_eq!(commits_not_in(path, "task/t-1", &todo).unwrap(), 0);
+        assert!(matches!(
+            commits_not_in(path, "missing", &task),
+            Err(TrackError::BookmarkNotFound { .. })
+        ));
+    }
 }
@@ -1,13 +1,15 @@
 mod jj;
 mod naming;
+mod remote;
 
-use crate::db::row_mapping::parse_datetime;
+use crate::db::row_mapping::{parse_datetime, parse_worktree_status};
 use crate::db::Database;
-use crate::models::{RepoLink, Worktree};
+use crate::models::{RepoLink, Worktree, WorktreeStatus};
+use crate::services::{LinkService, RepoService};
 use crate::utils::{Result, TrackError};
 use chrono::Utc;
 use rusqlite::{params, OptionalExtension};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Result of removing legacy track-managed JJ workspaces for a task.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -17,9 +19,35 @@ pub struct LegacyWorktreeCleanupOutcome {
     pub errors: Vec<String>,
 }
 
+/// A worktree together with the name of the task that owns it.
+#[derive(Debug, Clone)]
+pub struct TaskWorktree {
+    pub worktree: Worktree,
+    pub task_name: String,
+}
+
+/// Whether a worktree's bookmark is already contained in its merge target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeStatus {
+    pub branch: String,
+    /// Task bookmark for TODO worktrees, `trunk()` for the base worktree
+    pub base: String,
+    /// Commits on `branch` that `base` does not contain
+    pub unmerged_commits: usize,
+}
+
+impl MergeStatus {
+    pub fn is_merged(&self) -> bool {
+        self.unmerged_commits == 0
+    }
+}
+
 /// Returns true for track-managed workspaces from the legacy sync / `--worktree` model.
+///
+/// A base registered on the main checkout is not one: it is how jj-task tasks
+/// record their base and must survive `migrate legacy-worktrees`.
 pub fn is_legacy_worktree(worktree: &Worktree) -> bool {
-    worktree.is_base || worktree.todo_id.is_some()
+    (worktree.is_base && !worktree.is_main_checkout()) || worktree.todo_id.is_some()
 }
 
 pub struct WorktreeService<'a> {
@@ -44,6 +72,79 @@ impl<'a> WorktreeService<'a> {
             return Err(TrackError::NotJjRepository(repo_path.to_string()));
         }
 
+        let branch_name = self.resolve_branch_name(task_id, branch, ticket_id, todo_id)?;
+        let task_bookmark = naming::task_bookmark_name(task_id, ticket_id);
+        let base_revset = if is_base { "@" } else { task_bookmark.as_str() };
+
+        self.create_worktree(
+            task_id,
+            repo_path,
+            &branch_name,
+            todo_id,
+            is_base,
+            base_revset,
+        )
+    }
+
+    /// Like [`WorktreeService::add_worktree`], but when the bookmark already
+    /// exists (e.g. a fetched colleague's branch) the workspace is attached to
+    /// it instead of failing with `BookmarkExists`.
+    ///
+    /// Returns the worktree and whether an existing bookmark was reused.
+    pub fn add_or_attach_worktree(
+        &self,
+        task_id: i64,
+        repo_path: &str,
+        branch: Option<&str>,
+        ticket_id: Option<&str>,
+        todo_id: Option<i64>,
+    ) -> Result<(Worktree, bool)> {
+        if !jj::is_jj_repository(repo_path) {
+            return Err(TrackError::NotJjRepository(repo_path.to_string()));
+        }
+
+        let branch_name = self.resolve_branch_name(task_id, branch, ticket_id, todo_id)?;
+        if !jj::bookmark_exists(repo_path, &branch_name)? {
+            return self
+                .add_worktree(task_id, repo_path, branch, ticket_id, todo_id, false)
+                .map(|worktree| (worktree, false));
+        }
+
+        if let Some(existing) =
+            self.find_existing_worktree(task_id, repo_path, &branch_name, todo_id)?
+        {
+            return Err(TrackError::WorktreeExists(existing.path));
+        }
+        self.add_existing_worktree(task_id, repo_path, &branch_name, todo_id, false, None)
+            .map(|worktree| (worktree, true))
+    }
+
+    /// Adds a worktree whose branch starts at `from` (any jj revset) instead
+    /// of the task bookmark.
+    pub fn add_worktree_from(
+        &self,
+        task_id: i64,
+        repo_path: &str,
+        branch: Option<&str>,
+        ticket_id: Option<&str>,
+        todo_id: Option<i64>,
+        from: &str,
+    ) -> Result<Worktree> {
+        if !jj::is_jj_repository(repo_path) {
+            return Err(TrackError::NotJjRepository(repo_path.to_string()));
+        }
+
+        let branch_name = self.resolve_branch_name(task_id, branch, ticket_id, todo_id)?;
+        self.create_worktree(task_id, repo_path, &branch_name, todo_id, false, from)
+    }
+
+    fn resolve_branch_name(
+        &self,
+        task_id: i64,
+        branch: Option<&str>,
+        ticket_id: Option<&str>,
+        todo_id: Option<i64>,
+    ) -> Result<String> {
         let todo_index = if let Some(t_id) = todo_id {
             let conn = self.db.get_connection();
             let idx: i64 = conn
@@ -61,22 +162,36 @@ impl<'a> WorktreeService<'a> {
             None
         };
 
-        let branch_name = naming::determine_branch_name(branch, ticket_id, task_id, todo_index)?;
+        naming::determine_branch_name(branch, ticket_id, task_id, todo_index)
+    }
+
+    fn create_worktree(
+        &self,
+        task_id: i64,
+        repo_path: &str,
+        branch_name: &str,
+        todo_id: Option<i64>,
+        is_base: bool,
+        base_revset: &str,
+    ) -> Result<Worktree> {
+        let worktree_path = naming::determine_worktree_path(repo_path, branch_name)?;
 
-        if jj::bookmark_exists(repo_path, &branch_name)? {
-            return Err(TrackError::BookmarkExists(branch_name));
+        if let Some(existing) =
+            self.find_existing_worktree(task_id, repo_path, branch_name, todo_id)?
+        {
+            return Err(TrackError::WorktreeExists(existing.path));
         }
 
-        let worktree_path = naming::determine_worktree_path(repo_path, &branch_name)?;
-        let task_bookmark = naming::task_bookmark_name(task_id, ticket_id);
-        let base_revset = if is_base { "@" } else { task_bookmark.as_str() };
+        if jj::bookmark_exists(repo_path, branch_name)? {
+            return Err(TrackError::BookmarkExists(branch_name.to_string()));
+        }
 
-        jj::create_workspace(repo_path, &worktree_path, &branch_name, base_revset)?;
+        jj::create_workspace(repo_path, &worktree_path, branch_name, base_revset)?;
 
         self.insert_worktree_record(
             task_id,
             &worktree_path,
-            &branch_name,
+            branch_name,
             repo_path,
             todo_id,
             is_base,
@@ -97,14 +212,73 @@ impl<'a> WorktreeService<'a> {
 
         conn.execute(
             "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
-            params![task_id, worktree_path, branch_name, repo_path, "active", now, todo_id, is_base as i32],
+            params![task_id, worktree_path, branch_name, repo_path, WorktreeStatus::ACTIVE, now, todo_id, is_base as i32],
         )?;
 
         let worktree_id = conn.last_insert_rowid();
+        self.db.touch_task(task_id)?;
         self.db.increment_rev("worktrees")?;
         self.get_worktree(worktree_id)
     }
 
+    /// Registers the main repository checkout as the task's base worktree.
+    ///
+    /// No workspace is created; the record gives TODO completion a merge target.
+    /// Returns `None` when the task already has a base worktree for this repo.
+    pub fn register_base_worktree(
+        &self,
+        task_id: i64,
+        repo_path: &str,
+        branch: &str,
+    ) -> Result<Option<Worktree>> {
+        let conn = self.db.get_connection();
+        let existing: Option<i64> = conn
+            .query_row(
+                "SELECT id FROM worktrees WHERE task_id = ?1 AND base_repo = ?2 AND is_base = 1 AND status = 'active'",
+                params![task_id, repo_path],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if existing.is_some() {
+            return Ok(None);
+        }
+
+        self.insert_worktree_record(task_id, repo_path, branch, repo_path, None, true)
+            .map(Some)
+    }
+
+    /// Gives the task a base worktree on `repo_path` if it has none, so TODO
+    /// completion has a merge target. Returns the base and whether it was created.
+    ///
+    /// Like `sync --create-base-worktree`, the main checkout is registered as
+    /// the base on the task bookmark, which must already exist.
+    pub fn ensure_base_worktree(
+        &self,
+        task_id: i64,
+        repo_path: &str,
+        ticket_id: Option<&str>,
+    ) -> Result<(Worktree, bool)> {
+        if let Some(base) = self.get_base_worktree(task_id)? {
+            return Ok((base, false));
+        }
+
+        if !jj::is_jj_repository(repo_path) {
+            return Err(TrackError::NotJjRepository(repo_path.to_string()));
+        }
+        let task_bookmark = naming::task_bookmark_name(task_id, ticket_id);
+        if !jj::bookmark_exists(repo_path, &task_bookmark)? {
+            return Err(TrackError::BookmarkNotFound {
+                bookmark: task_bookmark,
+                repo_path: repo_path.to_string(),
+            });
+        }
+        match self.register_base_worktree(task_id, repo_path, &task_bookmark)? {
+            Some(base) => Ok((base, true)),
+            // Registered by someone else since the check above
+            None => self.ensure_base_worktree(task_id, repo_path, ticket_id),
+        }
+    }
+
     pub fn add_existing_worktree(
         &self,
         task_id: i64,
@@ -149,6 +323,7 @@ impl<'a> WorktreeService<'a> {
 
         let conn = self.db.get_connection();
         conn.execute("DELETE FROM worktrees WHERE id = ?1", params![worktree.id])?;
+        self.db.touch_task(worktree.task_id)?;
         self.db.increment_rev("worktrees")?;
 
         self.add_existing_worktree(
@@ -171,10 +346,80 @@ impl<'a> WorktreeService<'a> {
             .map_err(|_| TrackError::WorktreeNotFound(worktree_id))
     }
 
+    /// Renames a worktree's bookmark and, with `move_dir`, its directory.
+    ///
+    /// The new name must be a legal ref that no other worktree or bookmark in the repo uses.
+    /// The record is updated in a transaction that is only committed once the bookmark
+    /// and directory are renamed; a failed directory move renames the bookmark back.
+    /// Worktrees of non-jj repos are rejected before anything is changed.
+    pub fn rename_worktree(
+        &self,
+        worktree_id: i64,
+        new_branch: &str,
+        move_dir: bool,
+    ) -> Result<Worktree> {
+        let worktree = self.get_worktree(worktree_id)?;
+        naming::validate_branch_name(new_branch)?;
+        if new_branch == worktree.branch {
+            return Ok(worktree);
+        }
+
+        let conn = self.db.get_connection();
+        let taken: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM worktrees WHERE branch = ?1 AND base_repo IS ?2 AND id != ?3 AND status = 'active')",
+            params![new_branch, worktree.base_repo, worktree_id],
+            |row| row.get(0),
+        )?;
+        if taken {
+            return Err(TrackError::BookmarkExists(new_branch.to_string()));
+        }
+
+        let is_main_checkout = worktree.is_main_checkout();
+        let mut new_path = worktree.path.clone();
+        if let Some(repo_path) = worktree.base_repo.as_deref() {
+            if !jj::is_jj_repository(repo_path) {
+                return Err(TrackError::NotJjRepository(repo_path.to_string()));
+            }
+            if jj::bookmark_exists(repo_path, new_branch)? {
+                return Err(TrackError::BookmarkExists(new_branch.to_string()));
+            }
+            if move_dir && !is_main_checkout {
+                new_path = naming::determine_worktree_path(repo_path, new_branch)?;
+            }
+        }
+
+        self.db.with_transaction(|| {
+            conn.execute(
+                "UPDATE worktrees SET branch = ?1, path = ?2 WHERE id = ?3",
+                params![new_branch, new_path, worktree_id],
+            )?;
+            self.db.touch_task(worktree.task_id)?;
+            self.db.increment_rev("worktrees")?;
+
+            if let Some(repo_path) = worktree.base_repo.as_deref() {
+                jj::rename_bookmark(repo_path, &worktree.branch, new_branch)?;
+                if new_path != worktree.path {
+                    if let Err(err) = jj::move_workspace(&worktree.path, &new_path) {
+                        let _ = jj::rename_bookmark(repo_path, new_branch, &worktree.branch);
+                        return Err(err);
+                    }
+                }
+            }
+
+            self.get_worktree(worktree_id)
+        })
+    }
+
+    /// Lists the task's active worktrees; merged and removed ones are history.
     pub fn list_worktrees(&self, task_id: i64) -> Result<Vec<Worktree>> {
+        self.list_worktrees_by_status(task_id, Some(WorktreeStatus::Active))
+    }
+
+    /// Lists the task's merged and removed worktrees, oldest first.
+    pub fn list_worktree_history(&self, task_id: i64) -> Result<Vec<Worktree>> {
         let conn = self.db.get_connection();
         let mut stmt = conn.prepare(
-            "SELECT id, task_id, path, branch, base_repo, status, created_at, todo_id, is_base FROM worktrees WHERE task_id = ?1 ORDER BY created_at ASC"
+            "SELECT id, task_id, path, branch, base_repo, status, created_at, todo_id, is_base FROM worktrees WHERE task_id = ?1 AND status != 'active' ORDER BY created_at ASC"
         )?;
 
         let worktrees = stmt
@@ -184,10 +429,75 @@ impl<'a> WorktreeService<'a> {
         Ok(worktrees)
     }
 
+    /// Lists the task's worktrees, only those in `status` when given.
+    pub fn list_worktrees_by_status(
+        &self,
+        task_id: i64,
+        status: Option<WorktreeStatus>,
+    ) -> Result<Vec<Worktree>> {
+        let conn = self.db.get_connection();
+        let mut stmt = conn.prepare(
+            "SELECT id, task_id, path, branch, base_repo, status, created_at, todo_id, is_base FROM worktrees WHERE task_id = ?1 AND (?2 IS NULL OR status = ?2) ORDER BY created_at ASC"
+        )?;
+
+        let worktrees = stmt
+            .query_map(
+                params![task_id, status.as_ref().map(WorktreeStatus::as_str)],
+                map_worktree_row,
+            )?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(worktrees)
+    }
+
+    /// Lists the task's worktrees created from `repo_path`, base worktrees last.
+    pub fn list_worktrees_for_repo(&self, task_id: i64, repo_path: &str) -> Result<Vec<Worktree>> {
+        let canonical = |path: &str| {
+            let path = PathBuf::from(path);
+            path.canonicalize().unwrap_or(path)
+        };
+        let repo = canonical(repo_path);
+
+        let mut worktrees: Vec<Worktree> = self
+            .list_worktrees(task_id)?
+            .into_iter()
+            .filter(|wt| {
+                wt.base_repo
+                    .as_deref()
+                    .is_some_and(|base| base == repo_path || canonical(base) == repo)
+            })
+            .collect();
+        worktrees.sort_by_key(|wt| wt.is_base);
+        Ok(worktrees)
+    }
+
+    /// Lists every recorded worktree across all tasks, grouped by task, only
+    /// those in `status` when given.
+    pub fn list_all_worktrees(&self, status: Option<WorktreeStatus>) -> Result<Vec<TaskWorktree>> {
+        let conn = self.db.get_connection();
+        let mut stmt = conn.prepare(
+            "SELECT w.id, w.task_id, w.path, w.branch, w.base_repo, w.status, w.created_at, w.todo_id, w.is_base, t.name FROM worktrees w JOIN tasks t ON t.id = w.task_id WHERE (?1 IS NULL OR w.status = ?1) ORDER BY w.task_id ASC, w.created_at ASC"
+        )?;
+
+        let worktrees = stmt
+            .query_map(
+                params![status.as_ref().map(WorktreeStatus::as_str)],
+                |row| {
+                    Ok(TaskWorktree {
+                        worktree: map_worktree_row(row)?,
+                        task_name: row.get(9)?,
+                    })
+                },
+            )?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(worktrees)
+    }
+
     pub fn list_repo_links(&self, worktree_id: i64) -> Result<Vec<RepoLink>> {
         let conn = self.db.get_connection();
         let mut stmt = conn.prepare(
-            "SELECT id, worktree_id, url, kind, created_at FROM repo_links WHERE worktree_id = ?1 ORDER BY created_at ASC"
+            "SELECT id, worktree_id, url, kind, created_at, state FROM repo_links WHERE worktree_id = ?1 ORDER BY created_at ASC"
         )?;
 
         let repo_links = stmt
@@ -198,6 +508,7 @@ impl<'a> WorktreeService<'a> {
                     url: row.get(2)?,
                     kind: row.get(3)?,
                     created_at: parse_datetime(row.get::<_, String>(4)?)?,
+                    state: row.get(5)?,
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -205,17 +516,142 @@ impl<'a> WorktreeService<'a> {
         Ok(repo_links)
     }
 
-    pub fn remove_worktree(&self, worktree_id: i64, keep_files: bool) -> Result<()> {
+    /// Attach a repository link (e.g. a pull request URL) to a worktree.
+    pub fn add_repo_link(&self, worktree_id: i64, url: &str, kind: &str) -> Result<RepoLink> {
+        LinkService::new(self.db).validate_url(url)?;
         let worktree = self.get_worktree(worktree_id)?;
 
+        let now = Utc::now();
+        let conn = self.db.get_connection();
+        conn.execute(
+            "INSERT INTO repo_links (worktree_id, url, kind, created_at) VALUES (?1, ?2, ?3, ?4)",
+            params![worktree_id, url, kind, now.to_rfc3339()],
+        )?;
+
+        let id = conn.last_insert_rowid();
+        self.db.touch_task(worktree.task_id)?;
+        self.db.increment_rev("worktrees")?;
+        Ok(RepoLink {
+            id,
+            worktree_id,
+            url: url.to_string(),
+            kind: kind.to_string(),
+            created_at: now,
+            state: None,
+        })
+    }
+
+    /// Record the forge state (`open`, `merged`, `closed`) fetched for a repository link.
+    pub fn set_repo_link_state(&self, link_id: i64, state: &str) -> Result<()> {
+        let conn = self.db.get_connection();
+        conn.execute(
+            "UPDATE repo_links SET state = ?1 WHERE id = ?2",
+            params![state, link_id],
+        )?;
+        let worktree_id: Option<i64> = conn
+            .query_row(
+                "SELECT worktree_id FROM repo_links WHERE id = ?1",
+                params![link_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if let Some(worktree_id) = worktree_id {
+            self.db.touch_worktree_task(worktree_id)?;
+        }
+        self.db.increment_rev("worktrees")?;
+        Ok(())
+    }
+
+    /// Detect a pull request URL for the worktree's branch from its `origin` remote.
+    pub fn detect_pr_url(&self, worktree: &Worktree) -> Result<String> {
+        let repo_path = worktree.base_repo.as_deref().unwrap_or(&worktree.path);
+        remote::detect_pr_url(repo_path, &worktree.branch)
+            .ok_or_else(|| TrackError::PrDetectionFailed(worktree.branch.clone()))
+    }
+
+    /// Refuses to drop a base worktree while TODO worktrees of the same task remain,
+    /// since completing those TODOs merges into the base.
+    pub fn ensure_removable(&self, worktree: &Worktree) -> Result<()> {
+        if !worktree.is_base {
+            return Ok(());
+        }
+        let remaining: i64 = self.db.get_connection().query_row(
+            "SELECT COUNT(*) FROM worktrees WHERE task_id = ?1 AND is_base = 0 AND status = 'active'",
+            params![worktree.task_id],
+            |row| row.get(0),
+        )?;
+        if remaining > 0 {
+            return Err(TrackError::BaseWorktreeInUse {
+                id: worktree.id,
+                remaining: remaining as usize,
+            });
+        }
+        Ok(())
+    }
+
+    /// Removes a worktree's workspace and directory, then marks its record
+    /// `removed` so the branch work stays on record.
+    ///
+    /// The record is only retired once the directory is confirmed gone, so a
+    /// partially failed removal leaves DB and filesystem in agreement; see
+    /// [`Self::force_remove_worktree`] for stuck directories.
+    pub fn remove_worktree(&self, worktree_id: i64, keep_files: bool) -> Result<()> {
+        let worktree = self.get_worktree(worktree_id)?;
         if !keep_files {
-            if let Some(base_repo) = &worktree.base_repo {
-                if Path::new(&worktree.path).exists() {
-                    jj::remove_workspace(base_repo, &worktree.path)?;
-                }
+            self.remove_worktree_files(&worktree)?;
+        }
+        self.set_worktree_status(worktree_id, WorktreeStatus::Removed)
+    }
+
+    /// Like [`Self::remove_worktree`], but retires the record even when the
+    /// directory could not be removed. Returns the removal error, if any, so the
+    /// caller can report what was left behind.
+    pub fn force_remove_worktree(&self, worktree_id: i64) -> Result<Option<TrackError>> {
+        let worktree = self.get_worktree(worktree_id)?;
+        let leftover = self.remove_worktree_files(&worktree).err();
+        self.set_worktree_status(worktree_id, WorktreeStatus::Removed)?;
+        Ok(leftover)
+    }
+
+    /// Deletes a worktree record outright, history included. An active
+    /// worktree's directory is removed first, as with [`Self::remove_worktree`].
+    pub fn purge_worktree(&self, worktree_id: i64) -> Result<()> {
+        let worktree = self.get_worktree(worktree_id)?;
+        if worktree.status == WorktreeStatus::Active {
+            self.remove_worktree_files(&worktree)?;
+        }
+        self.delete_worktree_record(worktree_id)
+    }
+
+    fn remove_worktree_files(&self, worktree: &Worktree) -> Result<()> {
+        // A base registered on the main checkout is only a record; never delete the repo.
+        if worktree.is_main_checkout() {
+            return Ok(());
+        }
+        if let Some(base_repo) = &worktree.base_repo {
+            if Path::new(&worktree.path).exists() {
+                jj::remove_workspace(base_repo, &worktree.path)?;
             }
         }
+        if Path::new(&worktree.path).exists() {
+            return Err(TrackError::WorktreeDirectoryRemains(worktree.path.clone()));
+        }
+        Ok(())
+    }
 
+    fn set_worktree_status(&self, worktree_id: i64, status: WorktreeStatus) -> Result<()> {
+        let conn = self.db.get_connection();
+        conn.execute(
+            "UPDATE worktrees SET status = ?1 WHERE id = ?2",
+            params![status.as_str(), worktree_id],
+        )?;
+        self.db.touch_worktree_task(worktree_id)?;
+        self.db.increment_rev("worktrees")?;
+        Ok(())
+    }
+
+    fn delete_worktree_record(&self, worktree_id: i64) -> Result<()> {
+        self.db.touch_worktree_task(worktree_id)?;
         let conn = self.db.get_connection();
         conn.execute("DELETE FROM worktrees WHERE id = ?1", params![worktree_id])?;
         self.db.increment_rev("worktrees")?;
@@ -258,17 +694,17 @@ impl<'a> WorktreeService<'a> {
     }
 
     fn try_remove_legacy_worktree(&self, worktree: &Worktree, force: bool) -> Result<()> {
-        if !Path::new(&worktree.path).exists() {
-            return self.remove_worktree(worktree.id, true);
-        }
-
-        if jj::has_uncommitted_changes(&worktree.path)? && !force {
+        if Path::new(&worktree.path).exists()
+            && jj::has_uncommitted_changes(&worktree.path)?
+            && !force
+        {
             return Err(TrackError::WorkspaceHasUncommittedChanges {
                 path: worktree.path.clone(),
             });
         }
 
-        self.remove_worktree(worktree.id, false)
+        // Migration drops legacy records entirely rather than keeping history
+        self.purge_worktree(worktree.id)
     }
 
     pub fn bookmark_exists_in_repo(&self, repo_path: &str, bookmark: &str) -> Result<bool> {
@@ -281,42 +717,115 @@ impl<'a> WorktreeService<'a> {
 
     pub fn get_todo_branch_name(
         &self,
+        branch: Option<&str>,
         task_id: i64,
         ticket_id: Option<&str>,
         todo_index: i64,
     ) -> Result<String> {
-        naming::determine_branch_name(None, ticket_id, task_id, Some(todo_index))
+        naming::determine_branch_name(branch, ticket_id, task_id, Some(todo_index))
     }
 
     pub fn complete_worktree_for_todo(&self, todo_id: i64) -> Result<Option<String>> {
+        self.complete_worktree_for_todo_with_commit(todo_id, None)
+    }
+
+    /// Like [`Self::complete_worktree_for_todo`], but a dirty workspace is committed with
+    /// `commit_message` before merging instead of being rejected.
+    pub fn complete_worktree_for_todo_with_commit(
+        &self,
+        todo_id: i64,
+        commit_message: Option<&str>,
+    ) -> Result<Option<String>> {
         let wt = match self.get_worktree_by_todo(todo_id)? {
             Some(wt) => wt,
             None => return Ok(None),
         };
 
-        let merge_target_path = if let Some(base_wt) = self.get_base_worktree(wt.task_id)? {
-            base_wt.path
-        } else {
-            wt.base_repo
-                .clone()
-                .ok_or(TrackError::NoWorkspacePathsAvailable)?
-        };
+        let merge_target_path = self.merge_target_path(&wt)?;
 
         if jj::has_uncommitted_changes(&wt.path)? {
-            return Err(TrackError::WorkspaceHasUncommittedChanges {
-                path: wt.path.clone(),
-            });
+            match commit_message {
+                Some(message) => jj::commit_all(&wt.path, message)?,
+                None => {
+                    return Err(TrackError::WorkspaceHasUncommittedChanges {
+                        path: wt.path.clone(),
+                    })
+                }
+            }
         }
 
         let ticket_id = self.get_task_ticket_id(wt.task_id)?;
         let task_bookmark = naming::task_bookmark_name(wt.task_id, ticket_id.as_deref());
 
         jj::integrate_todo_bookmark(&merge_target_path, &wt.branch, &task_bookmark)?;
-        self.remove_worktree(wt.id, false)?;
+        self.remove_worktree_files(&wt)?;
+        self.set_worktree_status(wt.id, WorktreeStatus::Merged)?;
 
         Ok(Some(wt.branch))
     }
 
+    /// Path of the workspace a TODO's bookmark is integrated into by
+    /// `track todo done`: the task's base worktree, else the TODO's base repo.
+    pub fn todo_merge_target(&self, todo_id: i64) -> Result<Option<String>> {
+        match self.get_worktree_by_todo(todo_id)? {
+            Some(wt) => self.merge_target_path(&wt).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Repository the task bookmark lives in: the base worktree's checkout, else
+    /// the task's first registered repository.
+    pub fn task_base_repo(&self, task_id: i64) -> Result<Option<String>> {
+        if let Some(base) = self.get_base_worktree(task_id)? {
+            return Ok(Some(base.base_repo.unwrap_or(base.path)));
+        }
+        Ok(RepoService::new(self.db)
+            .list_repos(task_id)?
+            .into_iter()
+            .next()
+            .map(|repo| repo.repo_path))
+    }
+
+    /// Pushes the task bookmark from `repo_path` to its git remote and returns
+    /// the bookmark name.
+    pub fn push_task_bookmark(&self, task_id: i64, repo_path: &str) -> Result<String> {
+        let ticket_id = self.get_task_ticket_id(task_id)?;
+        let task_bookmark = naming::task_bookmark_name(task_id, ticket_id.as_deref());
+        jj::push_bookmark(repo_path, &task_bookmark)?;
+        Ok(task_bookmark)
+    }
+
+    fn merge_target_path(&self, wt: &Worktree) -> Result<String> {
+        if let Some(base_wt) = self.get_base_worktree(wt.task_id)? {
+            return Ok(base_wt.path);
+        }
+        wt.base_repo
+            .clone()
+            .ok_or(TrackError::NoWorkspacePathsAvailable)
+    }
+
+    /// Checks whether `worktree`'s bookmark is already merged into the bookmark
+    /// `track todo done` would integrate it into (the task bookmark), or into
+    /// `trunk()` for the base worktree.
+    pub fn merge_status(&self, worktree: &Worktree) -> Result<MergeStatus> {
+        let repo_path = worktree.base_repo.as_deref().unwrap_or(&worktree.path);
+        let (base, base_revset) = if worktree.is_base {
+            ("trunk()".to_string(), "trunk()".to_string())
+        } else {
+            let ticket_id = self.get_task_ticket_id(worktree.task_id)?;
+            let task_bookmark = naming::task_bookmark_name(worktree.task_id, ticket_id.as_deref());
+            let revset = jj::revset_symbol(&task_bookmark);
+            (task_bookmark, revset)
+        };
+
+        let unmerged_commits = jj::commits_not_in(repo_path, &worktree.branch, &base_revset)?;
+        Ok(MergeStatus {
+            branch: worktree.branch.clone(),
+            base,
+            unmerged_commits,
+        })
+    }
+
     pub fn has_uncommitted_changes(&self, path: &str) -> Result<bool> {
         jj::has_uncommitted_changes(path)
     }
@@ -333,7 +842,7 @@ impl<'a> WorktreeService<'a> {
     fn get_worktree_by_todo(&self, todo_id: i64) -> Result<Option<Worktree>> {
         let conn = self.db.get_connection();
         let mut stmt = conn.prepare(
-            "SELECT id, task_id, path, branch, base_repo, status, created_at, todo_id, is_base FROM worktrees WHERE todo_id = ?1"
+            "SELECT id, task_id, path, branch, base_repo, status, created_at, todo_id, is_base FROM worktrees WHERE todo_id = ?1 AND status = 'active'"
         )?;
 
         stmt.query_row(params![todo_id], map_worktree_row)
@@ -341,10 +850,31 @@ impl<'a> WorktreeService<'a> {
             .map_err(TrackError::from)
     }
 
+    /// Finds a registered worktree for the same repo and branch (or TODO).
+    fn find_existing_worktree(
+        &self,
+        task_id: i64,
+        repo_path: &str,
+        branch: &str,
+        todo_id: Option<i64>,
+    ) -> Result<Option<Worktree>> {
+        let conn = self.db.get_connection();
+        let mut stmt = conn.prepare(
+            "SELECT id, task_id, path, branch, base_repo, status, created_at, todo_id, is_base FROM worktrees WHERE task_id = ?1 AND base_repo = ?2 AND (branch = ?3 OR (?4 IS NOT NULL AND todo_id = ?4)) AND status = 'active' LIMIT 1"
+        )?;
+
+        stmt.query_row(
+            params![task_id, repo_path, branch, todo_id],
+            map_worktree_row,
+        )
+        .optional()
+        .map_err(TrackError::from)
+    }
+
     fn get_base_worktree(&self, task_id: i64) -> Result<Option<Worktree>> {
         let conn = self.db.get_connection();
         let mut stmt = conn.prepare(
-            "SELECT id, task_id, path, branch, base_repo, status, created_at, todo_id, is_base FROM worktrees WHERE task_id = ?1 AND is_base = 1"
+            "SELECT id, task_id, path, branch, base_repo, status, created_at, todo_id, is_base FROM worktrees WHERE task_id = ?1 AND is_base = 1 AND status = 'active'"
         )?;
 
         stmt.query_row(params![task_id], map_worktree_row)
@@ -361,7 +891,7 @@ fn map_worktree_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Worktree> {
         path: row.get(2)?,
         branch: row.get(3)?,
         base_repo: row.get(4)?,
-        status: row.get(5)?,
+        status: parse_worktree_status(row.get(5)?)?,
         created_at: parse_datetime(row.get::<_, String>(6)?)?,
         todo_id: row.get(7)?,
         is_base: is_base != 0,
@@ -431,6 +961,270 @@ mod tests {
         );
     }
 
+    #[test]
+    fn register_base_worktree_is_idempotent_and_keeps_checkout() {
+        let db = setup_db();
+        let task = TaskService::new(&db)
+            .create_task("Task", None, None, None)
+            .unwrap();
+        let service = WorktreeService::new(&db);
+        let repo = tempfile::tempdir().unwrap();
+        let repo_path = repo.path().to_string_lossy().to_string();
+
+        let base = service
+            .register_base_worktree(task.id, &repo_path, "task/task-1")
+            .unwrap()
+            .expect("base registered");
+        assert!(base.is_base);
+        assert_eq!(base.path, repo_path);
+        assert!(service
+            .register_base_worktree(task.id, &repo_path, "task/task-1")
+            .unwrap()
+            .is_none());
+
+        service.remove_worktree(base.id, false).unwrap();
+        assert!(repo.path().exists());
+        assert!(service.list_worktrees(task.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn ensure_base_worktree_is_noop_when_base_exists() {
+        let db = setup_db();
+        let task = TaskService::new(&db)
+            .create_task("Task", None, None, None)
+            .unwrap();
+        let service = WorktreeService::new(&db);
+        let repo = tempfile::tempdir().unwrap();
+        let repo_path = repo.path().to_string_lossy().to_string();
+
+        let base = service
+            .register_base_worktree(task.id, &repo_path, "task/task-1")
+            .unwrap()
+            .expect("base registered");
+        let (existing, created) = service
+            .ensure_base_worktree(task.id, &repo_path, None)
+            .unwrap();
+        assert!(!created);
+        assert_eq!(existing.id, base.id);
+        assert_eq!(service.list_worktrees(task.id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn task_base_repo_prefers_base_worktree_over_registered_repos() {
+        let db = setup_db();
+        let task = TaskService::new(&db)
+            .create_task("Task", None, None, None)
+            .unwrap();
+        let service = WorktreeService::new(&db);
+        assert_eq!(service.task_base_repo(task.id).unwrap(), None);
+
+        db.get_connection()
+            .execute(
+                "INSERT INTO task_repos (task_id, task_index, repo_path, created_at) VALUES (?1, 1, '/repos/first', datetime('now'))",
+                params![task.id],
+            )
+            .unwrap();
+        assert_eq!(
+            service.task_base_repo(task.id).unwrap().as_deref(),
+            Some("/repos/first")
+        );
+
+        service
+            .register_base_worktree(task.id, "/repos/base", "task/task-1")
+            .unwrap();
+        assert_eq!(
+            service.task_base_repo(task.id).unwrap().as_deref(),
+            Some("/repos/base")
+        );
+    }
+
+    #[test]
+    fn ensure_base_worktree_rejects_non_jj_repository() {
+        let db = setup_db();
+        let task = TaskService::new(&db)
+            .create_task("Task", None, None, None)
+            .unwrap();
+        let service = WorktreeService::new(&db);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().to_string_lossy().to_string();
+
+        let result = service.ensure_base_worktree(task.id, &path, None);
+        assert!(matches!(result, Err(TrackError::NotJjRepository(_))));
+        assert!(service.list_worktrees(task.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_ensure_base_worktree_registers_checkout() {
+        if !require_jj() {
+            return;
+        }
+
+        let db = setup_db();
+        let task = TaskService::new(&db)
+            .create_task("Task", None, Some("PROJ-401"), None)
+            .unwrap();
+        let service = WorktreeService::new(&db);
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_path = temp_dir.path().to_str().unwrap();
+        init_jj_repo(repo_path);
+        describe_change(repo_path, "Initial commit");
+
+        let missing = service.ensure_base_worktree(task.id, repo_path, Some("PROJ-401"));
+        assert!(matches!(missing, Err(TrackError::BookmarkNotFound { .. })));
+
+        create_bookmark(repo_path, "task/PROJ-401");
+        let (base, created) = service
+            .ensure_base_worktree(task.id, repo_path, Some("PROJ-401"))
+            .unwrap();
+        assert!(created);
+        assert!(base.is_base);
+        assert_eq!(base.path, repo_path);
+        assert_eq!(base.branch, "task/PROJ-401");
+    }
+
+    #[test]
+    fn ensure_removable_guards_base_with_todo_worktrees() {
+        let db = setup_db();
+        let task = TaskService::new(&db)
+            .create_task("Task", None, None, None)
+            .unwrap();
+        let service = WorktreeService::new(&db);
+        let repo = tempfile::tempdir().unwrap();
+        let repo_path = repo.path().to_string_lossy().to_string();
+
+        let base = service
+            .register_base_worktree(task.id, &repo_path, "task/task-1")
+            .unwrap()
+            .expect("base registered");
+        service.ensure_removable(&base).unwrap();
+
+        db.get_connection()
+            .execute(
+                "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, '/wt/todo', 'b', ?2, 'active', ?3, NULL, 0)",
+                rusqlite::params![task.id, repo_path, Utc::now().to_rfc3339()],
+            )
+            .unwrap();
+
+        let err = service.ensure_removable(&base).unwrap_err();
+        assert!(matches!(
+            err,
+            TrackError::BaseWorktreeInUse { id, remaining: 1 } if id == base.id
+        ));
+        let todo_wt = service
+            .list_worktrees(task.id)
+            .unwrap()
+            .into_iter()
+            .find(|wt| !wt.is_base)
+            .unwrap();
+        service.ensure_removable(&todo_wt).unwrap();
+    }
+
+    #[test]
+    fn list_worktrees_for_repo_matches_base_repo() {
+        let db = setup_db();
+        let task = TaskService::new(&db)
+            .create_task("Task", None, None, None)
+            .unwrap();
+        let service = WorktreeService::new(&db);
+        let repo = tempfile::tempdir().unwrap();
+        let repo_path = repo.path().to_string_lossy().to_string();
+
+        service
+            .register_base_worktree(task.id, &repo_path, "task/task-1")
+            .unwrap()
+            .expect("base registered");
+        for (path, base_repo) in [
+            ("/wt/todo", repo_path.as_str()),
+            ("/wt/other", "/other/repo"),
+        ] {
+            db.get_connection()
+                .execute(
+                    "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, ?2, 'b', ?3, 'active', ?4, NULL, 0)",
+                    rusqlite::params![task.id, path, base_repo, Utc::now().to_rfc3339()],
+                )
+                .unwrap();
+        }
+
+        let paths: Vec<String> = service
+            .list_worktrees_for_repo(task.id, &format!("{repo_path}/."))
+            .unwrap()
+            .into_iter()
+            .map(|wt| wt.path)
+            .collect();
+        assert_eq!(paths, vec!["/wt/todo".to_string(), repo_path]);
+    }
+
+    #[test]
+    fn list_all_worktrees_spans_tasks_with_names() {
+        let db = setup_db();
+        let task_service = TaskService::new(&db);
+        let service = WorktreeService::new(&db);
+        let first = task_service.create_task("First", None, None, None).unwrap();
+        let second = task_service
+            .create_task("Second", None, None, None)
+            .unwrap();
+
+        let now = Utc::now().to_rfc3339();
+        for (task_id, path) in [(second.id, "/wt/b"), (first.id, "/wt/a")] {
+            db.get_connection()
+                .execute(
+                    "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, ?2, 'b', '/repo', 'active', ?3, NULL, 0)",
+                    rusqlite::params![task_id, path, now],
+                )
+                .unwrap();
+        }
+
+        let all = service.list_all_worktrees(None).unwrap();
+        let summary: Vec<_> = all
+            .iter()
+            .map(|tw| (tw.task_name.as_str(), tw.worktree.path.as_str()))
+            .collect();
+        assert_eq!(summary, vec![("First", "/wt/a"), ("Second", "/wt/b")]);
+    }
+
+    #[test]
+    fn list_worktrees_filters_by_status() {
+        let db = setup_db();
+        let task = TaskService::new(&db)
+            .create_task("Task", None, None, None)
+            .unwrap();
+        let service = WorktreeService::new(&db);
+
+        let now = Utc::now().to_rfc3339();
+        for (path, status) in [
+            ("/wt/a", "active"),
+            ("/wt/m", "merged"),
+            ("/wt/r", "removed"),
+        ] {
+            db.get_connection()
+                .execute(
+                    "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, ?2, 'b', '/repo', ?3, ?4, NULL, 0)",
+                    rusqlite::params![task.id, path, status, now],
+                )
+                .unwrap();
+        }
+
+        assert_eq!(
+            service
+                .list_worktrees_by_status(task.id, None)
+                .unwrap()
+                .len(),
+            3
+        );
+        let merged = service
+            .list_worktrees_by_status(task.id, Some(WorktreeStatus::Merged))
+            .unwrap();
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].path, "/wt/m");
+        assert_eq!(merged[0].status, WorktreeStatus::Merged);
+
+        let removed = service
+            .list_all_worktrees(Some(WorktreeStatus::Removed))
+            .unwrap();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].worktree.path, "/wt/r");
+    }
+
     #[test]
     fn cleanup_removes_db_record_when_path_missing() {
         let db = setup_db();
@@ -451,6 +1245,213 @@ mod tests {
         assert!(service.list_worktrees(task.id).unwrap().is_empty());
     }
 
+    #[test]
+    fn main_checkout_base_is_not_legacy() {
+        let db = setup_db();
+        let task_service = TaskService::new(&db);
+        let service = WorktreeService::new(&db);
+        let task = task_service.create_task("Task", None, None, None).unwrap();
+
+        let now = Utc::now().to_rfc3339();
+        for path in ["/repo", "/repo/.worktrees/base"] {
+            db.get_connection()
+                .execute(
+                    "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, ?2, 'task/task-1', '/repo', 'active', ?3, NULL, 1)",
+                    rusqlite::params![task.id, path, now],
+                )
+                .unwrap();
+        }
+
+        let legacy: Vec<String> = service
+            .list_legacy_worktrees(task.id)
+            .unwrap()
+            .into_iter()
+            .map(|wt| wt.path)
+            .collect();
+        assert_eq!(legacy, vec!["/repo/.worktrees/base"]);
+    }
+
+    #[test]
+    fn add_worktree_reports_existing_worktree() {
+        let db = setup_db();
+        let task_service = TaskService::new(&db);
+        let service = WorktreeService::new(&db);
+        let task = task_service.create_task("Task", None, None, None).unwrap();
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_path = temp_dir.path().to_str().unwrap();
+        fs::create_dir(temp_dir.path().join(".jj")).unwrap();
+
+        // Branch already registered in the database
+        let now = Utc::now().to_rfc3339();
+        db.get_connection()
+            .execute(
+                "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, ?2, ?3, ?4, 'active', ?5, NULL, 0)",
+                rusqlite::params![task.id, "/elsewhere/other", "other", repo_path, now],
+            )
+            .unwrap();
+        let result = service.add_worktree(task.id, repo_path, Some("other"), None, None, false);
+        assert!(
+            matches!(result, Err(TrackError::WorktreeExists(path)) if path == "/elsewhere/other")
+        );
+    }
+
+    #[test]
+    fn remove_worktree_keeps_record_while_directory_remains() {
+        let db = setup_db();
+        let task = TaskService::new(&db)
+            .create_task("Task", None, None, None)
+            .unwrap();
+        let service = WorktreeService::new(&db);
+        let stuck = tempfile::tempdir().unwrap();
+        db.get_connection()
+            .execute(
+                "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, ?2, 'b', NULL, 'active', ?3, NULL, 0)",
+                rusqlite::params![task.id, stuck.path().to_str().unwrap(), Utc::now().to_rfc3339()],
+            )
+            .unwrap();
+        let worktree_id = service.list_worktrees(task.id).unwrap()[0].id;
+
+        assert!(matches!(
+            service.remove_worktree(worktree_id, false),
+            Err(TrackError::WorktreeDirectoryRemains(_))
+        ));
+        assert_eq!(service.list_worktrees(task.id).unwrap().len(), 1);
+
+        let leftover = service.force_remove_worktree(worktree_id).unwrap();
+        assert!(matches!(
+            leftover,
+            Some(TrackError::WorktreeDirectoryRemains(_))
+        ));
+        assert!(service.list_worktrees(task.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn remove_worktree_keeps_history_until_purged() {
+        use crate::services::TodoService;
+
+        let db = setup_db();
+        let task = TaskService::new(&db)
+            .create_task("Task", None, None, None)
+            .unwrap();
+        let todo = TodoService::new(&db)
+            .add_todo(task.id, "Item", false)
+            .unwrap();
+        let service = WorktreeService::new(&db);
+        db.get_connection()
+            .execute(
+                "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, '/missing/wt', 'task-1-todo-1', '/repo', 'active', ?2, ?3, 0)",
+                rusqlite::params![task.id, Utc::now().to_rfc3339(), todo.id],
+            )
+            .unwrap();
+        let worktree_id = service.list_worktrees(task.id).unwrap()[0].id;
+
+        service.remove_worktree(worktree_id, false).unwrap();
+        assert!(service.list_worktrees(task.id).unwrap().is_empty());
+        assert_eq!(service.todo_merge_target(todo.id).unwrap(), None);
+        let history = service.list_worktree_history(task.id).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].status, WorktreeStatus::Removed);
+        assert_eq!(history[0].todo_id, Some(todo.id));
+
+        service.purge_worktree(worktree_id).unwrap();
+        assert!(service.list_worktree_history(task.id).unwrap().is_empty());
+        assert!(matches!(
+            service.get_worktree(worktree_id),
+            Err(TrackError::WorktreeNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn rename_worktree_validates_and_updates_branch() {
+        let db = setup_db();
+        let task_service = TaskService::new(&db);
+        let service = WorktreeService::new(&db);
+        let task = task_service.create_task("Task", None, None, None).unwrap();
+
+        let now = Utc::now().to_rfc3339();
+        let conn = db.get_connection();
+        for (path, branch) in [("/repo/wt", "feautre"), ("/repo/other", "taken")] {
+            conn.execute(
+                "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, ?2, ?3, '/repo', 'active', ?4, NULL, 0)",
+                rusqlite::params![task.id, path, branch, now],
+            )
+            .unwrap();
+        }
+        let worktree_id = service.list_worktrees(task.id).unwrap()[0].id;
+
+        assert!(matches!(
+            service.rename_worktree(worktree_id, "bad name", false),
+            Err(TrackError::InvalidBranchName(_))
+        ));
+        assert!(matches!(
+            service.rename_worktree(worktree_id, "taken", false),
+            Err(TrackError::BookmarkExists(name)) if name == "taken"
+        ));
+
+        // /repo is not a jj repository, so neither the bookmark nor the record changes.
+        for move_dir in [false, true] {
+            assert!(matches!(
+                service.rename_worktree(worktree_id, "feature", move_dir),
+                Err(TrackError::NotJjRepository(path)) if path == "/repo"
+            ));
+        }
+        let unchanged = service.get_worktree(worktree_id).unwrap();
+        assert_eq!(unchanged.branch, "feautre");
+        assert_eq!(unchanged.path, "/repo/wt");
+
+        // Without a base repo only the record is renamed.
+        conn.execute(
+            "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, '/detached', 'old', NULL, 'active', ?2, NULL, 0)",
+            rusqlite::params![task.id, now],
+        )
+        .unwrap();
+        let detached_id = conn.last_insert_rowid();
+        let renamed = service.rename_worktree(detached_id, "new", true).unwrap();
+        assert_eq!(renamed.branch, "new");
+        assert_eq!(renamed.path, "/detached");
+    }
+
+    #[test]
+    fn add_repo_link_attaches_url_to_worktree() {
+        let db = setup_db();
+        let task_service = TaskService::new(&db);
+        let service = WorktreeService::new(&db);
+        let task = task_service.create_task("Task", None, None, None).unwrap();
+
+        let now = Utc::now().to_rfc3339();
+        db.get_connection()
+            .execute(
+                "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, ?2, ?3, ?4, 'active', ?5, NULL, 0)",
+                rusqlite::params![task.id, "/repo/wt", "track/feature", "/repo", now],
+            )
+            .unwrap();
+        let worktree_id = db.get_connection().last_insert_rowid();
+
+        let link = service
+            .add_repo_link(worktree_id, "https://github.com/o/r/pull/1", "pr")
+            .unwrap();
+        assert_eq!(link.kind, "pr");
+
+        let links = service.list_repo_links(worktree_id).unwrap();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].url, "https://github.com/o/r/pull/1");
+        assert_eq!(links[0].state, None);
+
+        service.set_repo_link_state(link.id, "merged").unwrap();
+        let links = service.list_repo_links(worktree_id).unwrap();
+        assert_eq!(links[0].state.as_deref(), Some("merged"));
+
+        assert!(matches!(
+            service.add_repo_link(worktree_id, "not-a-url", "pr"),
+            Err(TrackError::InvalidUrl(_))
+        ));
+        assert!(matches!(
+            service.add_repo_link(worktree_id + 1, "https://example.com", "pr"),
+            Err(TrackError::WorktreeNotFound(_))
+        ));
+    }
+
     #[test]
     fn test_add_worktree_and_get() {
         if !require_jj() {
@@ -484,6 +1485,45 @@ mod tests {
         assert_eq!(retrieved.branch, "task/PROJ-100");
     }
 
+    #[test]
+    fn test_add_worktree_from_explicit_revision() {
+        if !require_jj() {
+            return;
+        }
+
+        let db = setup_db();
+        let task = TaskService::new(&db)
+            .create_task("Test Task", None, None, None)
+            .unwrap();
+        let service = WorktreeService::new(&db);
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_path = temp_dir.path().to_str().unwrap();
+        init_jj_repo(repo_path);
+        fs::write(temp_dir.path().join("README.md"), "# Test").unwrap();
+        describe_change(repo_path, "Initial commit");
+        create_bookmark(repo_path, "integration");
+
+        let worktree = service
+            .add_worktree_from(
+                task.id,
+                repo_path,
+                Some("feature"),
+                None,
+                None,
+                "integration",
+            )
+            .unwrap();
+        assert_eq!(worktree.branch, "feature");
+        assert!(!worktree.is_base);
+        assert!(Path::new(&worktree.path).join("README.md").exists());
+
+        assert!(matches!(
+            service.add_worktree_from(task.id, repo_path, Some("other"), None, None, "missing"),
+            Err(TrackError::Jj(_))
+        ));
+    }
+
     #[test]
     fn test_list_worktrees() {
         use crate::services::TodoService;
@@ -732,4 +1772,37 @@ mod tests {
         let result = service.add_worktree(1, repo_path, Some("existing-branch"), None, None, false);
         assert!(matches!(result, Err(TrackError::BookmarkExists(_))));
     }
+
+    #[test]
+    fn test_add_or_attach_worktree_reuses_existing_bookmark() {
+        if !require_jj() {
+            return;
+        }
+
+        let db = setup_db();
+        let service = WorktreeService::new(&db);
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_path = temp_dir.path().to_str().unwrap();
+        init_jj_repo(repo_path);
+        fs::write(temp_dir.path().join("README.md"), "# Test").unwrap();
+        describe_change(repo_path, "Initial commit");
+        create_bookmark(repo_path, "colleague-branch");
+
+        let (worktree, attached) = service
+            .add_or_attach_worktree(1, repo_path, Some("colleague-branch"), None, None)
+            .unwrap();
+        assert!(attached);
+        assert_eq!(worktree.branch, "colleague-branch");
+        assert!(Path::new(&worktree.path).exists());
+
+        let result =
+            service.add_or_attach_worktree(1, repo_path, Some("colleague-branch"), None, None);
+        assert!(matches!(result, Err(TrackError::WorktreeExists(_))));
+
+        let (worktree, attached) = service
+            .add_or_attach_worktree(1, repo_path, Some("fresh-branch"), None, None)
+            .unwrap();
+        assert!(!attached);
+        assert_eq!(worktree.branch, "fresh-branch");
+    }
 }
@@ -1,4 +1,4 @@
-use crate::utils::Result;
+use crate::utils::{Result, TrackError};
 use chrono::Utc;
 use std::path::Path;
 
@@ -29,12 +29,42 @@ pub fn task_bookmark_name(task_id: i64, ticket_id: Option<&str>) -> String {
     }
 }
 
+/// Flattens `branch` into a single directory under `repo_path` (`task/PROJ-1` -> `task-PROJ-1`),
+/// appending `-2`, `-3`, ... while that directory already exists.
 pub fn determine_worktree_path(repo_path: &str, branch: &str) -> Result<String> {
-    let sanitized_branch = branch.replace(['/', '\\'], "_");
-    let worktree_path = Path::new(repo_path).join(sanitized_branch);
+    let dir_name = branch.replace(['/', '\\'], "-");
+    let repo = Path::new(repo_path);
+    let mut worktree_path = repo.join(&dir_name);
+    let mut suffix = 2;
+    while worktree_path.exists() {
+        worktree_path = repo.join(format!("{dir_name}-{suffix}"));
+        suffix += 1;
+    }
     Ok(worktree_path.to_string_lossy().to_string())
 }
 
+/// Rejects names that are not legal git refs (mirrors `git check-ref-format --branch`).
+pub fn validate_branch_name(name: &str) -> Result<()> {
+    let invalid = name.is_empty()
+        || name == "@"
+        || name.starts_with(['-', '/'])
+        || name.ends_with(['/', '.'])
+        || name.ends_with(".lock")
+        || name.contains("..")
+        || name.contains("//")
+        || name.contains("@{")
+        || name.contains("/.")
+        || name.starts_with('.')
+        || name
+            .chars()
+            .any(|c| c.is_control() || c.is_whitespace() || "~^:?*[\\".contains(c));
+
+    if invalid {
+        return Err(TrackError::InvalidBranchName(name.to_string()));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,11 +112,56 @@ mod tests {
         } else {
             "/path/to/repo"
         };
-        let result = determine_worktree_path(repo_path, "feature/test").unwrap();
+        let result = determine_worktree_path(repo_path, "task/PROJ-1").unwrap();
         let expected = Path::new(repo_path)
-            .join("feature_test")
+            .join("task-PROJ-1")
             .to_string_lossy()
             .to_string();
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn determine_worktree_path_suffixes_existing_directories() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_path = temp_dir.path().to_str().unwrap();
+        std::fs::create_dir(temp_dir.path().join("task-PROJ-1")).unwrap();
+        std::fs::create_dir(temp_dir.path().join("task-PROJ-1-2")).unwrap();
+
+        let result = determine_worktree_path(repo_path, "task/PROJ-1").unwrap();
+        assert_eq!(
+            result,
+            temp_dir.path().join("task-PROJ-1-3").to_string_lossy()
+        );
+    }
+
+    #[test]
+    fn validate_branch_name_accepts_legal_refs() {
+        for name in ["feature", "PROJ-1/fix-typo", "task-2-todo-7", "user/a.b"] {
+            assert!(validate_branch_name(name).is_ok(), "{name}");
+        }
+    }
+
+    #[test]
+    fn validate_branch_name_rejects_illegal_refs() {
+        for name in [
+            "",
+            "-x",
+            "a..b",
+            "a b",
+            "a~1",
+            "a:b",
+            "trailing/",
+            "x.lock",
+            "a/.hidden",
+            "@",
+        ] {
+            assert!(
+                matches!(
+                    validate_branch_name(name),
+                    Err(TrackError::InvalidBranchName(_))
+                ),
+                "{name}"
+            );
+        }
+    }
 }
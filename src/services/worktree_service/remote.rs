@@ -0,0 +1,113 @@
+use crate::utils::CommandExt;
+use std::process::Command;
+
+/// Detect a pull request URL for `branch`, preferring `gh` and falling back
+/// to a GitHub PR search URL built from the `origin` remote.
+pub fn detect_pr_url(repo_path: &str, branch: &str) -> Option<String> {
+    gh_pr_url(repo_path, branch).or_else(|| {
+        origin_remote_url(repo_path).and_then(|remote| github_pr_search_url(&remote, branch))
+    })
+}
+
+fn gh_pr_url(repo_path: &str, branch: &str) -> Option<String> {
+    let output = Command::new("gh")
+        .current_dir(repo_path)
+        .args(["pr", "view", branch, "--json", "url", "--jq", ".url"])
+        .logged_output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    url.starts_with("https://").then_some(url)
+}
+
+fn origin_remote_url(repo_path: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["-C", repo_path, "remote", "get-url", "origin"])
+        .logged_output()
+        .ok()?;
+
+    if output.status.success() {
+        let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if !url.is_empty() {
+            return Some(url);
+        }
+    }
+
+    // Non-colocated jj repositories keep remotes in the jj git store
+    let output = Command::new("jj")
+        .args(["-R", repo_path, "git", "remote", "list"])
+        .logged_output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix("origin "))
+        .map(|url| url.trim().to_string())
+}
+
+/// Build `https://github.com/<owner>/<repo>/pulls?q=...` for a branch.
+///
+/// Accepts HTTPS (`https://github.com/o/r.git`) and SSH
+/// (`git@github.com:o/r.git`, `ssh://git@github.com/o/r`) remotes.
+pub fn github_pr_search_url(remote: &str, branch: &str) -> Option<String> {
+    let remote = remote.trim();
+    let path = remote
+        .strip_prefix("https://github.com/")
+        .or_else(|| remote.strip_prefix("http://github.com/"))
+        .or_else(|| remote.strip_prefix("git@github.com:"))
+        .or_else(|| remote.strip_prefix("ssh://git@github.com/"))?;
+
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let mut parts = path.split('/');
+    let (owner, repo) = (parts.next()?, parts.next()?);
+    if owner.is_empty() || repo.is_empty() || parts.next().is_some() {
+        return None;
+    }
+
+    Some(format!(
+        "https://github.com/{owner}/{repo}/pulls?q=is%3Apr+head%3A{}",
+        branch.replace('/', "%2F")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn github_pr_search_url_from_https_remote() {
+        let url = github_pr_search_url("https://github.com/manji0/track.git", "track/fix-1");
+        assert_eq!(
+            url.as_deref(),
+            Some("https://github.com/manji0/track/pulls?q=is%3Apr+head%3Atrack%2Ffix-1")
+        );
+    }
+
+    #[test]
+    fn github_pr_search_url_from_ssh_remotes() {
+        let expected = Some("https://github.com/o/r/pulls?q=is%3Apr+head%3Afeature".to_string());
+        assert_eq!(
+            github_pr_search_url("git@github.com:o/r.git", "feature"),
+            expected
+        );
+        assert_eq!(
+            github_pr_search_url("ssh://git@github.com/o/r", "feature"),
+            expected
+        );
+    }
+
+    #[test]
+    fn github_pr_search_url_rejects_other_hosts() {
+        assert!(github_pr_search_url("https://gitlab.com/o/r.git", "feature").is_none());
+        assert!(github_pr_search_url("https://github.com/o", "feature").is_none());
+    }
+}
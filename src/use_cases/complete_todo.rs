@@ -1,6 +1,6 @@
 use crate::db::Database;
-use crate::models::TodoStatus;
-use crate::services::{TodoService, WorktreeService};
+use crate::models::{Todo, TodoStatus};
+use crate::services::{ScrapService, TodoService, WorktreeService};
 use crate::utils::{Result, TrackError};
 
 /// Result of completing a TODO, including optional workspace bookmark name.
@@ -8,6 +8,19 @@ use crate::utils::{Result, TrackError};
 pub struct CompleteTodoOutcome {
     pub task_index: i64,
     pub merged_bookmark: Option<String>,
+    /// Workspace the bookmark was integrated into, when a merge happened.
+    pub merge_target: Option<String>,
+    /// Task-scoped index of the closing-note scrap, when a note was given.
+    pub note_scrap_id: Option<i64>,
+}
+
+/// Optional inputs for [`CompleteTodoUseCase::execute_with_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompleteTodoOptions<'o> {
+    /// Commit message used when the TODO's workspace has uncommitted changes.
+    pub commit_message: Option<&'o str>,
+    /// Closing note recorded as a scrap attached to the TODO.
+    pub note: Option<&'o str>,
 }
 
 /// Completes a TODO: merges/removes JJ workspaces, then marks the TODO done in SQLite.
@@ -27,6 +40,28 @@ impl<'a> CompleteTodoUseCase<'a> {
 
     /// Complete the TODO identified by task-scoped `task_index` on `task_id`.
     pub fn execute(&self, task_id: i64, task_index: i64) -> Result<CompleteTodoOutcome> {
+        self.execute_with_options(task_id, task_index, CompleteTodoOptions::default())
+    }
+
+    /// Complete the TODO, committing a dirty workspace with `options.commit_message`
+    /// when given.
+    ///
+    /// `options.note` is recorded as a scrap in the same transaction that marks the
+    /// TODO done.
+    pub fn execute_with_options(
+        &self,
+        task_id: i64,
+        task_index: i64,
+        options: CompleteTodoOptions,
+    ) -> Result<CompleteTodoOutcome> {
+        let CompleteTodoOptions {
+            commit_message,
+            note,
+        } = options;
+        if note.is_some_and(|note| note.trim().is_empty()) {
+            return Err(TrackError::EmptyScrapContent);
+        }
+
         let todo_service = TodoService::new(self.db);
         let worktree_service = WorktreeService::new(self.db);
 
@@ -39,26 +74,53 @@ impl<'a> CompleteTodoUseCase<'a> {
             });
         }
 
-        let merged_bookmark = worktree_service.complete_worktree_for_todo(todo.id)?;
+        let merge_target = worktree_service.todo_merge_target(todo.id)?;
+        let merged_bookmark =
+            worktree_service.complete_worktree_for_todo_with_commit(todo.id, commit_message)?;
 
-        if let Err(err) = todo_service.mark_done(todo.id) {
-            if let Some(bookmark) = merged_bookmark.clone() {
-                return Err(TrackError::TodoCompletionDbFailed {
-                    todo_index: task_index,
-                    bookmark,
-                    detail: err.to_string(),
-                });
-            }
-            return Err(err);
-        }
+        let note_scrap_id =
+            mark_done_after_merge(self.db, &todo, note, merged_bookmark.as_deref())?;
 
         Ok(CompleteTodoOutcome {
             task_index,
+            merge_target: merged_bookmark.as_ref().and(merge_target),
             merged_bookmark,
+            note_scrap_id,
         })
     }
 }
 
+/// Persists the done status, and the closing note if any, in one transaction once
+/// the workspace (if any) has been merged away.
+///
+/// The merge cannot be undone, so a failure here is reported as
+/// [`TrackError::TodoCompletionDbFailed`] naming the merged bookmark. Re-running
+/// `todo done` is safe: with the worktree gone only the status update is retried.
+fn mark_done_after_merge(
+    db: &Database,
+    todo: &Todo,
+    note: Option<&str>,
+    merged_bookmark: Option<&str>,
+) -> Result<Option<i64>> {
+    db.with_transaction(|| {
+        TodoService::new(db).mark_done(todo.id)?;
+        note.map(|note| {
+            ScrapService::new(db)
+                .add_scrap_for_todo_in_tx(todo.task_id, note, todo.task_index)
+                .map(|scrap| scrap.scrap_id)
+        })
+        .transpose()
+    })
+    .map_err(|err| match merged_bookmark {
+        Some(bookmark) => TrackError::TodoCompletionDbFailed {
+            todo_index: todo.task_index,
+            bookmark: bookmark.to_string(),
+            detail: err.to_string(),
+        },
+        None => err,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,6 +149,123 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn status_update_failure_after_merge_names_bookmark_and_can_be_retried() {
+        let db = setup_db();
+        let task_id = TaskService::new(&db)
+            .create_task("Task", None, None, None)
+            .unwrap()
+            .id;
+        let todo_service = TodoService::new(&db);
+        let todo = todo_service.add_todo(task_id, "Item", false).unwrap();
+        db.get_connection()
+            .execute_batch(
+                "CREATE TRIGGER fail_done BEFORE UPDATE OF status ON todos
+                 BEGIN SELECT RAISE(ABORT, 'disk I/O error'); END;",
+            )
+            .unwrap();
+
+        let result = mark_done_after_merge(&db, &todo, None, Some("t-1-todo-1"));
+        match result {
+            Err(TrackError::TodoCompletionDbFailed {
+                todo_index,
+                bookmark,
+                detail,
+            }) => {
+                assert_eq!(todo_index, todo.task_index);
+                assert_eq!(bookmark, "t-1-todo-1");
+                assert!(detail.contains("disk I/O error"));
+            }
+            other => panic!("expected TodoCompletionDbFailed, got {other:?}"),
+        }
+        assert_eq!(
+            todo_service.get_todo(todo.id).unwrap().status,
+            TodoStatus::Pending
+        );
+
+        // With the workspace already merged away, re-running only records the status.
+        db.get_connection()
+            .execute_batch("DROP TRIGGER fail_done;")
+            .unwrap();
+        let outcome = CompleteTodoUseCase::new(&db)
+            .execute(task_id, todo.task_index)
+            .unwrap();
+        assert_eq!(outcome.merged_bookmark, None);
+        assert_eq!(outcome.merge_target, None);
+        assert_eq!(
+            todo_service.get_todo(todo.id).unwrap().status,
+            TodoStatus::Done
+        );
+    }
+
+    #[test]
+    fn complete_todo_records_note_attached_to_the_todo() {
+        let db = setup_db();
+        let task_id = TaskService::new(&db)
+            .create_task("Task", None, None, None)
+            .unwrap()
+            .id;
+        let todo_service = TodoService::new(&db);
+        todo_service.add_todo(task_id, "First", false).unwrap();
+        let second = todo_service.add_todo(task_id, "Second", false).unwrap();
+        let use_case = CompleteTodoUseCase::new(&db);
+
+        assert!(matches!(
+            use_case.execute_with_options(
+                task_id,
+                second.task_index,
+                CompleteTodoOptions {
+                    note: Some("  "),
+                    ..Default::default()
+                }
+            ),
+            Err(TrackError::EmptyScrapContent)
+        ));
+        assert_eq!(
+            todo_service.get_todo(second.id).unwrap().status,
+            TodoStatus::Pending
+        );
+
+        let outcome = use_case
+            .execute_with_options(
+                task_id,
+                second.task_index,
+                CompleteTodoOptions {
+                    note: Some("Went with plan B"),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let scraps = ScrapService::new(&db).list_scraps(task_id).unwrap();
+        assert_eq!(outcome.note_scrap_id, Some(scraps[0].scrap_id));
+        assert_eq!(scraps[0].content, "Went with plan B");
+        assert_eq!(scraps[0].active_todo_id, Some(second.task_index));
+    }
+
+    #[test]
+    fn note_failure_rolls_back_the_done_status() {
+        let db = setup_db();
+        let task_id = TaskService::new(&db)
+            .create_task("Task", None, None, None)
+            .unwrap()
+            .id;
+        let todo_service = TodoService::new(&db);
+        let todo = todo_service.add_todo(task_id, "Item", false).unwrap();
+        db.get_connection()
+            .execute_batch(
+                "CREATE TRIGGER fail_scrap BEFORE INSERT ON scraps
+                 BEGIN SELECT RAISE(ABORT, 'disk I/O error'); END;",
+            )
+            .unwrap();
+
+        let result = mark_done_after_merge(&db, &todo, Some("Closing note"), None);
+        assert!(result.is_err());
+        assert_eq!(
+            todo_service.get_todo(todo.id).unwrap().status,
+            TodoStatus::Pending
+        );
+    }
+
     #[test]
     fn complete_todo_surfaces_db_failure_after_merge_with_typed_error() {
         let db = setup_db();
@@ -23,9 +23,10 @@ impl<'a> CreateTodayTaskUseCase<'a> {
         let task_name = format!("Today: {}", today);
 
         let conn = self.db.get_connection();
+        // Paused or blocked still counts as today's task; only archiving retires it
         let active_query = format!(
-            "SELECT id FROM tasks WHERE is_today_task = 1 AND name = ?1 AND status = '{}'",
-            TaskStatus::ACTIVE
+            "SELECT id FROM tasks WHERE is_today_task = 1 AND name = ?1 AND status != '{}'",
+            TaskStatus::ARCHIVED
         );
         let mut stmt = conn.prepare(&active_query)?;
 
@@ -82,8 +83,8 @@ impl<'a> CreateTodayTaskUseCase<'a> {
     fn find_today_task_to_inherit_from(&self) -> Result<Option<i64>> {
         let conn = self.db.get_connection();
         let active_query = format!(
-            "SELECT id FROM tasks WHERE is_today_task = 1 AND status = '{}' ORDER BY created_at DESC LIMIT 1",
-            TaskStatus::ACTIVE
+            "SELECT id FROM tasks WHERE is_today_task = 1 AND status != '{}' ORDER BY created_at DESC LIMIT 1",
+            TaskStatus::ARCHIVED
         );
         let mut stmt = conn.prepare(&active_query)?;
 
@@ -134,4 +135,19 @@ mod tests {
         assert_eq!(inherited_scraps.len(), 1);
         assert_eq!(inherited_scraps[0].content, "linked note");
     }
+
+    #[test]
+    fn get_or_create_reuses_paused_today_task() {
+        let db = Database::new_in_memory().unwrap();
+        let use_case = CreateTodayTaskUseCase::new(&db);
+
+        let today_task = use_case.get_or_create().unwrap();
+        TaskService::new(&db)
+            .set_status(today_task.id, TaskStatus::Paused)
+            .unwrap();
+
+        let again = use_case.get_or_create().unwrap();
+        assert_eq!(again.id, today_task.id);
+        assert_eq!(again.status, TaskStatus::Paused);
+    }
 }
@@ -0,0 +1,279 @@
+use crate::db::Database;
+use crate::models::VcsMode;
+use crate::utils::{CommandExt, Result};
+use rusqlite::params;
+use std::path::Path;
+use std::process::Command;
+
+/// Outcome of a single doctor check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Pass => "PASS",
+            Self::Warn => "WARN",
+            Self::Fail => "FAIL",
+        }
+    }
+}
+
+/// One line of the `track doctor` checklist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoctorCheck {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+    pub hint: Option<String>,
+}
+
+impl DoctorCheck {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn problem(
+        name: &'static str,
+        status: CheckStatus,
+        detail: impl Into<String>,
+        hint: impl Into<String>,
+    ) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoctorReport {
+    pub checks: Vec<DoctorCheck>,
+}
+
+impl DoctorReport {
+    pub fn failures(&self) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| check.status == CheckStatus::Fail)
+            .count()
+    }
+}
+
+/// Diagnoses environment and database problems for `track doctor`.
+pub struct DoctorUseCase<'a> {
+    db: &'a Database,
+}
+
+impl<'a> DoctorUseCase<'a> {
+    pub fn new(db: &'a Database) -> Self {
+        Self { db }
+    }
+
+    pub fn run(&self) -> Result<DoctorReport> {
+        let vcs_mode = self.db.get_vcs_mode()?;
+
+        let checks = vec![
+            Self::check_tool("git", vcs_mode == VcsMode::Git),
+            Self::check_tool("jj", vcs_mode == VcsMode::Jj),
+            self.check_db_writable(),
+            self.check_integrity()?,
+            self.check_orphaned_worktrees()?,
+            self.check_duplicate_tickets()?,
+        ];
+
+        Ok(DoctorReport { checks })
+    }
+
+    fn check_tool(program: &'static str, required: bool) -> DoctorCheck {
+        let output = Command::new(program).arg("--version").logged_output();
+        match output {
+            Ok(output) if output.status.success() => DoctorCheck::pass(
+                program,
+                String::from_utf8_lossy(&output.stdout).trim().to_string(),
+            ),
+            _ => {
+                let (status, hint) = if required {
+                    (
+                        CheckStatus::Fail,
+                        format!("Install {program} (required by the current vcs-mode)"),
+                    )
+                } else {
+                    (
+                        CheckStatus::Warn,
+                        format!("Install {program} if you plan to switch vcs-mode"),
+                    )
+                };
+                DoctorCheck::problem(program, status, "not found on PATH", hint)
+            }
+        }
+    }
+
+    fn check_db_writable(&self) -> DoctorCheck {
+        let Some(path) = self.db.path() else {
+            return DoctorCheck::pass("database path", "in-memory");
+        };
+
+        match std::fs::OpenOptions::new().append(true).open(&path) {
+            Ok(_) => DoctorCheck::pass("database path", path.display().to_string()),
+            Err(err) => DoctorCheck::problem(
+                "database path",
+                CheckStatus::Fail,
+                format!("{} is not writable: {err}", path.display()),
+                "Check file permissions on the track data directory",
+            ),
+        }
+    }
+
+    fn check_integrity(&self) -> Result<DoctorCheck> {
+        let result: String =
+            self.db
+                .get_connection()
+                .query_row("PRAGMA integrity_check", [], |row| row.get(0))?;
+
+        Ok(if result == "ok" {
+            DoctorCheck::pass("database integrity", "ok")
+        } else {
+            DoctorCheck::problem(
+                "database integrity",
+                CheckStatus::Fail,
+                result,
+                "Restore track.db from a backup; the file is corrupt",
+            )
+        })
+    }
+
+    fn check_orphaned_worktrees(&self) -> Result<DoctorCheck> {
+        let conn = self.db.get_connection();
+        let mut stmt = conn.prepare(
+            "SELECT w.path, t.id IS NULL FROM worktrees w LEFT JOIN tasks t ON t.id = w.task_id WHERE w.status = 'active'",
+        )?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, bool>(1)?))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let orphaned: Vec<String> = rows
+            .into_iter()
+            .filter(|(path, missing_task)| *missing_task || !Path::new(path).exists())
+            .map(|(path, _)| path)
+            .collect();
+
+        Ok(if orphaned.is_empty() {
+            DoctorCheck::pass("worktree records", "no orphaned rows")
+        } else {
+            DoctorCheck::problem(
+                "worktree records",
+                CheckStatus::Warn,
+                format!(
+                    "{} orphaned row(s): {}",
+                    orphaned.len(),
+                    orphaned.join(", ")
+                ),
+                "Run `track migrate legacy-worktrees` to clean up stale worktree records",
+            )
+        })
+    }
+
+    /// Ticket IDs are UNIQUE in the schema, so only case variants can collide.
+    fn check_duplicate_tickets(&self) -> Result<DoctorCheck> {
+        let conn = self.db.get_connection();
+        let mut stmt = conn.prepare(
+            "SELECT UPPER(ticket_id), COUNT(*) FROM tasks WHERE ticket_id IS NOT NULL GROUP BY UPPER(ticket_id) HAVING COUNT(*) > 1 ORDER BY 1",
+        )?;
+        let duplicates = stmt
+            .query_map(params![], |row| {
+                Ok(format!(
+                    "{} ({} tasks)",
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i64>(1)?
+                ))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(if duplicates.is_empty() {
+            DoctorCheck::pass("ticket uniqueness", "no duplicate tickets")
+        } else {
+            DoctorCheck::problem(
+                "ticket uniqueness",
+                CheckStatus::Warn,
+                duplicates.join(", "),
+                "Re-link one of the tasks with `track ticket <id> <url> --task <task_id>`",
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::TaskService;
+
+    fn find<'r>(report: &'r DoctorReport, name: &str) -> &'r DoctorCheck {
+        report
+            .checks
+            .iter()
+            .find(|check| check.name == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn healthy_database_passes_db_checks() {
+        let db = Database::new_in_memory().unwrap();
+        TaskService::new(&db)
+            .create_task("Task", None, Some("PROJ-1"), None)
+            .unwrap();
+
+        let report = DoctorUseCase::new(&db).run().unwrap();
+        for name in [
+            "database path",
+            "database integrity",
+            "worktree records",
+            "ticket uniqueness",
+        ] {
+            assert_eq!(find(&report, name).status, CheckStatus::Pass, "{name}");
+        }
+    }
+
+    #[test]
+    fn reports_orphaned_worktrees_and_duplicate_tickets() {
+        let db = Database::new_in_memory().unwrap();
+        let task = TaskService::new(&db)
+            .create_task("Task", None, Some("PROJ-1"), None)
+            .unwrap();
+
+        let conn = db.get_connection();
+        conn.execute(
+            "INSERT INTO tasks (name, status, ticket_id, created_at) VALUES ('Dup', 'active', 'proj-1', ?1)",
+            params![chrono::Utc::now().to_rfc3339()],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO worktrees (task_id, path, branch, status, created_at, is_base) VALUES (?1, '/missing/wt', 'b', 'active', ?2, 0)",
+            params![task.id, chrono::Utc::now().to_rfc3339()],
+        )
+        .unwrap();
+
+        let report = DoctorUseCase::new(&db).run().unwrap();
+
+        let worktrees = find(&report, "worktree records");
+        assert_eq!(worktrees.status, CheckStatus::Warn);
+        assert!(worktrees.detail.contains("/missing/wt"));
+
+        let tickets = find(&report, "ticket uniqueness");
+        assert_eq!(tickets.status, CheckStatus::Warn);
+        assert!(tickets.detail.contains("PROJ-1 (2 tasks)"));
+    }
+}
@@ -4,6 +4,10 @@ use crate::services::agent_context::build_agent_extensions;
 use crate::services::{
     LinkService, RepoService, ScrapService, TaskService, TodoService, WorktreeService,
 };
+use crate::use_cases::status_document::{
+    StatusDocument, StatusLink, StatusRepo, StatusScrap, StatusTask, StatusTodo, StatusWorktree,
+    STATUS_SCHEMA_VERSION,
+};
 use crate::utils::{Result, TrackError};
 
 /// Aggregated task detail for CLI status and agent tooling.
@@ -14,6 +18,8 @@ pub struct TaskInfoSnapshot {
     pub links: Vec<Link>,
     pub scraps: Vec<Scrap>,
     pub worktrees: Vec<Worktree>,
+    /// Merged and removed worktrees, oldest first
+    pub worktree_history: Vec<Worktree>,
     pub repos: Vec<TaskRepo>,
     pub vcs_mode: VcsMode,
 }
@@ -43,6 +49,7 @@ impl<'a> GetTaskInfoUseCase<'a> {
 
         let worktree_service = WorktreeService::new(self.db);
         let worktrees = worktree_service.list_worktrees(task_id)?;
+        let worktree_history = worktree_service.list_worktree_history(task_id)?;
 
         let repo_service = RepoService::new(self.db);
         let repos = repo_service.list_repos(task_id)?;
@@ -54,6 +61,7 @@ impl<'a> GetTaskInfoUseCase<'a> {
             links,
             scraps,
             worktrees,
+            worktree_history,
             repos,
             vcs_mode,
         })
@@ -86,6 +94,7 @@ impl<'a> GetTaskInfoUseCase<'a> {
 
         WorktreeService::new(self.db)
             .get_todo_branch_name(
+                None,
                 snapshot.task.id,
                 snapshot.task.ticket_id.as_deref(),
                 todo.task_index,
@@ -101,48 +110,38 @@ impl<'a> GetTaskInfoUseCase<'a> {
             .collect()
     }
 
-    pub fn to_cli_json(&self, snapshot: &TaskInfoSnapshot) -> Result<serde_json::Value> {
-        let worktree_service = WorktreeService::new(self.db);
-
-        let mut todos_json = Vec::with_capacity(snapshot.todos.len());
-        for todo in &snapshot.todos {
-            let mut todo_val = serde_json::to_value(todo)
-                .map_err(|e| TrackError::SerializationFailed(e.to_string()))?;
-            if let Some(obj) = todo_val.as_object_mut() {
-                let worktree_branch = self.todo_worktree_branch(snapshot, todo);
-                obj.insert(
-                    "worktree_branch".to_string(),
-                    serde_json::to_value(worktree_branch)
-                        .map_err(|e| TrackError::SerializationFailed(e.to_string()))?,
-                );
-            }
-            todos_json.push(todo_val);
-        }
-
-        let mut worktrees_json = Vec::with_capacity(snapshot.worktrees.len());
-        for worktree in &snapshot.worktrees {
-            let mut wt_val = serde_json::to_value(worktree)
-                .map_err(|e| TrackError::SerializationFailed(e.to_string()))?;
-            if let Some(obj) = wt_val.as_object_mut() {
-                obj.remove("id");
-                obj.remove("is_base");
-                obj.remove("task_id");
-
-                let task_scoped_id = worktree.todo_id.and_then(|id| {
-                    snapshot
-                        .todos
-                        .iter()
-                        .find(|todo| todo.id == id)
-                        .map(|todo| todo.task_index)
-                });
-                obj.insert(
-                    "todo_id".to_string(),
-                    serde_json::to_value(task_scoped_id)
-                        .map_err(|e| TrackError::SerializationFailed(e.to_string()))?,
-                );
-            }
-            worktrees_json.push(wt_val);
-        }
+    /// Builds the versioned document printed by `track status --json`.
+    pub fn status_document(&self, snapshot: &TaskInfoSnapshot) -> StatusDocument {
+        let todos = snapshot
+            .todos
+            .iter()
+            .map(|todo| StatusTodo {
+                todo_id: todo.task_index,
+                content: todo.content.clone(),
+                status: todo.status,
+                completed_at: todo.completed_at,
+                estimate: todo.estimate,
+                due_date: todo.due_date,
+                worktree_branch: self.todo_worktree_branch(snapshot, todo),
+            })
+            .collect();
+
+        let status_worktree = |worktree: &Worktree| StatusWorktree {
+            path: Some(worktree.path.clone()),
+            branch: Some(worktree.branch.clone()),
+            base_repo: worktree.base_repo.clone(),
+            status: worktree.status.as_str().to_string(),
+            created_at: Some(worktree.created_at),
+            todo_id: worktree.todo_id.and_then(|id| {
+                snapshot
+                    .todos
+                    .iter()
+                    .find(|todo| todo.id == id)
+                    .map(|todo| todo.task_index)
+            }),
+        };
+        let mut worktrees: Vec<StatusWorktree> =
+            snapshot.worktrees.iter().map(status_worktree).collect();
 
         for todo in &snapshot.todos {
             if todo.worktree_requested
@@ -151,27 +150,18 @@ impl<'a> GetTaskInfoUseCase<'a> {
                     .iter()
                     .any(|wt| wt.todo_id == Some(todo.id))
             {
-                let branch_name = self.todo_worktree_branch(snapshot, todo);
-                worktrees_json.push(serde_json::json!({
-                    "todo_id": todo.task_index,
-                    "branch": branch_name,
-                    "status": "requested",
-                    "path": null,
-                    "created_at": null,
-                    "base_repo": null
-                }));
+                worktrees.push(StatusWorktree {
+                    path: None,
+                    branch: self.todo_worktree_branch(snapshot, todo),
+                    base_repo: None,
+                    status: "requested".to_string(),
+                    created_at: None,
+                    todo_id: Some(todo.task_index),
+                });
             }
         }
 
-        let mut output = serde_json::json!({
-            "task": snapshot.task,
-            "todos": todos_json,
-            "links": snapshot.links,
-            "scraps": snapshot.scraps,
-            "worktrees": worktrees_json,
-            "repos": snapshot.repos,
-        });
-
+        let worktree_service = WorktreeService::new(self.db);
         let agent = build_agent_extensions(
             snapshot.vcs_mode,
             &snapshot.task,
@@ -180,17 +170,28 @@ impl<'a> GetTaskInfoUseCase<'a> {
             &snapshot.repos,
             &worktree_service,
         );
-        let agent_val = serde_json::to_value(&agent)
-            .map_err(|e| TrackError::SerializationFailed(e.to_string()))?;
-        if let Some(obj) = output.as_object_mut() {
-            if let Some(agent_obj) = agent_val.as_object() {
-                for (key, value) in agent_obj {
-                    obj.insert(key.clone(), value.clone());
-                }
-            }
+
+        StatusDocument {
+            schema_version: STATUS_SCHEMA_VERSION,
+            task: Some(StatusTask::from(&snapshot.task)),
+            todos,
+            links: snapshot.links.iter().map(StatusLink::from).collect(),
+            scraps: snapshot.scraps.iter().map(StatusScrap::from).collect(),
+            worktrees,
+            worktree_history: snapshot
+                .worktree_history
+                .iter()
+                .map(status_worktree)
+                .collect(),
+            repos: snapshot.repos.iter().map(StatusRepo::from).collect(),
+            agent: Some(agent),
         }
+    }
 
-        Ok(output)
+    /// [`Self::status_document`] as a JSON value.
+    pub fn to_cli_json(&self, snapshot: &TaskInfoSnapshot) -> Result<serde_json::Value> {
+        serde_json::to_value(self.status_document(snapshot))
+            .map_err(|e| TrackError::SerializationFailed(e.to_string()))
     }
 }
 
@@ -224,7 +225,11 @@ mod tests {
         let snapshot = use_case.load(task.id).unwrap();
         let json = use_case.to_cli_json(&snapshot).unwrap();
 
+        assert_eq!(json["schema_version"], STATUS_SCHEMA_VERSION);
         assert_eq!(json["task"]["name"], "Task");
         assert_eq!(json["todos"].as_array().map(|t| t.len()), Some(1));
+        assert_eq!(json["todos"][0]["todo_id"], 1);
+        assert!(json["todos"][0].get("id").is_none());
+        assert_eq!(json["workflow"]["phase"], "setup");
     }
 }
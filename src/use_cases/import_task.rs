@@ -0,0 +1,285 @@
+use crate::db::Database;
+use crate::models::{Task, TodoStatus};
+use crate::services::TaskService;
+use crate::utils::{Result, TrackError};
+use chrono::{DateTime, NaiveDate, Utc};
+use rusqlite::params;
+use serde::Deserialize;
+use std::str::FromStr;
+
+/// Task bundle as produced by `track status --json`.
+///
+/// Unknown sections (worktrees, repos, workflow, ...) are ignored.
+#[derive(Debug, Deserialize)]
+pub struct TaskBundle {
+    pub task: BundleTask,
+    #[serde(default)]
+    pub todos: Vec<BundleTodo>,
+    #[serde(default)]
+    pub links: Vec<BundleLink>,
+    #[serde(default)]
+    pub scraps: Vec<BundleScrap>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BundleTask {
+    pub name: String,
+    pub description: Option<String>,
+    pub ticket_id: Option<String>,
+    pub ticket_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BundleTodo {
+    pub todo_id: Option<i64>,
+    pub content: String,
+    pub status: String,
+    pub completed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub estimate: Option<f64>,
+    #[serde(default)]
+    pub due_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BundleLink {
+    pub link_id: Option<i64>,
+    pub url: String,
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BundleScrap {
+    pub scrap_id: Option<i64>,
+    pub content: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub active_todo_id: Option<i64>,
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+impl TaskBundle {
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|err| TrackError::InvalidImport(err.to_string()))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportTaskOutcome {
+    pub task: Task,
+    pub todos: usize,
+    pub links: usize,
+    pub scraps: usize,
+    /// Ticket ID dropped because another task already uses it.
+    pub skipped_ticket: Option<String>,
+}
+
+/// Recreates a task with its TODOs, links, and scraps from an exported bundle.
+pub struct ImportTaskUseCase<'a> {
+    db: &'a Database,
+}
+
+impl<'a> ImportTaskUseCase<'a> {
+    pub fn new(db: &'a Database) -> Self {
+        Self { db }
+    }
+
+    /// Imports the bundle as a new task (new id) and switches to it.
+    ///
+    /// Worktrees are skipped because their paths are machine-specific. When
+    /// `skip_conflicting_ticket` is set, a ticket already owned by another
+    /// task is dropped instead of failing the import.
+    pub fn execute(
+        &self,
+        bundle: &TaskBundle,
+        skip_conflicting_ticket: bool,
+    ) -> Result<ImportTaskOutcome> {
+        let statuses = bundle
+            .todos
+            .iter()
+            .map(|todo| {
+                TodoStatus::from_str(&todo.status).map_err(|_| {
+                    TrackError::InvalidImport(format!("unknown TODO status '{}'", todo.status))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.db.with_transaction(|| {
+            let task_service = TaskService::new(self.db);
+            let conn = self.db.get_connection();
+
+            let mut ticket_id = bundle.task.ticket_id.as_deref();
+            let mut skipped_ticket = None;
+            if let Some(ticket) = ticket_id {
+                if skip_conflicting_ticket && task_service.find_task_by_ticket(ticket)?.is_some() {
+                    skipped_ticket = Some(ticket.to_string());
+                    ticket_id = None;
+                }
+            }
+
+            let task = task_service.create_task(
+                &bundle.task.name,
+                bundle.task.description.as_deref(),
+                ticket_id,
+                ticket_id.and(bundle.task.ticket_url.as_deref()),
+            )?;
+
+            let now = Utc::now().to_rfc3339();
+            for (position, (todo, status)) in bundle.todos.iter().zip(&statuses).enumerate() {
+                let task_index = todo.todo_id.unwrap_or(position as i64 + 1);
+                conn.execute(
+                    "INSERT INTO todos (task_id, task_index, content, status, created_at, completed_at, estimate, due_date) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                    params![
+                        task.id,
+                        task_index,
+                        todo.content,
+                        status.as_str(),
+                        now,
+                        todo.completed_at.map(|at| at.to_rfc3339()),
+                        todo.estimate,
+                        todo.due_date.map(|due| due.to_string())
+                    ],
+                )?;
+            }
+
+            for (position, link) in bundle.links.iter().enumerate() {
+                conn.execute(
+                    "INSERT INTO links (task_id, task_index, url, title, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![
+                        task.id,
+                        link.link_id.unwrap_or(position as i64 + 1),
+                        link.url,
+                        link.title.as_deref().unwrap_or(&link.url),
+                        now
+                    ],
+                )?;
+            }
+
+            for (position, scrap) in bundle.scraps.iter().enumerate() {
+                let created_at = scrap
+                    .created_at
+                    .map(|at| at.to_rfc3339())
+                    .unwrap_or_else(|| now.clone());
+                conn.execute(
+                    "INSERT INTO scraps (task_id, task_index, content, created_at, active_todo_id, pinned) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    params![
+                        task.id,
+                        scrap.scrap_id.unwrap_or(position as i64 + 1),
+                        scrap.content,
+                        created_at,
+                        scrap.active_todo_id,
+                        scrap.pinned
+                    ],
+                )?;
+            }
+
+            for section in ["task", "todos", "links", "scraps"] {
+                self.db.increment_rev(section)?;
+            }
+
+            Ok(ImportTaskOutcome {
+                task,
+                todos: bundle.todos.len(),
+                links: bundle.links.len(),
+                scraps: bundle.scraps.len(),
+                skipped_ticket,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::{LinkService, ScrapService, TodoService};
+    use crate::use_cases::GetTaskInfoUseCase;
+
+    fn export(db: &Database, task_id: i64) -> String {
+        let info = GetTaskInfoUseCase::new(db);
+        let snapshot = info.load(task_id).unwrap();
+        serde_json::to_string(&info.to_cli_json(&snapshot).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn round_trips_status_json() {
+        let source = Database::new_in_memory().unwrap();
+        let task = TaskService::new(&source)
+            .create_task("Port", Some("Desc"), Some("PROJ-9"), Some("https://t/9"))
+            .unwrap();
+        let todo_service = TodoService::new(&source);
+        let done = todo_service.add_todo(task.id, "First", false).unwrap();
+        todo_service.add_todo(task.id, "Second", false).unwrap();
+        todo_service.mark_done(done.id).unwrap();
+        LinkService::new(&source)
+            .add_link(task.id, "https://example.com", Some("Example"))
+            .unwrap();
+        ScrapService::new(&source)
+            .add_scrap(task.id, "note")
+            .unwrap();
+
+        let json = export(&source, task.id);
+
+        let target = Database::new_in_memory().unwrap();
+        let bundle = TaskBundle::from_json(&json).unwrap();
+        let outcome = ImportTaskUseCase::new(&target)
+            .execute(&bundle, false)
+            .unwrap();
+
+        assert_eq!(outcome.task.name, "Port");
+        assert_eq!(outcome.task.ticket_id.as_deref(), Some("PROJ-9"));
+        assert_eq!(target.get_current_task_id().unwrap(), Some(outcome.task.id));
+
+        let todos = TodoService::new(&target)
+            .list_todos(outcome.task.id)
+            .unwrap();
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].status, TodoStatus::Done);
+        assert!(todos[0].completed_at.is_some());
+        assert_eq!(todos[1].content, "Second");
+
+        let links = LinkService::new(&target)
+            .list_links(outcome.task.id)
+            .unwrap();
+        assert_eq!(links[0].title, "Example");
+
+        let scraps = ScrapService::new(&target)
+            .list_scraps(outcome.task.id)
+            .unwrap();
+        assert_eq!(scraps[0].content, "note");
+        assert_eq!(scraps[0].active_todo_id, Some(2));
+    }
+
+    #[test]
+    fn conflicting_ticket_fails_unless_skipped() {
+        let db = Database::new_in_memory().unwrap();
+        let task = TaskService::new(&db)
+            .create_task("Original", None, Some("PROJ-1"), None)
+            .unwrap();
+        let bundle = TaskBundle::from_json(&export(&db, task.id)).unwrap();
+
+        let result = ImportTaskUseCase::new(&db).execute(&bundle, false);
+        assert!(matches!(result, Err(TrackError::DuplicateTicket(_, _))));
+
+        let outcome = ImportTaskUseCase::new(&db).execute(&bundle, true).unwrap();
+        assert_eq!(outcome.task.ticket_id, None);
+        assert_eq!(outcome.skipped_ticket.as_deref(), Some("PROJ-1"));
+    }
+
+    #[test]
+    fn rejects_schema_mismatch() {
+        assert!(matches!(
+            TaskBundle::from_json(r#"{"todos": []}"#),
+            Err(TrackError::InvalidImport(_))
+        ));
+
+        let bundle = TaskBundle::from_json(
+            r#"{"task": {"name": "T"}, "todos": [{"content": "x", "status": "wip"}]}"#,
+        )
+        .unwrap();
+        let db = Database::new_in_memory().unwrap();
+        assert!(matches!(
+            ImportTaskUseCase::new(&db).execute(&bundle, false),
+            Err(TrackError::InvalidImport(_))
+        ));
+    }
+}
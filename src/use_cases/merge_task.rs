@@ -0,0 +1,259 @@
+use crate::db::Database;
+use crate::models::{Task, TaskStatus};
+use crate::services::TaskService;
+use crate::utils::{Result, TrackError};
+use rusqlite::params;
+
+/// What happens to the source task once its contents have moved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeSourceFate {
+    Archive,
+    Delete,
+}
+
+#[derive(Debug, Clone)]
+pub struct MergeTaskOutcome {
+    pub source: Task,
+    pub dest: Task,
+    pub todos: usize,
+    pub links: usize,
+    pub scraps: usize,
+    pub repos: usize,
+    /// TODO worktrees that moved with their TODOs (branches keep the source's naming)
+    pub worktrees: usize,
+    /// Repository paths the destination already had; the source's copies were dropped.
+    pub skipped_repos: Vec<String>,
+    /// Source ticket dropped because the destination already has one.
+    pub dropped_ticket: Option<String>,
+    /// Source worktrees not tied to a TODO, left on the archived source.
+    pub kept_worktrees: Vec<(i64, String)>,
+}
+
+/// Folds one task into another: TODOs, links, scraps and repos move over in a
+/// single transaction, then the source is archived or deleted.
+pub struct MergeTaskUseCase<'a> {
+    db: &'a Database,
+}
+
+impl<'a> MergeTaskUseCase<'a> {
+    pub fn new(db: &'a Database) -> Self {
+        Self { db }
+    }
+
+    /// Moves everything from `source_id` into `dest_id`.
+    ///
+    /// Moved items are renumbered after the destination's highest index, keeping
+    /// their order; scraps' active TODO references shift with them. When both
+    /// tasks have tickets the destination's wins. Deleting the source is refused
+    /// while it still has worktrees that are not tied to a TODO.
+    pub fn execute(
+        &self,
+        source_id: i64,
+        dest_id: i64,
+        fate: MergeSourceFate,
+    ) -> Result<MergeTaskOutcome> {
+        if source_id == dest_id {
+            return Err(TrackError::TaskMergeIntoSelf(source_id));
+        }
+
+        self.db.with_transaction(|| {
+            let task_service = TaskService::new(self.db);
+            let source = task_service.get_task(source_id)?;
+            let dest = task_service.get_task(dest_id)?;
+            if dest.status == TaskStatus::Archived {
+                return Err(TrackError::TaskArchived(dest_id));
+            }
+
+            let conn = self.db.get_connection();
+            let kept_worktrees = conn
+                .prepare("SELECT id, path FROM worktrees WHERE task_id = ?1 AND todo_id IS NULL AND status = 'active' ORDER BY id")?
+                .query_map(params![source_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+                .collect::<std::result::Result<Vec<(i64, String)>, _>>()?;
+            if fate == MergeSourceFate::Delete && !kept_worktrees.is_empty() {
+                return Err(TrackError::TaskHasWorktrees {
+                    task_id: source_id,
+                    count: kept_worktrees.len(),
+                });
+            }
+
+            let max_index = |table: &str| -> Result<i64> {
+                Ok(conn.query_row(
+                    &format!("SELECT COALESCE(MAX(task_index), 0) FROM {table} WHERE task_id = ?1"),
+                    params![dest_id],
+                    |row| row.get(0),
+                )?)
+            };
+            let todo_offset = max_index("todos")?;
+            let link_offset = max_index("links")?;
+            let scrap_offset = max_index("scraps")?;
+            let repo_offset = max_index("task_repos")?;
+
+            let worktrees = conn.execute(
+                "UPDATE worktrees SET task_id = ?1 WHERE task_id = ?2 AND todo_id IS NOT NULL",
+                params![dest_id, source_id],
+            )?;
+            let todos = conn.execute(
+                "UPDATE todos SET task_id = ?1, task_index = task_index + ?2 WHERE task_id = ?3",
+                params![dest_id, todo_offset, source_id],
+            )?;
+            let links = conn.execute(
+                "UPDATE links SET task_id = ?1, task_index = task_index + ?2 WHERE task_id = ?3",
+                params![dest_id, link_offset, source_id],
+            )?;
+            let scraps = conn.execute(
+                "UPDATE scraps SET task_id = ?1, task_index = task_index + ?2, active_todo_id = active_todo_id + ?3 WHERE task_id = ?4",
+                params![dest_id, scrap_offset, todo_offset, source_id],
+            )?;
+
+            let skipped_repos = conn
+                .prepare("SELECT repo_path FROM task_repos WHERE task_id = ?1 AND repo_path IN (SELECT repo_path FROM task_repos WHERE task_id = ?2) ORDER BY task_index")?
+                .query_map(params![source_id, dest_id], |row| row.get(0))?
+                .collect::<std::result::Result<Vec<String>, _>>()?;
+            conn.execute(
+                "DELETE FROM task_repos WHERE task_id = ?1 AND repo_path IN (SELECT repo_path FROM task_repos WHERE task_id = ?2)",
+                params![source_id, dest_id],
+            )?;
+            let repos = conn.execute(
+                "UPDATE task_repos SET task_id = ?1, task_index = task_index + ?2 WHERE task_id = ?3",
+                params![dest_id, repo_offset, source_id],
+            )?;
+
+            let mut dropped_ticket = None;
+            if let Some(ticket_id) = &source.ticket_id {
+                conn.execute(
+                    "UPDATE tasks SET ticket_id = NULL, ticket_url = NULL WHERE id = ?1",
+                    params![source_id],
+                )?;
+                if dest.ticket_id.is_some() {
+                    dropped_ticket = Some(ticket_id.clone());
+                } else {
+                    conn.execute(
+                        "UPDATE tasks SET ticket_id = ?1, ticket_url = ?2 WHERE id = ?3",
+                        params![ticket_id, source.ticket_url, dest_id],
+                    )?;
+                }
+            }
+
+            let was_current = self.db.get_current_task_id()? == Some(source_id);
+            match fate {
+                MergeSourceFate::Archive if source.status != TaskStatus::Archived => {
+                    task_service.archive_task(source_id)?;
+                }
+                MergeSourceFate::Archive => {}
+                MergeSourceFate::Delete => {
+                    conn.execute("DELETE FROM tasks WHERE id = ?1", params![source_id])?;
+                }
+            }
+            if was_current {
+                self.db.set_current_task_id(dest_id)?;
+            }
+
+            self.db.touch_task(dest_id)?;
+            for section in ["task", "todos", "links", "scraps", "repos", "worktrees"] {
+                self.db.increment_rev(section)?;
+            }
+
+            Ok(MergeTaskOutcome {
+                source,
+                dest: task_service.get_task(dest_id)?,
+                todos,
+                links,
+                scraps,
+                repos,
+                worktrees,
+                skipped_repos,
+                dropped_ticket,
+                kept_worktrees,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::{LinkService, ScrapService, TodoService};
+
+    fn add_repo(db: &Database, task_id: i64, index: i64, path: &str) {
+        db.get_connection()
+            .execute(
+                "INSERT INTO task_repos (task_id, task_index, repo_path, created_at) VALUES (?1, ?2, ?3, '2026-01-01T00:00:00+00:00')",
+                params![task_id, index, path],
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn merge_renumbers_after_destination_and_archives_source() {
+        let db = Database::new_in_memory().unwrap();
+        let task_service = TaskService::new(&db);
+        let todo_service = TodoService::new(&db);
+        let dest = task_service
+            .create_task("Dest", None, Some("PROJ-1"), None)
+            .unwrap();
+        todo_service.add_todo(dest.id, "Dest todo", false).unwrap();
+        add_repo(&db, dest.id, 1, "/repo/shared");
+        let source = task_service
+            .create_task("Source", None, Some("PROJ-2"), None)
+            .unwrap();
+        todo_service.add_todo(source.id, "First", false).unwrap();
+        todo_service.add_todo(source.id, "Second", false).unwrap();
+        ScrapService::new(&db).add_scrap(source.id, "note").unwrap();
+        LinkService::new(&db)
+            .add_link(source.id, "https://example.com/a", None)
+            .unwrap();
+        add_repo(&db, source.id, 1, "/repo/shared");
+        add_repo(&db, source.id, 2, "/repo/other");
+
+        let outcome = MergeTaskUseCase::new(&db)
+            .execute(source.id, dest.id, MergeSourceFate::Archive)
+            .unwrap();
+        assert_eq!(
+            (outcome.todos, outcome.links, outcome.scraps, outcome.repos),
+            (2, 1, 1, 1)
+        );
+        assert_eq!(outcome.skipped_repos, vec!["/repo/shared".to_string()]);
+        assert_eq!(outcome.dropped_ticket.as_deref(), Some("PROJ-2"));
+        assert_eq!(outcome.dest.ticket_id.as_deref(), Some("PROJ-1"));
+
+        let todos = todo_service.list_todos(dest.id).unwrap();
+        let indexed: Vec<(i64, &str)> = todos
+            .iter()
+            .map(|todo| (todo.task_index, todo.content.as_str()))
+            .collect();
+        assert_eq!(indexed, vec![(1, "Dest todo"), (2, "First"), (3, "Second")]);
+        let scraps = ScrapService::new(&db).list_scraps(dest.id).unwrap();
+        assert_eq!(scraps[0].active_todo_id, Some(2));
+        assert_eq!(
+            task_service.get_task(source.id).unwrap().status,
+            TaskStatus::Archived
+        );
+        assert_eq!(db.get_current_task_id().unwrap(), Some(dest.id));
+    }
+
+    #[test]
+    fn merge_moves_ticket_and_deletes_source() {
+        let db = Database::new_in_memory().unwrap();
+        let task_service = TaskService::new(&db);
+        let dest = task_service.create_task("Dest", None, None, None).unwrap();
+        let source = task_service
+            .create_task("Source", None, Some("PROJ-2"), None)
+            .unwrap();
+        let use_case = MergeTaskUseCase::new(&db);
+
+        assert!(matches!(
+            use_case.execute(dest.id, dest.id, MergeSourceFate::Delete),
+            Err(TrackError::TaskMergeIntoSelf(_))
+        ));
+
+        let outcome = use_case
+            .execute(source.id, dest.id, MergeSourceFate::Delete)
+            .unwrap();
+        assert_eq!(outcome.dropped_ticket, None);
+        assert_eq!(outcome.dest.ticket_id.as_deref(), Some("PROJ-2"));
+        assert!(matches!(
+            task_service.get_task(source.id),
+            Err(TrackError::TaskNotFound(_))
+        ));
+    }
+}
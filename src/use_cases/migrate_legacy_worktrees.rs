@@ -1,5 +1,5 @@
 use crate::db::Database;
-use crate::models::{jj_slug, Task, TaskStatus};
+use crate::models::{jj_slug, Task, TaskListFilter};
 use crate::services::{LegacyWorktreeCleanupOutcome, TaskService, TodoService, WorktreeService};
 use crate::utils::Result;
 
@@ -48,9 +48,9 @@ impl<'a> MigrateLegacyWorktreesUseCase<'a> {
         let tasks: Vec<Task> = match task_id {
             Some(id) => vec![task_service.get_task(id)?],
             None => task_service
-                .list_tasks(true)?
+                .list_tasks(TaskListFilter::All)?
                 .into_iter()
-                .filter(|task| task.status == TaskStatus::Active)
+                .filter(|task| task.status.is_open())
                 .collect(),
         };
 
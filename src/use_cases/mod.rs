@@ -8,8 +8,12 @@ pub mod archive_task;
 pub mod complete_todo;
 pub mod create_today_task;
 pub mod delete_todo;
+pub mod doctor;
 pub mod get_task_info;
+pub mod import_task;
+pub mod merge_task;
 pub mod migrate_legacy_worktrees;
+pub mod status_document;
 pub mod sync_task;
 pub mod todo_workspace;
 
@@ -18,17 +22,22 @@ pub use archive_task::{
     ArchiveBlockers, ArchiveCompletionView, ArchivePrompt, ArchivePromptKind, ArchivePromptView,
     ArchiveTaskOutcome, ArchiveTaskStep, ArchiveTaskUseCase, DirtyWorkspace,
 };
-pub use complete_todo::{CompleteTodoOutcome, CompleteTodoUseCase};
+pub use complete_todo::{CompleteTodoOptions, CompleteTodoOutcome, CompleteTodoUseCase};
 pub use create_today_task::CreateTodayTaskUseCase;
 pub use delete_todo::{
     DeleteTodoCompletionView, DeleteTodoOutcome, DeleteTodoPrompt, DeleteTodoPromptView,
     DeleteTodoStep, DeleteTodoUseCase,
 };
+pub use doctor::{CheckStatus, DoctorCheck, DoctorReport, DoctorUseCase};
 pub use get_task_info::{GetTaskInfoUseCase, TaskInfoSnapshot};
+pub use import_task::{ImportTaskOutcome, ImportTaskUseCase, TaskBundle};
+pub use merge_task::{MergeSourceFate, MergeTaskOutcome, MergeTaskUseCase};
 pub use migrate_legacy_worktrees::{
     LegacyWorktreeTaskReport, MigrateLegacyWorktreesOutcome, MigrateLegacyWorktreesUseCase,
 };
+pub use status_document::{StatusDocument, STATUS_SCHEMA_VERSION};
 pub use sync_task::{
-    RepoSyncOutcome, SyncTaskOutcome, SyncTaskUseCase, WorkspaceCreateError, WorkspaceCreated,
+    verify_worktree, PrunableWorktree, PruneOutcome, RepoSyncOutcome, SyncOptions, SyncSummary,
+    SyncTaskOutcome, SyncTaskUseCase, WorkspaceCreateError, WorkspaceCreated, WorktreeVerification,
 };
 pub use todo_workspace::{TodoWorkspaceOutcome, TodoWorkspaceRequest, TodoWorkspaceUseCase};
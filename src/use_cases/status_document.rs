@@ -0,0 +1,169 @@
+//! Versioned JSON document behind `track status --json` and `/api/status`.
+//!
+//! Every field is copied explicitly from the domain models so that renaming a
+//! model field cannot silently change the output. Changes that remove, rename
+//! or retype a field bump [`STATUS_SCHEMA_VERSION`]; new fields may be added
+//! without a bump.
+
+use crate::models::{Link, Scrap, Task, TaskRepo, TaskStatus, TodoStatus};
+use crate::services::agent_context::AgentStatusExtensions;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+
+/// Version of the status JSON shape, emitted as `schema_version`.
+pub const STATUS_SCHEMA_VERSION: u32 = 1;
+
+/// Top-level status payload.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusDocument {
+    pub schema_version: u32,
+    /// `null` only from `/api/status` when no task is active
+    pub task: Option<StatusTask>,
+    pub todos: Vec<StatusTodo>,
+    pub links: Vec<StatusLink>,
+    pub scraps: Vec<StatusScrap>,
+    pub worktrees: Vec<StatusWorktree>,
+    /// Merged and removed worktrees, kept as a record of branch work
+    pub worktree_history: Vec<StatusWorktree>,
+    pub repos: Vec<StatusRepo>,
+    /// `vcs_mode`, `workflow`, `jj`, `git`, `todos_agent` and `guardrails`
+    #[serde(flatten)]
+    pub agent: Option<AgentStatusExtensions>,
+}
+
+impl StatusDocument {
+    /// Payload for `/api/status` when no task is active.
+    pub fn empty() -> Self {
+        Self {
+            schema_version: STATUS_SCHEMA_VERSION,
+            task: None,
+            todos: vec![],
+            links: vec![],
+            scraps: vec![],
+            worktrees: vec![],
+            worktree_history: vec![],
+            repos: vec![],
+            agent: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusTask {
+    pub id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    pub status: TaskStatus,
+    pub ticket_id: Option<String>,
+    pub ticket_url: Option<String>,
+    pub alias: Option<String>,
+    pub is_today_task: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_active_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Task> for StatusTask {
+    fn from(task: &Task) -> Self {
+        Self {
+            id: task.id,
+            name: task.name.clone(),
+            description: task.description.clone(),
+            status: task.status,
+            ticket_id: task.ticket_id.clone(),
+            ticket_url: task.ticket_url.clone(),
+            alias: task.alias.clone(),
+            is_today_task: task.is_today_task,
+            created_at: task.created_at,
+            last_active_at: task.last_active_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusTodo {
+    /// Task-scoped index, as accepted by `track todo` commands
+    pub todo_id: i64,
+    pub content: String,
+    pub status: TodoStatus,
+    pub completed_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<NaiveDate>,
+    /// Branch of the TODO's worktree, existing or requested
+    pub worktree_branch: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusLink {
+    pub link_id: i64,
+    pub url: String,
+    pub title: String,
+    /// `pr`, `issue`, `ci`, `repo`, `doc` or `link`
+    pub kind: &'static str,
+}
+
+impl From<&Link> for StatusLink {
+    fn from(link: &Link) -> Self {
+        Self {
+            link_id: link.task_index,
+            url: link.url.clone(),
+            title: link.title.clone(),
+            kind: link.kind(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusScrap {
+    pub scrap_id: i64,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+    pub active_todo_id: Option<i64>,
+    /// Pinned scraps come first in `scraps`
+    pub pinned: bool,
+}
+
+impl From<&Scrap> for StatusScrap {
+    fn from(scrap: &Scrap) -> Self {
+        Self {
+            scrap_id: scrap.scrap_id,
+            content: scrap.content.clone(),
+            created_at: scrap.created_at,
+            active_todo_id: scrap.active_todo_id,
+            pinned: scrap.pinned,
+        }
+    }
+}
+
+/// A worktree row, or a `requested` placeholder for a TODO whose worktree
+/// `track sync` has not created yet (`path`, `base_repo` and `created_at` are `null`).
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusWorktree {
+    pub path: Option<String>,
+    pub branch: Option<String>,
+    pub base_repo: Option<String>,
+    pub status: String,
+    pub created_at: Option<DateTime<Utc>>,
+    /// Task-scoped index of the owning TODO; `null` for base worktrees
+    pub todo_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusRepo {
+    pub repo_id: i64,
+    pub repo_path: String,
+    pub base_branch: Option<String>,
+    pub base_commit_hash: Option<String>,
+}
+
+impl From<&TaskRepo> for StatusRepo {
+    fn from(repo: &TaskRepo) -> Self {
+        Self {
+            repo_id: repo.task_index,
+            repo_path: repo.repo_path.clone(),
+            base_branch: repo.base_branch.clone(),
+            base_commit_hash: repo.base_commit_hash.clone(),
+        }
+    }
+}
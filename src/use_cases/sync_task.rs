@@ -1,16 +1,21 @@
 use crate::db::Database;
-use crate::models::{jj_slug, Task, TodoStatus, VcsMode};
+use crate::models::{jj_slug, Task, TodoStatus, VcsMode, Worktree};
 use crate::services::{git_worktree, RepoService, TaskService, TodoService, WorktreeService};
-use crate::utils::{Result, TrackError};
+use crate::utils::{CommandExt, Result, TrackError};
+use serde::Serialize;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
 /// Per-repository result from a sync run.
+///
+/// `from_head` is set when the repo has no stored base branch or commit and
+/// the task branch was cut from whatever was checked out.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RepoSyncOutcome {
     Missing,
     BookmarkCreated {
         base_ref: String,
+        from_head: bool,
         edit_ok: bool,
     },
     BookmarkExists {
@@ -18,10 +23,12 @@ pub enum RepoSyncOutcome {
     },
     BookmarkCreateFailed {
         base_ref: String,
+        from_head: bool,
         detail: String,
     },
     WorktreeCreated {
         base_ref: String,
+        from_head: bool,
         workspace_path: String,
     },
     WorktreeExists {
@@ -29,10 +36,32 @@ pub enum RepoSyncOutcome {
     },
     WorktreeCreateFailed {
         base_ref: String,
+        from_head: bool,
         detail: String,
     },
 }
 
+impl RepoSyncOutcome {
+    /// True when the task branch was cut from the current checkout because no
+    /// base was stored for the repo.
+    pub fn created_from_head(&self) -> bool {
+        match self {
+            RepoSyncOutcome::BookmarkCreated { from_head, .. }
+            | RepoSyncOutcome::BookmarkCreateFailed { from_head, .. }
+            | RepoSyncOutcome::WorktreeCreated { from_head, .. }
+            | RepoSyncOutcome::WorktreeCreateFailed { from_head, .. } => *from_head,
+            _ => false,
+        }
+    }
+}
+
+/// Ref the task branch is cut from: the repo's stored base branch, then its
+/// stored base commit. Blank values count as unset; `None` means neither is set.
+fn stored_base_ref(repo: &crate::models::TaskRepo) -> Option<String> {
+    let non_blank = |value: &Option<String>| value.clone().filter(|v| !v.trim().is_empty());
+    non_blank(&repo.base_branch).or_else(|| non_blank(&repo.base_commit_hash))
+}
+
 /// A TODO workspace created during sync.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WorkspaceCreated {
@@ -51,6 +80,31 @@ pub struct WorkspaceCreateError {
     pub detail: String,
 }
 
+/// A worktree whose TODO is done or cancelled, removable by `track sync --prune`.
+#[derive(Debug, Clone)]
+pub struct PrunableWorktree {
+    pub worktree: Worktree,
+    pub todo_index: i64,
+    pub todo_status: TodoStatus,
+}
+
+/// Result of pruning finished TODO worktrees.
+#[derive(Debug, Clone, Default)]
+pub struct PruneOutcome {
+    pub removed: Vec<PrunableWorktree>,
+    /// Worktrees that could not be removed, with the error detail.
+    pub failed: Vec<(PrunableWorktree, String)>,
+}
+
+/// Flags controlling a sync run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncOptions {
+    /// JJ mode: run the deprecated bookmark / per-TODO workspace sync.
+    pub legacy: bool,
+    /// JJ mode: register each repo's main checkout as the task's base worktree.
+    pub create_base_worktree: bool,
+}
+
 /// Result of syncing the current task's VCS workspaces.
 #[derive(Debug, Clone)]
 pub struct SyncTaskOutcome {
@@ -60,6 +114,105 @@ pub struct SyncTaskOutcome {
     pub repos: Vec<(String, RepoSyncOutcome)>,
     pub workspaces_created: Vec<WorkspaceCreated>,
     pub workspace_errors: Vec<WorkspaceCreateError>,
+    /// TODO workspaces that already existed from a previous sync.
+    pub workspaces_skipped: usize,
+    /// Base worktrees registered by `create_base_worktree` on this run.
+    pub base_worktrees: Vec<Worktree>,
+}
+
+/// Result of running the `track sync --verify` command in one new worktree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeVerification {
+    pub workspace_path: String,
+    pub passed: bool,
+    /// Exit status and last stderr line when the command failed
+    pub detail: Option<String>,
+}
+
+/// Runs `command` through the shell inside `workspace_path`.
+///
+/// A failing or unspawnable command is reported in the result rather than as
+/// an error, so one broken branch does not stop the others being checked.
+pub fn verify_worktree(workspace_path: &str, command: &str) -> WorktreeVerification {
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+    let output = Command::new(shell)
+        .args([flag, command])
+        .current_dir(workspace_path)
+        .logged_output();
+
+    let detail = match output {
+        Ok(output) if output.status.success() => None,
+        Ok(output) => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            match stderr.lines().rev().find(|line| !line.trim().is_empty()) {
+                Some(line) => Some(format!("{}: {}", output.status, line.trim())),
+                None => Some(output.status.to_string()),
+            }
+        }
+        Err(err) => Some(err.to_string()),
+    };
+    WorktreeVerification {
+        workspace_path: workspace_path.to_string(),
+        passed: detail.is_none(),
+        detail,
+    }
+}
+
+/// Counts of the actions a sync run took, for the closing summary and `--json`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SyncSummary {
+    pub branches_created: usize,
+    pub branches_reused: usize,
+    pub worktrees_created: usize,
+    pub worktrees_skipped: usize,
+    pub repos_missing: usize,
+    pub failures: usize,
+}
+
+impl SyncTaskOutcome {
+    pub fn summary(&self) -> SyncSummary {
+        let mut summary = SyncSummary::default();
+        for (_, outcome) in &self.repos {
+            match outcome {
+                RepoSyncOutcome::Missing => summary.repos_missing += 1,
+                RepoSyncOutcome::BookmarkCreated { edit_ok, .. } => {
+                    summary.branches_created += 1;
+                    summary.failures += usize::from(!edit_ok);
+                }
+                RepoSyncOutcome::BookmarkExists { edit_ok } => {
+                    summary.branches_reused += 1;
+                    summary.failures += usize::from(!edit_ok);
+                }
+                RepoSyncOutcome::WorktreeCreated { .. } => summary.worktrees_created += 1,
+                RepoSyncOutcome::WorktreeExists { .. } => summary.worktrees_skipped += 1,
+                RepoSyncOutcome::BookmarkCreateFailed { .. }
+                | RepoSyncOutcome::WorktreeCreateFailed { .. } => summary.failures += 1,
+            }
+        }
+        summary.worktrees_created += self.workspaces_created.len();
+        summary.worktrees_skipped += self.workspaces_skipped;
+        summary.failures += self.workspace_errors.len();
+        summary
+    }
+
+    /// Paths of the repo worktrees and TODO workspaces this run created.
+    pub fn created_worktree_paths(&self) -> Vec<&str> {
+        let repo_worktrees = self.repos.iter().filter_map(|(_, outcome)| match outcome {
+            RepoSyncOutcome::WorktreeCreated { workspace_path, .. } => {
+                Some(workspace_path.as_str())
+            }
+            _ => None,
+        });
+        let todo_workspaces = self
+            .workspaces_created
+            .iter()
+            .map(|created| created.workspace_path.as_str());
+        repo_worktrees.chain(todo_workspaces).collect()
+    }
 }
 
 /// Syncs task bookmarks/worktrees across registered repos and creates pending TODO workspaces.
@@ -72,29 +225,39 @@ impl<'a> SyncTaskUseCase<'a> {
         Self { db }
     }
 
-    pub fn execute(&self, task_id: i64, legacy: bool) -> Result<SyncTaskOutcome> {
+    /// Fails the way [`execute`](Self::execute) would before it touches anything:
+    /// no registered repositories, or jj mode without `legacy` and no pending
+    /// legacy worktree. Lets callers check before side effects like pruning.
+    pub fn validate(&self, task_id: i64, legacy: bool) -> Result<()> {
+        let task = TaskService::new(self.db).get_task(task_id)?;
+        if RepoService::new(self.db).list_repos(task_id)?.is_empty() {
+            return Err(TrackError::NoRepositoriesRegistered);
+        }
+
+        if self.db.get_vcs_mode()? == VcsMode::Jj && !legacy {
+            let todos = TodoService::new(self.db).list_todos(task_id)?;
+            if !crate::models::legacy_worktree_pending(&todos) {
+                return Err(TrackError::SyncUseJjTask {
+                    slug: jj_slug(&task),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn execute(&self, task_id: i64, options: SyncOptions) -> Result<SyncTaskOutcome> {
+        self.validate(task_id, options.legacy)?;
+
         let vcs_mode = self.db.get_vcs_mode()?;
         let task_service = TaskService::new(self.db);
         let task = task_service.get_task(task_id)?;
         let repo_service = RepoService::new(self.db);
         let repos = repo_service.list_repos(task_id)?;
 
-        if repos.is_empty() {
-            return Err(TrackError::NoRepositoriesRegistered);
-        }
-
         let slug = jj_slug(&task);
         let worktree_service = WorktreeService::new(self.db);
         let existing_worktrees = worktree_service.list_worktrees(task_id)?;
 
-        if vcs_mode == VcsMode::Jj && !legacy {
-            let todo_service = TodoService::new(self.db);
-            let todos = todo_service.list_todos(task_id)?;
-            if !crate::models::legacy_worktree_pending(&todos) {
-                return Err(TrackError::SyncUseJjTask { slug });
-            }
-        }
-
         let task_bookmark = match vcs_mode {
             VcsMode::Jj => {
                 let worktree_service = WorktreeService::new(self.db);
@@ -117,8 +280,28 @@ impl<'a> SyncTaskUseCase<'a> {
             repo_outcomes.push((repo.repo_path.clone(), outcome));
         }
 
+        let mut base_worktrees = Vec::new();
+        if vcs_mode == VcsMode::Jj && options.create_base_worktree {
+            for (repo_path, outcome) in &repo_outcomes {
+                if matches!(
+                    outcome,
+                    RepoSyncOutcome::BookmarkCreated { .. }
+                        | RepoSyncOutcome::BookmarkExists { .. }
+                ) {
+                    if let Some(base) = worktree_service.register_base_worktree(
+                        task_id,
+                        repo_path,
+                        &task_bookmark,
+                    )? {
+                        base_worktrees.push(base);
+                    }
+                }
+            }
+        }
+
         let mut workspaces_created = Vec::new();
         let mut workspace_errors = Vec::new();
+        let mut workspaces_skipped = 0;
 
         if vcs_mode == VcsMode::Jj {
             let todo_service = TodoService::new(self.db);
@@ -126,12 +309,6 @@ impl<'a> SyncTaskUseCase<'a> {
 
             for todo in todos {
                 if todo.worktree_requested && todo.status != TodoStatus::Done {
-                    let worktrees = worktree_service.list_worktrees(task_id)?;
-                    let exists = worktrees.iter().any(|wt| wt.todo_id == Some(todo.id));
-                    if exists {
-                        continue;
-                    }
-
                     for repo in &repos {
                         match worktree_service.add_worktree(
                             task_id,
@@ -148,6 +325,8 @@ impl<'a> SyncTaskUseCase<'a> {
                                 workspace_path: wt.path,
                                 branch: wt.branch,
                             }),
+                            // Already set up on a previous sync
+                            Err(TrackError::WorktreeExists(_)) => workspaces_skipped += 1,
                             Err(err) => workspace_errors.push(WorkspaceCreateError {
                                 todo_index: todo.task_index,
                                 repo_path: repo.repo_path.clone(),
@@ -166,9 +345,45 @@ impl<'a> SyncTaskUseCase<'a> {
             repos: repo_outcomes,
             workspaces_created,
             workspace_errors,
+            workspaces_skipped,
+            base_worktrees,
         })
     }
 
+    /// Lists TODO worktrees of `task_id` whose TODO is done or cancelled.
+    pub fn prune_candidates(&self, task_id: i64) -> Result<Vec<PrunableWorktree>> {
+        let todos = TodoService::new(self.db).list_todos(task_id)?;
+        let worktrees = WorktreeService::new(self.db).list_worktrees(task_id)?;
+
+        Ok(worktrees
+            .into_iter()
+            .filter(|wt| !wt.is_base)
+            .filter_map(|wt| {
+                let todo = todos.iter().find(|t| Some(t.id) == wt.todo_id)?;
+                (todo.status != TodoStatus::Pending).then_some(PrunableWorktree {
+                    worktree: wt,
+                    todo_index: todo.task_index,
+                    todo_status: todo.status,
+                })
+            })
+            .collect())
+    }
+
+    /// Removes the given worktrees (files and records); failures are collected, not fatal.
+    pub fn prune(&self, candidates: Vec<PrunableWorktree>) -> PruneOutcome {
+        let worktree_service = WorktreeService::new(self.db);
+        let mut outcome = PruneOutcome::default();
+
+        for candidate in candidates {
+            match worktree_service.remove_worktree(candidate.worktree.id, false) {
+                Ok(()) => outcome.removed.push(candidate),
+                Err(err) => outcome.failed.push((candidate, err.to_string())),
+            }
+        }
+
+        outcome
+    }
+
     fn sync_repo_jj(
         &self,
         worktree_service: &WorktreeService<'_>,
@@ -183,7 +398,7 @@ impl<'a> SyncTaskUseCase<'a> {
         let status_output = Command::new("jj")
             .current_dir(&repo.repo_path)
             .args(["-R", &repo.repo_path, "diff", "--summary"])
-            .output()?;
+            .logged_output()?;
 
         if !status_output.status.success() {
             return Err(TrackError::FailedRepoStatusCheck(repo.repo_path.clone()));
@@ -201,11 +416,9 @@ impl<'a> SyncTaskUseCase<'a> {
             worktree_service.bookmark_exists_in_repo(&repo.repo_path, task_bookmark)?;
 
         if !bookmark_exists {
-            let base_ref = repo
-                .base_branch
-                .clone()
-                .or_else(|| repo.base_commit_hash.clone())
-                .unwrap_or_else(|| "@".to_string());
+            let stored = stored_base_ref(repo);
+            let from_head = stored.is_none();
+            let base_ref = stored.unwrap_or_else(|| "@".to_string());
 
             let create_result = Command::new("jj")
                 .args([
@@ -217,17 +430,25 @@ impl<'a> SyncTaskUseCase<'a> {
                     "-r",
                     &base_ref,
                 ])
-                .output()?;
+                .logged_output()?;
 
             if create_result.status.success() {
                 let edit_ok = try_edit_workspace(&repo.repo_path, task_bookmark);
-                return Ok(RepoSyncOutcome::BookmarkCreated { base_ref, edit_ok });
+                return Ok(RepoSyncOutcome::BookmarkCreated {
+                    base_ref,
+                    from_head,
+                    edit_ok,
+                });
             }
 
             let detail = String::from_utf8_lossy(&create_result.stderr)
                 .trim()
                 .to_string();
-            return Ok(RepoSyncOutcome::BookmarkCreateFailed { base_ref, detail });
+            return Ok(RepoSyncOutcome::BookmarkCreateFailed {
+                base_ref,
+                from_head,
+                detail,
+            });
         }
 
         let edit_ok = try_edit_workspace(&repo.repo_path, task_bookmark);
@@ -254,19 +475,23 @@ impl<'a> SyncTaskUseCase<'a> {
             return Err(TrackError::RepoHasPendingChanges(repo.repo_path.clone()));
         }
 
-        let base_ref = repo
-            .base_branch
-            .clone()
-            .or_else(|| repo.base_commit_hash.clone())
-            .unwrap_or_else(|| "HEAD".to_string());
+        // Name the checked-out branch in the fallback so the output shows
+        // where the task branch actually started.
+        let stored = stored_base_ref(repo);
+        let from_head = stored.is_none();
+        let base_ref = stored.unwrap_or_else(|| {
+            git_worktree::current_git_branch(&repo.repo_path).unwrap_or_else(|| "HEAD".to_string())
+        });
 
         match git_worktree::create_git_worktree(&repo.repo_path, slug, &base_ref) {
             Ok(path) => Ok(RepoSyncOutcome::WorktreeCreated {
                 base_ref,
+                from_head,
                 workspace_path: path,
             }),
             Err(err) => Ok(RepoSyncOutcome::WorktreeCreateFailed {
                 base_ref,
+                from_head,
                 detail: err.to_string(),
             }),
         }
@@ -283,6 +508,8 @@ impl<'a> SyncTaskUseCase<'a> {
         let repo_worktrees: Vec<PathBuf> = existing_worktrees
             .iter()
             .filter(|wt| wt.base_repo.as_deref() == Some(repo_path))
+            // A base registered on the main checkout must not mask its own changes
+            .filter(|wt| !wt.is_main_checkout())
             .filter_map(|wt| Path::new(&wt.path).canonicalize().ok())
             .filter_map(|wt_path| wt_path.strip_prefix(&repo_root).ok().map(PathBuf::from))
             .collect();
@@ -329,7 +556,7 @@ impl<'a> SyncTaskUseCase<'a> {
 fn try_edit_workspace(repo_path: &str, task_bookmark: &str) -> bool {
     Command::new("jj")
         .args(["-R", repo_path, "edit", task_bookmark])
-        .status()
+        .logged_status()
         .map(|status| status.success())
         .unwrap_or(false)
 }
@@ -340,6 +567,126 @@ mod tests {
     use crate::db::Database;
     use crate::services::TodoService;
 
+    fn git(repo: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args([
+                "-c",
+                "user.name=track",
+                "-c",
+                "user.email=track@example.com",
+            ])
+            .args(args)
+            .output()
+            .expect("failed to run git");
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    /// A git repo on `feature`, one commit ahead of `main`.
+    fn repo_on_feature_branch() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        git(dir.path(), &["init", "-q", "-b", "main"]);
+        git(dir.path(), &["commit", "-q", "--allow-empty", "-m", "init"]);
+        git(dir.path(), &["checkout", "-q", "-b", "feature"]);
+        git(dir.path(), &["commit", "-q", "--allow-empty", "-m", "wip"]);
+        dir
+    }
+
+    fn sync_git_repo(repo_path: &str, base_branch: Option<&str>) -> RepoSyncOutcome {
+        let db = Database::new_in_memory().unwrap();
+        db.set_vcs_mode(VcsMode::Git).unwrap();
+        let task = TaskService::new(&db)
+            .create_task("Base", None, Some("BASE-1"), None)
+            .unwrap();
+        db.get_connection()
+            .execute(
+                "INSERT INTO task_repos (task_id, task_index, repo_path, base_branch, created_at) VALUES (?1, 1, ?2, ?3, datetime('now'))",
+                rusqlite::params![task.id, repo_path, base_branch],
+            )
+            .unwrap();
+
+        let mut outcome = SyncTaskUseCase::new(&db)
+            .execute(task.id, SyncOptions::default())
+            .unwrap();
+        outcome.repos.remove(0).1
+    }
+
+    #[test]
+    fn stored_base_ref_skips_blank_branch_for_commit() {
+        let repo = |branch: Option<&str>, commit: Option<&str>| crate::models::TaskRepo {
+            id: 1,
+            task_id: 1,
+            task_index: 1,
+            repo_path: "/repo".to_string(),
+            base_branch: branch.map(str::to_string),
+            base_commit_hash: commit.map(str::to_string),
+            created_at: chrono::Utc::now(),
+        };
+
+        assert_eq!(
+            stored_base_ref(&repo(Some("main"), Some("abc123"))).as_deref(),
+            Some("main")
+        );
+        assert_eq!(
+            stored_base_ref(&repo(Some(" "), Some("abc123"))).as_deref(),
+            Some("abc123")
+        );
+        assert_eq!(stored_base_ref(&repo(Some(""), Some(""))), None);
+        assert_eq!(stored_base_ref(&repo(None, None)), None);
+    }
+
+    #[test]
+    fn git_sync_cuts_branch_from_stored_base_not_current_checkout() {
+        let dir = repo_on_feature_branch();
+        let repo_path = dir.path().to_str().unwrap();
+
+        let outcome = sync_git_repo(repo_path, Some("main"));
+        let RepoSyncOutcome::WorktreeCreated {
+            base_ref,
+            from_head,
+            workspace_path,
+        } = outcome
+        else {
+            panic!("unexpected outcome: {outcome:?}");
+        };
+        assert_eq!(base_ref, "main");
+        assert!(!from_head);
+        assert_eq!(
+            git(Path::new(&workspace_path), &["rev-parse", "HEAD"]),
+            git(dir.path(), &["rev-parse", "main"])
+        );
+        assert_eq!(git(dir.path(), &["branch", "--show-current"]), "feature");
+    }
+
+    #[test]
+    fn git_sync_falls_back_to_current_branch_without_stored_base() {
+        let dir = repo_on_feature_branch();
+        let repo_path = dir.path().to_str().unwrap();
+
+        let outcome = sync_git_repo(repo_path, None);
+        assert!(outcome.created_from_head());
+        let RepoSyncOutcome::WorktreeCreated {
+            base_ref,
+            workspace_path,
+            ..
+        } = outcome
+        else {
+            panic!("unexpected outcome: {outcome:?}");
+        };
+        assert_eq!(base_ref, "feature");
+        assert_eq!(
+            git(Path::new(&workspace_path), &["rev-parse", "HEAD"]),
+            git(dir.path(), &["rev-parse", "feature"])
+        );
+    }
+
     #[test]
     fn sync_requires_registered_repos() {
         let db = Database::new_in_memory().unwrap();
@@ -348,10 +695,65 @@ mod tests {
             .create_task("Sync task", None, None, None)
             .unwrap();
 
-        let result = SyncTaskUseCase::new(&db).execute(task.id, false);
+        let result = SyncTaskUseCase::new(&db).execute(task.id, SyncOptions::default());
         assert!(matches!(result, Err(TrackError::NoRepositoriesRegistered)));
     }
 
+    #[test]
+    fn sync_summary_counts_missing_repos() {
+        let db = Database::new_in_memory().unwrap();
+        let task = TaskService::new(&db)
+            .create_task("Summary", None, Some("SUM-1"), None)
+            .unwrap();
+        for (index, path) in [(1, "/missing/a"), (2, "/missing/b")] {
+            db.get_connection()
+                .execute(
+                    "INSERT INTO task_repos (task_id, task_index, repo_path, created_at) VALUES (?1, ?2, ?3, '2026-01-01T00:00:00+00:00')",
+                    rusqlite::params![task.id, index, path],
+                )
+                .unwrap();
+        }
+
+        let outcome = SyncTaskUseCase::new(&db)
+            .execute(
+                task.id,
+                SyncOptions {
+                    legacy: true,
+                    ..SyncOptions::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            outcome.summary(),
+            SyncSummary {
+                repos_missing: 2,
+                ..SyncSummary::default()
+            }
+        );
+    }
+
+    #[test]
+    fn verify_worktree_reports_failures_without_erroring() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().to_str().unwrap();
+        std::fs::write(dir.path().join("marker"), "").unwrap();
+
+        let passed = verify_worktree(path, "test -f marker");
+        assert!(passed.passed);
+        assert_eq!(passed.detail, None);
+
+        let failed = verify_worktree(path, "echo 'build broke' >&2; exit 3");
+        assert!(!failed.passed);
+        let detail = failed.detail.unwrap();
+        assert!(
+            detail.contains('3') && detail.ends_with("build broke"),
+            "{detail}"
+        );
+
+        let missing = verify_worktree("/nonexistent/worktree", "true");
+        assert!(!missing.passed);
+    }
+
     #[test]
     fn sync_rejects_jj_mode_without_legacy_or_worktree_todos() {
         let db = Database::new_in_memory().unwrap();
@@ -369,7 +771,48 @@ mod tests {
             )
             .unwrap();
 
-        let result = SyncTaskUseCase::new(&db).execute(task.id, false);
+        let result = SyncTaskUseCase::new(&db).execute(task.id, SyncOptions::default());
         assert!(matches!(result, Err(TrackError::SyncUseJjTask { .. })));
     }
+
+    #[test]
+    fn prune_removes_worktrees_of_finished_todos_only() {
+        let db = Database::new_in_memory().unwrap();
+        let task = TaskService::new(&db)
+            .create_task("Prune", None, None, None)
+            .unwrap();
+        let todo_service = TodoService::new(&db);
+        let done = todo_service.add_todo(task.id, "Done", true).unwrap();
+        let cancelled = todo_service.add_todo(task.id, "Cancelled", true).unwrap();
+        let pending = todo_service.add_todo(task.id, "Pending", true).unwrap();
+        todo_service.mark_done(done.id).unwrap();
+        todo_service
+            .update_status(cancelled.id, TodoStatus::CANCELLED)
+            .unwrap();
+
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = db.get_connection();
+        for todo_id in [Some(done.id), Some(cancelled.id), Some(pending.id), None] {
+            conn.execute(
+                "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, '/missing', 'b', NULL, 'active', ?2, ?3, 0)",
+                rusqlite::params![task.id, now, todo_id],
+            )
+            .unwrap();
+        }
+
+        let use_case = SyncTaskUseCase::new(&db);
+        let candidates = use_case.prune_candidates(task.id).unwrap();
+        let indices: Vec<i64> = candidates.iter().map(|c| c.todo_index).collect();
+        assert_eq!(indices, vec![done.task_index, cancelled.task_index]);
+
+        let outcome = use_case.prune(candidates);
+        assert_eq!(outcome.removed.len(), 2);
+        assert!(outcome.failed.is_empty());
+
+        let remaining = WorktreeService::new(&db).list_worktrees(task.id).unwrap();
+        assert_eq!(remaining.len(), 2);
+        assert!(remaining
+            .iter()
+            .all(|wt| wt.todo_id.is_none() || wt.todo_id == Some(pending.id)));
+    }
 }
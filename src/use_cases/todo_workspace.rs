@@ -6,10 +6,16 @@ use std::path::{Path, PathBuf};
 
 /// Options for creating or showing a TODO workspace.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct TodoWorkspaceRequest {
+pub struct TodoWorkspaceRequest<'a> {
     pub recreate: bool,
     pub force: bool,
     pub all_repos: bool,
+    /// With `all_repos`, abort on the first repo that fails instead of
+    /// continuing and reporting failures in the outcome.
+    pub strict: bool,
+    /// Branch name for newly created workspaces instead of `{ticket}-todo-{index}`;
+    /// the ticket prefix is still applied.
+    pub branch: Option<&'a str>,
 }
 
 /// Result of resolving or creating TODO workspaces.
@@ -17,6 +23,8 @@ pub struct TodoWorkspaceRequest {
 pub struct TodoWorkspaceOutcome {
     pub paths: Vec<String>,
     pub warnings: Vec<String>,
+    /// Repos whose workspace could not be created or recreated, with the error.
+    pub failures: Vec<(String, String)>,
 }
 
 /// Creates, recreates, or lists jj workspaces for a TODO.
@@ -33,7 +41,7 @@ impl<'a> TodoWorkspaceUseCase<'a> {
         &self,
         task_id: i64,
         todo_index: i64,
-        request: TodoWorkspaceRequest,
+        request: TodoWorkspaceRequest<'_>,
     ) -> Result<TodoWorkspaceOutcome> {
         let todo_service = TodoService::new(self.db);
         let worktree_service = WorktreeService::new(self.db);
@@ -56,6 +64,7 @@ impl<'a> TodoWorkspaceUseCase<'a> {
         let worktrees = worktree_service.list_worktrees(task_id)?;
         let task = task_service.get_task(task_id)?;
         let branch_name = worktree_service.get_todo_branch_name(
+            request.branch,
             task_id,
             task.ticket_id.as_deref(),
             todo.task_index,
@@ -63,105 +72,122 @@ impl<'a> TodoWorkspaceUseCase<'a> {
 
         let mut paths = Vec::new();
         let mut warnings = Vec::new();
+        let mut failures = Vec::new();
 
         for repo in target_repos {
-            let mut todo_worktrees: Vec<_> = worktrees
-                .iter()
-                .filter(|wt| {
-                    wt.todo_id == Some(todo.id)
-                        && wt.base_repo.as_deref() == Some(repo.repo_path.as_str())
-                })
-                .cloned()
-                .collect();
-
-            if !todo_worktrees.is_empty() {
-                if !request.recreate {
-                    paths.extend(todo_worktrees.iter().map(|worktree| worktree.path.clone()));
-                    continue;
-                }
+            let mut workspace_for_repo = || -> Result<()> {
+                let mut todo_worktrees: Vec<_> = worktrees
+                    .iter()
+                    .filter(|wt| {
+                        wt.todo_id == Some(todo.id)
+                            && wt.base_repo.as_deref() == Some(repo.repo_path.as_str())
+                    })
+                    .cloned()
+                    .collect();
+
+                if !todo_worktrees.is_empty() {
+                    if !request.recreate {
+                        paths.extend(todo_worktrees.iter().map(|worktree| worktree.path.clone()));
+                        return Ok(());
+                    }
 
-                if !request.force {
-                    for worktree in &todo_worktrees {
-                        if Path::new(&worktree.path).exists()
-                            && worktree_service.has_uncommitted_changes(&worktree.path)?
+                    if !request.force {
+                        for worktree in &todo_worktrees {
+                            if Path::new(&worktree.path).exists()
+                                && worktree_service.has_uncommitted_changes(&worktree.path)?
+                            {
+                                return Err(TrackError::WorkspaceHasUncommittedChanges {
+                                    path: worktree.path.clone(),
+                                });
+                            }
+                        }
+                    }
+
+                    for worktree in todo_worktrees.drain(..) {
+                        if !worktree_service
+                            .bookmark_exists_in_repo(repo.repo_path.as_str(), &worktree.branch)?
                         {
-                            return Err(TrackError::WorkspaceHasUncommittedChanges {
-                                path: worktree.path.clone(),
+                            if Path::new(&worktree.path).exists() {
+                                paths.push(worktree.path.clone());
+                            }
+                            if request.all_repos {
+                                warnings.push(format!(
+                                    "Skipping recreate for {} (missing branch/bookmark).",
+                                    worktree.path
+                                ));
+                                return Ok(());
+                            }
+                            return Err(TrackError::BookmarkNotFound {
+                                bookmark: worktree.branch.clone(),
+                                repo_path: repo.repo_path.clone(),
                             });
                         }
-                    }
-                }
 
-                for worktree in todo_worktrees.drain(..) {
-                    if !worktree_service
-                        .bookmark_exists_in_repo(repo.repo_path.as_str(), &worktree.branch)?
+                        let recreated =
+                            worktree_service.recreate_worktree(&worktree, request.force)?;
+                        paths.push(recreated.path);
+                    }
+                } else {
+                    if request.recreate
+                        && !worktree_service
+                            .bookmark_exists_in_repo(repo.repo_path.as_str(), &branch_name)?
                     {
-                        if Path::new(&worktree.path).exists() {
-                            paths.push(worktree.path.clone());
-                        }
                         if request.all_repos {
                             warnings.push(format!(
-                                "Skipping recreate for {} (missing branch/bookmark).",
-                                worktree.path
+                                "Skipping create for {} (missing branch/bookmark).",
+                                repo.repo_path
                             ));
-                            continue;
+                            return Ok(());
                         }
                         return Err(TrackError::BookmarkNotFound {
-                            bookmark: worktree.branch.clone(),
+                            bookmark: branch_name.clone(),
                             repo_path: repo.repo_path.clone(),
                         });
                     }
 
-                    let recreated = worktree_service.recreate_worktree(&worktree, request.force)?;
-                    paths.push(recreated.path);
-                }
-            } else {
-                if request.recreate
-                    && !worktree_service
-                        .bookmark_exists_in_repo(repo.repo_path.as_str(), &branch_name)?
-                {
-                    if request.all_repos {
-                        warnings.push(format!(
-                            "Skipping create for {} (missing branch/bookmark).",
-                            repo.repo_path
-                        ));
-                        continue;
-                    }
-                    return Err(TrackError::BookmarkNotFound {
-                        bookmark: branch_name.clone(),
-                        repo_path: repo.repo_path.clone(),
-                    });
-                }
-
-                let created = match worktree_service.add_worktree(
-                    task_id,
-                    &repo.repo_path,
-                    None,
-                    task.ticket_id.as_deref(),
-                    Some(todo.id),
-                    false,
-                ) {
-                    Ok(worktree) => worktree,
-                    Err(TrackError::BookmarkExists(_)) => worktree_service.add_existing_worktree(
+                    let created = match worktree_service.add_worktree(
                         task_id,
                         &repo.repo_path,
-                        &branch_name,
+                        request.branch,
+                        task.ticket_id.as_deref(),
                         Some(todo.id),
                         false,
-                        None,
-                    )?,
-                    Err(err) => return Err(err),
-                };
+                    ) {
+                        Ok(worktree) => worktree,
+                        Err(TrackError::BookmarkExists(_)) => worktree_service
+                            .add_existing_worktree(
+                                task_id,
+                                &repo.repo_path,
+                                &branch_name,
+                                Some(todo.id),
+                                false,
+                                None,
+                            )?,
+                        Err(err) => return Err(err),
+                    };
+
+                    paths.push(created.path);
+                }
+                Ok(())
+            };
 
-                paths.push(created.path);
+            if let Err(err) = workspace_for_repo() {
+                if !request.all_repos || request.strict {
+                    return Err(err);
+                }
+                failures.push((repo.repo_path.clone(), err.to_string()));
             }
         }
 
-        if paths.is_empty() {
+        if paths.is_empty() && failures.is_empty() {
             return Err(TrackError::NoWorkspacePathsAvailable);
         }
 
-        Ok(TodoWorkspaceOutcome { paths, warnings })
+        Ok(TodoWorkspaceOutcome {
+            paths,
+            warnings,
+            failures,
+        })
     }
 }
 
@@ -213,8 +239,45 @@ mod tests {
                 recreate: false,
                 force: false,
                 all_repos: false,
+                strict: false,
+                branch: None,
             },
         );
         assert!(matches!(result, Err(TrackError::NoRepositoriesRegistered)));
     }
+
+    #[test]
+    fn all_repos_collects_failures_unless_strict() {
+        let db = Database::new_in_memory().unwrap();
+        let task = TaskService::new(&db)
+            .create_task("Task", None, None, None)
+            .unwrap();
+        TodoService::new(&db)
+            .add_todo(task.id, "Todo", false)
+            .unwrap();
+        let missing = tempfile::tempdir().unwrap();
+        for (index, name) in [(1, "a"), (2, "b")] {
+            let path = missing.path().join(name);
+            db.get_connection()
+                .execute(
+                    "INSERT INTO task_repos (task_id, task_index, repo_path, created_at) VALUES (?1, ?2, ?3, '2026-01-01T00:00:00+00:00')",
+                    rusqlite::params![task.id, index, path.to_str().unwrap()],
+                )
+                .unwrap();
+        }
+        let request = |strict| TodoWorkspaceRequest {
+            branch: None,
+            recreate: false,
+            force: false,
+            all_repos: true,
+            strict,
+        };
+        let use_case = TodoWorkspaceUseCase::new(&db);
+
+        let outcome = use_case.execute(task.id, 1, request(false)).unwrap();
+        assert!(outcome.paths.is_empty());
+        assert_eq!(outcome.failures.len(), 2);
+
+        assert!(use_case.execute(task.id, 1, request(true)).is_err());
+    }
 }
@@ -0,0 +1,108 @@
+use crate::models::{TaskStatus, TodoStatus};
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static FORCE_DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// ANSI styles used by table output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Green,
+    Yellow,
+    Red,
+    Dim,
+    /// Dim + strikethrough.
+    Struck,
+}
+
+impl Style {
+    fn code(self) -> &'static str {
+        match self {
+            Style::Green => "32",
+            Style::Yellow => "33",
+            Style::Red => "31",
+            Style::Dim => "2",
+            Style::Struck => "2;9",
+        }
+    }
+}
+
+/// Turns color off for the rest of the process (`--no-color`).
+pub fn disable() {
+    FORCE_DISABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether stdout output should be colored: a TTY, no `NO_COLOR`, no `--no-color`.
+pub fn enabled() -> bool {
+    !FORCE_DISABLED.load(Ordering::Relaxed)
+        && std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+        && std::io::stdout().is_terminal()
+}
+
+/// Wraps `text` in the style's escape codes when `enabled` is true.
+pub fn paint(text: &str, style: Style, enabled: bool) -> String {
+    if enabled {
+        format!("\x1b[{}m{}\x1b[0m", style.code(), text)
+    } else {
+        text.to_string()
+    }
+}
+
+/// Renders a TODO status for tables: green done, yellow pending, struck cancelled.
+pub fn todo_status(status: TodoStatus, enabled: bool) -> String {
+    let style = match status {
+        TodoStatus::Done => Style::Green,
+        TodoStatus::Pending => Style::Yellow,
+        TodoStatus::Cancelled => Style::Struck,
+    };
+    paint(status.as_str(), style, enabled)
+}
+
+/// Renders a task status for tables: yellow blocked, dim paused, struck archived.
+pub fn task_status(status: TaskStatus, enabled: bool) -> String {
+    let style = match status {
+        TaskStatus::Active => return status.as_str().to_string(),
+        TaskStatus::Blocked => Style::Yellow,
+        TaskStatus::Paused => Style::Dim,
+        TaskStatus::Archived => Style::Struck,
+    };
+    paint(status.as_str(), style, enabled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paints_only_when_enabled() {
+        assert_eq!(paint("done", Style::Green, false), "done");
+        assert_eq!(paint("done", Style::Green, true), "\x1b[32mdone\x1b[0m");
+    }
+
+    #[test]
+    fn todo_status_styles() {
+        assert_eq!(
+            todo_status(TodoStatus::Pending, true),
+            "\x1b[33mpending\x1b[0m"
+        );
+        assert_eq!(
+            todo_status(TodoStatus::Cancelled, true),
+            "\x1b[2;9mcancelled\x1b[0m"
+        );
+        assert_eq!(todo_status(TodoStatus::Done, false), "done");
+    }
+
+    #[test]
+    fn task_status_styles() {
+        assert_eq!(task_status(TaskStatus::Active, true), "active");
+        assert_eq!(
+            task_status(TaskStatus::Blocked, true),
+            "\x1b[33mblocked\x1b[0m"
+        );
+        assert_eq!(
+            task_status(TaskStatus::Paused, true),
+            "\x1b[2mpaused\x1b[0m"
+        );
+        assert_eq!(task_status(TaskStatus::Archived, false), "archived");
+    }
+}
@@ -0,0 +1,73 @@
+//! Logging wrappers for shelled-out git/jj commands.
+
+use std::io;
+use std::process::{Command, ExitStatus, Output};
+
+/// Runs a [`Command`] while logging the invocation and exit status at debug level.
+pub trait CommandExt {
+    /// Like [`Command::output`], logging stderr at trace level on failure.
+    fn logged_output(&mut self) -> io::Result<Output>;
+
+    /// Like [`Command::status`].
+    fn logged_status(&mut self) -> io::Result<ExitStatus>;
+}
+
+impl CommandExt for Command {
+    fn logged_output(&mut self) -> io::Result<Output> {
+        let rendered = render(self);
+        tracing::debug!(command = %rendered, "running");
+
+        let output = self.output();
+        match &output {
+            Ok(output) => {
+                tracing::debug!(command = %rendered, status = %output.status, "finished");
+                if !output.status.success() {
+                    tracing::trace!(
+                        command = %rendered,
+                        stderr = %String::from_utf8_lossy(&output.stderr).trim(),
+                        "stderr"
+                    );
+                }
+            }
+            Err(err) => tracing::debug!(command = %rendered, error = %err, "failed to spawn"),
+        }
+        output
+    }
+
+    fn logged_status(&mut self) -> io::Result<ExitStatus> {
+        let rendered = render(self);
+        tracing::debug!(command = %rendered, "running");
+
+        let status = self.status();
+        match &status {
+            Ok(status) => tracing::debug!(command = %rendered, status = %status, "finished"),
+            Err(err) => tracing::debug!(command = %rendered, error = %err, "failed to spawn"),
+        }
+        status
+    }
+}
+
+/// Renders `program arg...` (plus working directory when set) for log lines.
+fn render(command: &Command) -> String {
+    let mut rendered = command.get_program().to_string_lossy().into_owned();
+    for arg in command.get_args() {
+        rendered.push(' ');
+        rendered.push_str(&arg.to_string_lossy());
+    }
+    if let Some(dir) = command.get_current_dir() {
+        rendered.push_str(&format!(" (in {})", dir.display()));
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_includes_args_and_dir() {
+        let mut command = Command::new("git");
+        command.args(["-C", "/repo", "status"]).current_dir("/tmp");
+        assert_eq!(render(&command), "git -C /repo status (in /tmp)");
+    }
+}
@@ -0,0 +1,64 @@
+//! Disk usage helpers for worktree cleanup decisions.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Total size in bytes of the files under `path`.
+///
+/// Symlinks are counted as links and never followed, so a worktree pointing
+/// into its base repository is not measured twice. Only `path` itself must be
+/// readable: unreadable entries below it are skipped, so the result is a lower
+/// bound rather than an error.
+pub fn dir_size(path: &Path) -> io::Result<u64> {
+    let metadata = fs::symlink_metadata(path)?;
+    if !metadata.is_dir() {
+        return Ok(metadata.len());
+    }
+
+    Ok(fs::read_dir(path)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| dir_size(&entry.path()).ok())
+        .sum())
+}
+
+/// Formats a byte count with binary units, e.g. `512 B`, `1.5 KiB`, `2.0 GiB`.
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dir_size_sums_nested_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.txt"), vec![0u8; 100]).unwrap();
+        fs::create_dir(dir.path().join("nested")).unwrap();
+        fs::write(dir.path().join("nested").join("b.txt"), vec![0u8; 50]).unwrap();
+
+        assert_eq!(dir_size(dir.path()).unwrap(), 150);
+        assert!(dir_size(&dir.path().join("missing")).is_err());
+    }
+
+    #[test]
+    fn format_size_uses_binary_units() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MiB");
+        assert_eq!(format_size(3 * 1024 * 1024 * 1024), "3.0 GiB");
+    }
+}
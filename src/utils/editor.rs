@@ -0,0 +1,86 @@
+//! Editing text in the user's editor, the way `git commit` does.
+
+use super::{CommandExt, Result, TrackError};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::process::Command;
+
+/// Editor to launch: `$VISUAL`, then `$EDITOR`, then `vi`.
+pub fn editor_command() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|key| std::env::var(key).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string())
+}
+
+/// Opens `initial` in the user's editor and returns the text saved on exit.
+///
+/// `file_name` is used as the temp file's suffix so editors pick a syntax mode.
+pub fn edit_text(initial: &str, file_name: &str) -> Result<String> {
+    edit_text_with(&editor_command(), initial, file_name)
+}
+
+/// Like [`edit_text`] with an explicit editor. The command runs through `sh`
+/// so values with arguments such as `code --wait` work.
+///
+/// The buffer is created exclusively inside a fresh private directory, so a
+/// file or symlink planted in a shared temp dir is never written through.
+fn edit_text_with(editor: &str, initial: &str, file_name: &str) -> Result<String> {
+    let dir = tempfile::Builder::new().prefix("track-edit-").tempdir()?;
+    let path = dir.path().join(file_name);
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)?
+        .write_all(initial.as_bytes())?;
+
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{editor} \"$1\""))
+        .arg("sh")
+        .arg(&path)
+        .logged_status();
+    match status {
+        Ok(status) if status.success() => fs::read_to_string(&path).map_err(TrackError::from),
+        Ok(status) => Err(TrackError::EditorFailed(format!(
+            "`{editor}` exited with {status}"
+        ))),
+        Err(err) => Err(TrackError::EditorFailed(format!(
+            "could not run `{editor}`: {err}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_text_returns_what_the_editor_saved() {
+        let text = edit_text_with("printf 'edited\\n' >>", "original\n", "edit-ok.md").unwrap();
+        assert_eq!(text, "original\nedited\n");
+    }
+
+    #[test]
+    fn edit_text_fails_when_editor_fails() {
+        let result = edit_text_with("false", "original", "edit-fail.md");
+        assert!(matches!(result, Err(TrackError::EditorFailed(_))));
+    }
+
+    #[test]
+    fn edit_text_uses_a_private_file_and_cleans_it_up() {
+        // The editor records the path it was given
+        let record = tempfile::NamedTempFile::new().unwrap();
+        let editor = format!("printf %s >{}", record.path().display());
+        edit_text_with(&editor, "original", "edit-private.md").unwrap();
+
+        let used = fs::read_to_string(record.path()).unwrap();
+        assert!(used.ends_with("/edit-private.md"), "{used}");
+        assert_ne!(
+            std::path::Path::new(&used).parent(),
+            Some(std::env::temp_dir().as_path())
+        );
+        assert!(!std::path::Path::new(&used).exists());
+    }
+}
@@ -61,9 +61,36 @@ pub enum TrackError {
     #[error("TODO #{0} not found")]
     TodoNotFound(i64),
 
+    #[error("TODO is already in task #{0}")]
+    TodoAlreadyInTask(i64),
+
+    #[error("Cannot merge task #{0} into itself")]
+    TaskMergeIntoSelf(i64),
+
+    #[error("Task #{task_id} still has {count} worktree(s) not tied to a TODO; archive it instead of deleting")]
+    TaskHasWorktrees { task_id: i64, count: usize },
+
     #[error("Worktree #{0} not found")]
     WorktreeNotFound(i64),
 
+    #[error("Worktree already exists: {0}")]
+    WorktreeExists(String),
+
+    #[error("Worktree #{id} is the task's base worktree; {remaining} TODO worktree(s) still merge into it and could not be completed without it. Remove them first or use --force.")]
+    BaseWorktreeInUse { id: i64, remaining: usize },
+
+    #[error("Invalid date '{input}': use {accepted}")]
+    InvalidDate {
+        input: String,
+        accepted: &'static str,
+    },
+
+    #[error("Invalid import file: {0}")]
+    InvalidImport(String),
+
+    #[error("Could not detect a pull request for branch '{0}'; pass the URL explicitly")]
+    PrDetectionFailed(String),
+
     #[error("Invalid status: {0}")]
     InvalidStatus(String),
 
@@ -76,8 +103,14 @@ pub enum TrackError {
     #[error("Use 'track todo done <id>' to complete a TODO (merges JJ workspace if present).")]
     TodoCompleteRequiresDoneCommand,
 
+    #[error("{count} TODOs match; pass --force to update more than {limit} at once")]
+    BulkStatusRequiresForce { count: usize, limit: usize },
+
+    #[error("TODO(s) {0} have worktrees to merge; complete them with 'track todo done <id>'")]
+    BulkDoneHasWorktrees(String),
+
     #[error(
-        "Workspace was merged (bookmark: {bookmark}) but failed to mark TODO #{todo_index} as done: {detail}"
+        "Workspace was merged (bookmark: {bookmark}) but failed to mark TODO #{todo_index} as done: {detail}. Re-run 'track todo done {todo_index}' to record it"
     )]
     TodoCompletionDbFailed {
         todo_index: i64,
@@ -94,9 +127,15 @@ pub enum TrackError {
     #[error("Bookmark '{0}' already exists")]
     BookmarkExists(String),
 
+    #[error("Invalid branch name '{0}'")]
+    InvalidBranchName(String),
+
     #[error("Invalid URL format: {0}")]
     InvalidUrl(String),
 
+    #[error("Task #{0} has no ticket; link one with `track ticket <id> <url>`")]
+    NoTicketLinked(i64),
+
     #[error("No repositories registered for this task")]
     NoRepositoriesRegistered,
 
@@ -125,6 +164,27 @@ pub enum TrackError {
     #[error("Bookmark '{bookmark}' not found in {repo_path}")]
     BookmarkNotFound { bookmark: String, repo_path: String },
 
+    #[error("No git remote configured for {0}; nothing was pushed")]
+    NoGitRemote(String),
+
+    #[error("Worktree directory {0} still exists after removal; kept its record (use --force to drop it anyway)")]
+    WorktreeDirectoryRemains(String),
+
+    #[error("Worktree #{id} is {status} and only kept as history (`track worktree remove {id} --purge` deletes the record)")]
+    WorktreeRetired { id: i64, status: String },
+
+    #[error("{0} completion candidate(s) are malformed and would break shell completion")]
+    MalformedCompletionCandidates(usize),
+
+    #[error("Editor failed: {0}; nothing was saved")]
+    EditorFailed(String),
+
+    #[error("Workspace setup failed for {0} repository(ies); the others were set up")]
+    WorkspaceSetupIncomplete(usize),
+
+    #[error("Failed to remove {0} worktree(s); the repository was kept")]
+    RepoWorktreeRemovalIncomplete(usize),
+
     #[error("No workspace paths available for this TODO")]
     NoWorkspacePathsAvailable,
 
@@ -152,8 +212,12 @@ pub enum TrackError {
     #[error("No task found with reference '{0}'")]
     TaskReferenceNotFound(String),
 
-    #[error("Alias '{alias}' is already in use by task #{task_id}")]
-    AliasInUse { alias: String, task_id: i64 },
+    #[error("Alias '{alias}' is already in use by task #{task_id} ({task_name}); use --force to move it")]
+    AliasInUse {
+        alias: String,
+        task_id: i64,
+        task_name: String,
+    },
 
     #[error("Invalid alias: {0}")]
     InvalidAlias(String),
@@ -167,9 +231,15 @@ pub enum TrackError {
     #[error("Repository #{0} not found in current task")]
     TaskRepoIndexNotFound(i64),
 
+    #[error("No repository matching '{0}' in current task")]
+    TaskRepoReferenceNotFound(String),
+
     #[error("Link #{0} not found in current task")]
     LinkIndexNotFound(i64),
 
+    #[error("Scrap #{0} not found in current task")]
+    ScrapIndexNotFound(i64),
+
     #[error("Path '{0}' is not a git repository")]
     NotGitRepository(String),
 
@@ -194,7 +264,24 @@ pub enum TrackError {
     #[error("Migration blocked: {detail}")]
     MigrationBlocked { detail: String },
 
-    #[error("Unknown config key '{0}' (supported: vcs-mode)")]
+    #[error("Estimate must be a non-negative number, got {0}")]
+    InvalidEstimate(String),
+
+    #[error("Description is {len} characters; the limit is {max}")]
+    DescriptionTooLong { len: usize, max: usize },
+
+    #[error("HTTP request failed: {0}")]
+    Http(String),
+
+    #[error("Invalid time zone '{0}' (expected local or utc)")]
+    InvalidTimeZone(String),
+
+    #[error("Invalid time format '{0}' (expected a strftime pattern such as %Y-%m-%d %H:%M)")]
+    InvalidTimeFormat(String),
+
+    #[error(
+        "Unknown config key '{0}' (supported: vcs-mode, notify-webhook, time-zone, time-format)"
+    )]
     UnknownConfigKey(String),
 
     #[error("{0}")]
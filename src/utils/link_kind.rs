@@ -0,0 +1,114 @@
+//! Classifies URLs for task links and worktree repository links, and finds
+//! them in free text.
+
+/// Kind of a URL, from its host and path:
+///
+/// - `pr`: GitHub pull requests, GitLab merge requests, Bitbucket pull requests
+/// - `issue`: GitHub/GitLab issues, Jira `browse/KEY-1`, Linear issues
+/// - `ci`: GitHub Actions runs (`/actions/runs/`), GitLab pipelines and jobs
+///   (`/-/pipelines/`, `/-/jobs/`), Bitbucket pipelines (`/pipelines/results/`)
+/// - `repo`: a GitHub repository root (`github.com/owner/repo`)
+/// - `doc`: Google Docs, Notion, Confluence wiki pages
+/// - `link`: anything else
+pub fn determine_link_kind(url: &str) -> &'static str {
+    let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+    else {
+        return "link";
+    };
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let host = host.to_ascii_lowercase();
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let has = |segment: &str| segments.contains(&segment);
+    let has_pair = |first: &str, second: &str| {
+        segments
+            .windows(2)
+            .any(|pair| pair[0] == first && pair[1] == second)
+    };
+
+    if has("pull") || has("merge_requests") || has("pull-requests") {
+        "pr"
+    } else if has("issues") || has("browse") || (host == "linear.app" && has("issue")) {
+        "issue"
+    } else if has_pair("actions", "runs")
+        || has_pair("-", "pipelines")
+        || has_pair("-", "jobs")
+        || has_pair("pipelines", "results")
+    {
+        "ci"
+    } else if host == "docs.google.com" || host.ends_with("notion.so") || has("wiki") {
+        "doc"
+    } else if host == "github.com" && segments.len() == 2 {
+        "repo"
+    } else {
+        "link"
+    }
+}
+
+/// Returns the http(s) URLs in `text`, in order, without duplicates.
+///
+/// Trailing sentence punctuation and closing brackets are not part of the URL,
+/// so `(see https://x.example/a).` yields `https://x.example/a`.
+pub fn extract_urls(text: &str) -> Vec<&str> {
+    let mut urls: Vec<&str> = Vec::new();
+    for word in text.split_whitespace() {
+        let Some(start) = word.find("https://").or_else(|| word.find("http://")) else {
+            continue;
+        };
+        let url = word[start..]
+            .trim_end_matches(['.', ',', ';', ':', '!', '?', ')', ']', '>', '"', '\'']);
+        let has_host = url
+            .split_once("://")
+            .is_some_and(|(_, rest)| !rest.is_empty());
+        if has_host && !urls.contains(&url) {
+            urls.push(url);
+        }
+    }
+    urls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_forge_and_tracker_urls() {
+        let cases = [
+            ("https://github.com/o/r/pull/12", "pr"),
+            ("https://gitlab.com/g/p/-/merge_requests/3/diffs", "pr"),
+            ("https://bitbucket.org/o/r/pull-requests/4", "pr"),
+            ("https://github.com/o/r/issues/7#issuecomment-1", "issue"),
+            ("https://acme.atlassian.net/browse/PROJ-1", "issue"),
+            ("https://linear.app/acme/issue/ENG-5/title", "issue"),
+            ("https://github.com/o/r/actions/runs/99", "ci"),
+            ("https://gitlab.com/g/p/-/pipelines/8", "ci"),
+            ("https://gitlab.com/g/p/-/jobs/42", "ci"),
+            ("https://bitbucket.org/o/r/pipelines/results/5", "ci"),
+            ("https://example.com/careers/jobs/123", "link"),
+            ("https://github.com/o/r/blob/main/docs/actions/x.md", "link"),
+            ("https://docs.google.com/document/d/abc/edit", "doc"),
+            ("https://acme.atlassian.net/wiki/spaces/ENG/pages/1", "doc"),
+            ("https://GitHub.com/o/r", "repo"),
+            ("https://github.com/o/r/tree/main", "link"),
+            ("https://example.com/", "link"),
+            ("not a url", "link"),
+        ];
+        for (url, kind) in cases {
+            assert_eq!(determine_link_kind(url), kind, "{url}");
+        }
+    }
+
+    #[test]
+    fn extracts_urls_from_text() {
+        let text =
+            "Fix crash (see https://github.com/o/r/issues/7), then <http://ci.example/run/1>. \
+                    Again: https://github.com/o/r/issues/7 and https://";
+        assert_eq!(
+            extract_urls(text),
+            vec!["https://github.com/o/r/issues/7", "http://ci.example/run/1"]
+        );
+        assert!(extract_urls("no links here").is_empty());
+    }
+}
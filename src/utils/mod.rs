@@ -3,6 +3,17 @@
 //! This module contains utility functions and types used throughout the application,
 //! including error handling and common helper functions.
 
+pub mod color;
+pub mod command;
+pub mod disk;
+pub mod editor;
 pub mod error;
+pub mod http;
+pub mod link_kind;
+pub mod term;
+pub mod time;
 
+pub use command::CommandExt;
 pub use error::{Result, TrackError};
+pub use link_kind::{determine_link_kind, extract_urls};
+pub use time::{format_timestamp, humanize_since, parse_due_date, parse_since};
@@ -0,0 +1,175 @@
+//! Terminal width detection and column truncation for table output.
+
+use super::CommandExt;
+use std::fs::File;
+use std::io::IsTerminal;
+use std::process::Command;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Narrowest a truncated column gets, so it still shows something readable.
+const MIN_COLUMN_WIDTH: usize = 8;
+
+/// Width of the terminal stdout is attached to, or `None` when it is not a TTY.
+///
+/// `COLUMNS` wins when set; otherwise the terminal is asked via `stty size`.
+pub fn terminal_width() -> Option<usize> {
+    if !std::io::stdout().is_terminal() {
+        return None;
+    }
+    if let Some(columns) = std::env::var("COLUMNS")
+        .ok()
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|&columns| columns > 0)
+    {
+        return Some(columns);
+    }
+
+    let tty = File::open("/dev/tty").ok()?;
+    let output = Command::new("stty")
+        .arg("size")
+        .stdin(tty)
+        .logged_output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .nth(1)?
+        .parse()
+        .ok()
+        .filter(|&columns: &usize| columns > 0)
+}
+
+/// Width a table should fit into: `--width` if given, else the terminal's.
+/// `None` means no truncation (`--no-truncate`, or output is not a terminal).
+pub fn table_width(width: Option<usize>, no_truncate: bool) -> Option<usize> {
+    if no_truncate {
+        return None;
+    }
+    width.or_else(terminal_width)
+}
+
+/// Shortens each line of `text` to at most `max` display columns, marking cut
+/// lines with `…`. Wide characters (CJK, emoji) count as two columns.
+pub fn truncate(text: &str, max: usize) -> String {
+    text.lines()
+        .map(|line| {
+            if line.width() <= max {
+                return line.to_string();
+            }
+            let budget = max.saturating_sub(1);
+            let mut used = 0;
+            let kept: String = line
+                .chars()
+                .take_while(|c| {
+                    used += c.width().unwrap_or(0);
+                    used <= budget
+                })
+                .collect();
+            format!("{kept}…")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Widest line of `text` in display columns.
+pub fn text_width(text: &str) -> usize {
+    text.lines().map(UnicodeWidthStr::width).max().unwrap_or(0)
+}
+
+/// Natural width of a column: its widest cell or its header.
+pub fn column_width<'a>(header: &str, cells: impl IntoIterator<Item = &'a str>) -> usize {
+    cells
+        .into_iter()
+        .map(text_width)
+        .chain([text_width(header)])
+        .max()
+        .unwrap_or(0)
+}
+
+/// Widths for the shrinkable columns of a bordered table so it fits in `width`.
+///
+/// `fixed` are the widths of columns that are never cut and `flexible` the
+/// natural widths of the ones that may be. Space is shared so narrow columns
+/// keep their full width and only the widest ones are cut, never below
+/// [`MIN_COLUMN_WIDTH`].
+pub fn fit_columns(width: usize, fixed: &[usize], flexible: &[usize]) -> Vec<usize> {
+    // Each column is padded by one space per side and followed by a `|`,
+    // plus the leading border.
+    let columns = fixed.len() + flexible.len();
+    let overhead = columns * 3 + 1 + fixed.iter().sum::<usize>();
+    let mut budget = width.saturating_sub(overhead);
+    if flexible.iter().sum::<usize>() <= budget {
+        return flexible.to_vec();
+    }
+
+    let mut sorted = flexible.to_vec();
+    sorted.sort_unstable();
+    let mut cap = 0;
+    for (i, &natural) in sorted.iter().enumerate() {
+        let share = budget / (sorted.len() - i);
+        if natural > share {
+            cap = share;
+            break;
+        }
+        budget -= natural;
+    }
+
+    flexible
+        .iter()
+        .map(|&natural| natural.min(cap.max(MIN_COLUMN_WIDTH)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncate_cuts_long_lines_with_ellipsis() {
+        assert_eq!(truncate("short", 10), "short");
+        assert_eq!(truncate("exactly ten", 11), "exactly ten");
+        assert_eq!(truncate("a long task name", 8), "a long …");
+        assert_eq!(truncate("first line\nok", 6), "first…\nok");
+        assert_eq!(truncate("日本語のタスク名", 4), "日…");
+        assert_eq!(truncate("日本語のタスク名", 5), "日本…");
+    }
+
+    #[test]
+    fn fit_columns_keeps_natural_widths_when_they_fit() {
+        // 4 columns: 13 overhead + 10 fixed leaves 57 for 6 + 20
+        assert_eq!(fit_columns(80, &[4, 6], &[6, 20]), vec![6, 20]);
+    }
+
+    #[test]
+    fn fit_columns_cuts_only_the_widest_column() {
+        // Budget 40 - 13 - 10 = 17: the 6-wide ticket keeps its width
+        assert_eq!(fit_columns(40, &[4, 6], &[6, 50]), vec![6, 11]);
+    }
+
+    #[test]
+    fn fit_columns_shares_space_between_wide_columns() {
+        // Budget 60 - 13 - 10 = 37, split between two long columns
+        assert_eq!(fit_columns(60, &[4, 6], &[30, 50]), vec![18, 18]);
+    }
+
+    #[test]
+    fn fit_columns_never_goes_below_minimum() {
+        assert_eq!(fit_columns(10, &[4, 6], &[30, 50]), vec![8, 8]);
+        assert_eq!(fit_columns(10, &[4], &[3]), vec![3]);
+    }
+
+    #[test]
+    fn column_width_counts_header_and_widest_line() {
+        assert_eq!(column_width("Name", ["ab", "abc"]), 4);
+        assert_eq!(column_width("ID", ["12345", "two\nlines"]), 5);
+        assert_eq!(column_width("Name", ["日本語"]), 6);
+    }
+
+    #[test]
+    fn table_width_prefers_explicit_width_and_honors_no_truncate() {
+        assert_eq!(table_width(Some(50), false), Some(50));
+        assert_eq!(table_width(Some(50), true), None);
+    }
+}
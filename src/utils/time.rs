@@ -0,0 +1,311 @@
+use crate::utils::{Result, TrackError};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone, Utc};
+use std::sync::RwLock;
+
+static TIME_STYLE: RwLock<TimeStyle> = RwLock::new(TimeStyle {
+    utc: false,
+    format: None,
+});
+
+/// How human-readable timestamps are rendered (`time-zone` / `time-format`
+/// config, `TRACK_TZ`). Machine-readable output stays RFC 3339.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimeStyle {
+    /// Render in UTC instead of the local time zone
+    pub utc: bool,
+    /// strftime pattern replacing each call site's default format
+    pub format: Option<String>,
+}
+
+impl TimeStyle {
+    /// Renders `at`, using `default_format` unless a format is configured.
+    pub fn format(&self, at: DateTime<Utc>, default_format: &str) -> String {
+        let pattern = self.format.as_deref().unwrap_or(default_format);
+        if self.utc {
+            at.format(pattern).to_string()
+        } else {
+            at.with_timezone(&Local).format(pattern).to_string()
+        }
+    }
+}
+
+/// Parses a time zone choice: `local` or `utc` (case-insensitive).
+pub fn parse_time_zone(input: &str) -> Result<bool> {
+    match input.trim().to_ascii_lowercase().as_str() {
+        "local" => Ok(false),
+        "utc" => Ok(true),
+        _ => Err(TrackError::InvalidTimeZone(input.to_string())),
+    }
+}
+
+/// Checks that `pattern` is a strftime pattern chrono can render.
+pub fn validate_time_format(pattern: &str) -> Result<()> {
+    if pattern.trim().is_empty() || StrftimeItems::new(pattern).any(|item| item == Item::Error) {
+        return Err(TrackError::InvalidTimeFormat(pattern.to_string()));
+    }
+    Ok(())
+}
+
+/// Sets the style used by [`format_timestamp`] for the rest of the process.
+pub fn set_time_style(style: TimeStyle) {
+    *TIME_STYLE.write().unwrap_or_else(|err| err.into_inner()) = style;
+}
+
+/// Installs a loaded style, warning and falling back to local time when it could
+/// not be read (e.g. an invalid `TRACK_TZ`).
+pub fn apply_time_style(style: Result<TimeStyle>) {
+    match style {
+        Ok(style) => set_time_style(style),
+        Err(err) => {
+            eprintln!("warning: {err}; showing local times");
+            set_time_style(TimeStyle::default());
+        }
+    }
+}
+
+/// Renders `at` for display with the configured time zone and format,
+/// falling back to `default_format` (e.g. `%Y-%m-%d %H:%M`).
+pub fn format_timestamp(at: DateTime<Utc>, default_format: &str) -> String {
+    TIME_STYLE
+        .read()
+        .unwrap_or_else(|err| err.into_inner())
+        .format(at, default_format)
+}
+
+/// Inputs [`parse_since`] accepts, as shown in its error.
+const SINCE_INPUTS: &str = "a span like 3d/12h/2w, YYYY-MM-DD, or an RFC 3339 timestamp";
+
+/// Inputs [`parse_due_date`] accepts, as shown in its error.
+const DUE_DATE_INPUTS: &str = "YYYY-MM-DD, today, tomorrow, or a span like 3d/2w";
+
+/// Parses a non-negative span such as `30m` or `2w` whose unit is one of
+/// `units` (from `m`, `h`, `d`, `w`).
+///
+/// `None` means `input` is not shaped like a span; `Some(None)` means it is
+/// but the unit is not allowed or the amount is out of range.
+fn parse_span(input: &str, units: &[char]) -> Option<Option<Duration>> {
+    let unit = input.chars().last().filter(|c| c.is_ascii_alphabetic())?;
+    let amount = input[..input.len() - 1].parse::<i64>().ok()?;
+    let unit = unit.to_ascii_lowercase();
+    let span = match unit {
+        'm' => Duration::try_minutes(amount),
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => None,
+    };
+    Some(span.filter(|span| units.contains(&unit) && *span >= Duration::zero()))
+}
+
+/// Parses a "since" point in time relative to `now`.
+///
+/// Accepts relative spans (`30m`, `12h`, `3d`, `2w`), local dates
+/// (`2026-01-31`, meaning local midnight), and RFC 3339 timestamps.
+pub fn parse_since(input: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let input = input.trim();
+    let invalid = || TrackError::InvalidDate {
+        input: input.to_string(),
+        accepted: SINCE_INPUTS,
+    };
+
+    if let Some(span) = parse_span(input, &['m', 'h', 'd', 'w']) {
+        return span
+            .and_then(|span| now.checked_sub_signed(span))
+            .ok_or_else(invalid);
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        let midnight = date.and_hms_opt(0, 0, 0).ok_or_else(invalid)?;
+        return Local
+            .from_local_datetime(&midnight)
+            .earliest()
+            .map(|at| at.with_timezone(&Utc))
+            .ok_or_else(invalid);
+    }
+
+    DateTime::parse_from_rfc3339(input)
+        .map(|at| at.with_timezone(&Utc))
+        .map_err(|_| invalid())
+}
+
+/// Parses a due date relative to `today`.
+///
+/// Accepts local dates (`2026-01-31`), `today`, `tomorrow`, and spans
+/// ahead of today in days or weeks (`3d`, `2w`).
+pub fn parse_due_date(input: &str, today: NaiveDate) -> Result<NaiveDate> {
+    let input = input.trim();
+    let invalid = || TrackError::InvalidDate {
+        input: input.to_string(),
+        accepted: DUE_DATE_INPUTS,
+    };
+
+    match input.to_ascii_lowercase().as_str() {
+        "today" => return Ok(today),
+        "tomorrow" => return today.succ_opt().ok_or_else(invalid),
+        _ => {}
+    }
+
+    if let Some(span) = parse_span(input, &['d', 'w']) {
+        return span
+            .and_then(|span| today.checked_add_signed(span))
+            .ok_or_else(invalid);
+    }
+
+    NaiveDate::parse_from_str(input, "%Y-%m-%d").map_err(|_| invalid())
+}
+
+/// Describes `at` relative to `now` for recent times (`just now`, `5m ago`,
+/// `3h ago`, `2d ago`); anything a week or more old, or in the future, falls
+/// back to the absolute `%Y-%m-%d %H:%M` (see [`format_timestamp`]).
+pub fn humanize_since(at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let elapsed = now.signed_duration_since(at);
+    if elapsed < Duration::zero() || elapsed >= Duration::weeks(1) {
+        return format_timestamp(at, "%Y-%m-%d %H:%M");
+    }
+
+    if elapsed < Duration::minutes(1) {
+        "just now".to_string()
+    } else if elapsed < Duration::hours(1) {
+        format!("{}m ago", elapsed.num_minutes())
+    } else if elapsed < Duration::days(1) {
+        format!("{}h ago", elapsed.num_hours())
+    } else {
+        format!("{}d ago", elapsed.num_days())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-03-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn parses_relative_spans() {
+        assert_eq!(parse_since("3d", now()).unwrap(), now() - Duration::days(3));
+        assert_eq!(
+            parse_since("12h", now()).unwrap(),
+            now() - Duration::hours(12)
+        );
+        assert_eq!(
+            parse_since("2w", now()).unwrap(),
+            now() - Duration::weeks(2)
+        );
+        assert_eq!(
+            parse_since("30m", now()).unwrap(),
+            now() - Duration::minutes(30)
+        );
+    }
+
+    #[test]
+    fn parses_absolute_dates() {
+        let expected = Local
+            .with_ymd_and_hms(2026, 3, 1, 0, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(parse_since("2026-03-01", now()).unwrap(), expected);
+        assert_eq!(
+            parse_since("2026-03-01T08:00:00+09:00", now()).unwrap(),
+            DateTime::parse_from_rfc3339("2026-02-28T23:00:00Z").unwrap()
+        );
+    }
+
+    #[test]
+    fn parses_due_dates() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(
+            parse_due_date("2026-04-01", today).unwrap(),
+            date(2026, 4, 1)
+        );
+        assert_eq!(parse_due_date("today", today).unwrap(), today);
+        assert_eq!(
+            parse_due_date("Tomorrow", today).unwrap(),
+            date(2026, 3, 11)
+        );
+        assert_eq!(parse_due_date("3d", today).unwrap(), date(2026, 3, 13));
+        assert_eq!(parse_due_date("2w", today).unwrap(), date(2026, 3, 24));
+        for input in ["", "-1d", "3h", "2026-02-30", "next week"] {
+            assert!(matches!(
+                parse_due_date(input, today),
+                Err(TrackError::InvalidDate { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        for input in ["", "3x", "-1d", "yesterday-ish", "2026-13-01"] {
+            assert!(matches!(
+                parse_since(input, now()),
+                Err(TrackError::InvalidDate { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn humanizes_recent_times() {
+        assert_eq!(
+            humanize_since(now() - Duration::seconds(20), now()),
+            "just now"
+        );
+        assert_eq!(
+            humanize_since(now() - Duration::minutes(5), now()),
+            "5m ago"
+        );
+        assert_eq!(
+            humanize_since(now() - Duration::minutes(150), now()),
+            "2h ago"
+        );
+        assert_eq!(humanize_since(now() - Duration::days(6), now()), "6d ago");
+
+        for at in [now() - Duration::weeks(1), now() + Duration::hours(1)] {
+            assert_eq!(
+                humanize_since(at, now()),
+                format_timestamp(at, "%Y-%m-%d %H:%M")
+            );
+        }
+    }
+
+    #[test]
+    fn time_style_applies_zone_and_format_override() {
+        let utc = TimeStyle {
+            utc: true,
+            format: None,
+        };
+        assert_eq!(utc.format(now(), "%Y-%m-%d %H:%M"), "2026-03-10 12:00");
+
+        let iso = TimeStyle {
+            utc: true,
+            format: Some("%Y-%m-%dT%H:%M:%SZ".to_string()),
+        };
+        assert_eq!(iso.format(now(), "%Y-%m-%d %H:%M"), "2026-03-10T12:00:00Z");
+
+        assert_eq!(
+            TimeStyle::default().format(now(), "%H:%M"),
+            now().with_timezone(&Local).format("%H:%M").to_string()
+        );
+    }
+
+    #[test]
+    fn validates_time_zone_and_format() {
+        assert!(!parse_time_zone("Local").unwrap());
+        assert!(parse_time_zone("UTC").unwrap());
+        assert!(matches!(
+            parse_time_zone("JST"),
+            Err(TrackError::InvalidTimeZone(_))
+        ));
+
+        assert!(validate_time_format("%Y-%m-%d %H:%M:%S %z").is_ok());
+        assert!(matches!(
+            validate_time_format("%Q"),
+            Err(TrackError::InvalidTimeFormat(_))
+        ));
+        assert!(validate_time_format(" ").is_err());
+    }
+}
@@ -0,0 +1,43 @@
+//! Static assets embedded at compile time.
+
+use axum::{
+    extract::Path,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+
+/// A file served under `/static`.
+struct Asset {
+    name: &'static str,
+    content_type: &'static str,
+    body: &'static [u8],
+}
+
+/// Embedded so `track webui` works from any directory and for installed binaries.
+const ASSETS: &[Asset] = &[
+    Asset {
+        name: "favicon.ico",
+        content_type: "image/x-icon",
+        body: include_bytes!("../../static/favicon.ico"),
+    },
+    Asset {
+        name: "track.svg",
+        content_type: "image/svg+xml",
+        body: include_bytes!("../../static/track.svg"),
+    },
+];
+
+/// Serve an embedded asset by file name.
+pub async fn static_asset(Path(name): Path<String>) -> Response {
+    match ASSETS.iter().find(|asset| asset.name == name) {
+        Some(asset) => (
+            [
+                (header::CONTENT_TYPE, asset.content_type),
+                (header::CACHE_CONTROL, "public, max-age=86400"),
+            ],
+            asset.body,
+        )
+            .into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
@@ -24,7 +24,16 @@ impl WebError {
             | TrackError::InvalidStatusTransition { .. }
             | TrackError::TodoReopenForbidden { .. }
             | TrackError::TodoCompleteRequiresDoneCommand
+            | TrackError::BulkStatusRequiresForce { .. }
+            | TrackError::BulkDoneHasWorktrees(_)
             | TrackError::InvalidUrl(_)
+            | TrackError::NoTicketLinked(_)
+            | TrackError::PrDetectionFailed(_)
+            | TrackError::InvalidImport(_)
+            | TrackError::InvalidDate { .. }
+            | TrackError::TodoAlreadyInTask(_)
+            | TrackError::TaskMergeIntoSelf(_)
+            | TrackError::TaskHasWorktrees { .. }
             | TrackError::DuplicateTicket(_, _)
             | TrackError::TaskArchived(_)
             | TrackError::NoRepositoriesRegistered
@@ -35,6 +44,13 @@ impl WebError {
             | TrackError::CurrentDirectoryNotRegistered
             | TrackError::WorkspaceHasUncommittedChanges { .. }
             | TrackError::BookmarkNotFound { .. }
+            | TrackError::NoGitRemote(_)
+            | TrackError::WorktreeDirectoryRemains(_)
+            | TrackError::WorktreeRetired { .. }
+            | TrackError::MalformedCompletionCandidates(_)
+            | TrackError::EditorFailed(_)
+            | TrackError::WorkspaceSetupIncomplete(_)
+            | TrackError::RepoWorktreeRemovalIncomplete(_)
             | TrackError::NoWorkspacePathsAvailable
             | TrackError::TodoIndexNotFound(_)
             | TrackError::TodoNotPending(_)
@@ -42,12 +58,20 @@ impl WebError {
             | TrackError::InvalidAlias(_)
             | TrackError::AliasInUse { .. }
             | TrackError::RepoAlreadyRegistered
+            | TrackError::WorktreeExists(_)
+            | TrackError::BaseWorktreeInUse { .. }
             | TrackError::TaskRepoIndexNotFound(_)
+            | TrackError::TaskRepoReferenceNotFound(_)
             | TrackError::LinkIndexNotFound(_)
+            | TrackError::ScrapIndexNotFound(_)
             | TrackError::TaskReferenceNotFound(_)
             | TrackError::LinkNotFound(_)
             | TrackError::InvalidVcsMode(_)
             | TrackError::UnknownConfigKey(_)
+            | TrackError::InvalidTimeZone(_)
+            | TrackError::InvalidTimeFormat(_)
+            | TrackError::InvalidEstimate(_)
+            | TrackError::DescriptionTooLong { .. }
             | TrackError::InvalidAppStateValue { .. } => StatusCode::BAD_REQUEST,
             TrackError::TaskNotFound(_)
             | TrackError::TodoNotFound(_)
@@ -58,9 +82,11 @@ impl WebError {
             | TrackError::TodoCompletionDbFailed { .. }
             | TrackError::Jj(_)
             | TrackError::Git(_)
+            | TrackError::Http(_)
             | TrackError::NotJjRepository(_)
             | TrackError::NotGitRepository(_)
             | TrackError::BookmarkExists(_)
+            | TrackError::InvalidBranchName(_)
             | TrackError::FailedRepoStatusCheck(_)
             | TrackError::WorkspaceRemovalFailed(_)
             | TrackError::WorkspaceStatusCheckFailed { .. }
@@ -9,6 +9,7 @@
 //! - Add/delete TODOs and scraps through the web interface
 //! - Real-time updates via Server-Sent Events (SSE)
 
+mod assets;
 mod error;
 mod routes;
 mod server;
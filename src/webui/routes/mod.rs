@@ -54,6 +54,13 @@ pub struct UpdateTicketForm {
     pub ticket_url: Option<String>,
 }
 
+/// Form data for setting or clearing the task alias
+#[derive(Deserialize)]
+pub struct UpdateAliasForm {
+    #[serde(default)]
+    pub alias: String,
+}
+
 /// Form data for adding a link
 #[derive(Deserialize)]
 pub struct AddLinkForm {
@@ -103,16 +110,18 @@ pub async fn index(State(state): State<WebState>) -> Result<Html<String>, AppErr
 
 /// JSON API endpoint for status data
 pub async fn api_status(State(state): State<WebState>) -> Result<Json<StatusResponse>, AppError> {
-    let db = state.app.db.lock().await;
-
-    let current_task_id = match db.get_current_task_id()? {
-        Some(id) => id,
-        None => return Ok(Json(StatusResponse::empty())),
-    };
-
-    let info = GetTaskInfoUseCase::new(&db);
-    let snapshot = info.load(current_task_id)?;
-    let response = view::build_api_status(&db, &snapshot)?;
+    let response = state
+        .app
+        .status_response(|db| {
+            let current_task_id = match db.get_current_task_id()? {
+                Some(id) => id,
+                None => return Ok(StatusResponse::empty()),
+            };
+
+            let snapshot = GetTaskInfoUseCase::new(db).load(current_task_id)?;
+            Ok(view::build_api_status(db, &snapshot))
+        })
+        .await?;
 
     Ok(Json(response))
 }
@@ -397,6 +406,38 @@ pub async fn update_ticket(
     Ok(Html(html))
 }
 
+/// Set the task alias, or clear it when the submitted alias is empty
+pub async fn update_alias(
+    State(state): State<WebState>,
+    Form(form): Form<UpdateAliasForm>,
+) -> Result<Html<String>, AppError> {
+    let db = state.app.db.lock().await;
+
+    let current_task_id = db.get_current_task_id()?.ok_or(TrackError::NoActiveTask)?;
+
+    let task_service = TaskService::new(&db);
+    let alias = form.alias.trim();
+    if alias.is_empty() {
+        task_service.remove_alias(current_task_id)?;
+    } else {
+        task_service.set_alias(current_task_id, alias, false)?;
+    }
+
+    let task = task_service.get_task(current_task_id)?;
+
+    // The alias is shown in the header, so refresh it everywhere
+    state.app.broadcast(SseEvent::Header);
+
+    let html = state.templates.render(
+        "partials/alias.html",
+        serde_json::json!({
+            "task": task,
+        }),
+    )?;
+
+    Ok(Html(html))
+}
+
 /// Add a new link
 pub async fn add_link(
     State(state): State<WebState>,
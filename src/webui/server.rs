@@ -1,6 +1,7 @@
 //! WebUI server implementation.
 
 use crate::utils::{Result, TrackError};
+use crate::webui::assets;
 use crate::webui::routes::{self, WebState};
 use crate::webui::sse::sse_handler;
 use crate::webui::state::AppState;
@@ -10,9 +11,9 @@ use axum::{
     Router,
 };
 use std::net::SocketAddr;
+use std::path::Path;
 use std::sync::Arc;
 use tokio::net::TcpListener;
-use tower_http::services::ServeDir;
 
 /// Build the WebUI router (shared by server startup and tests).
 pub fn build_router(web_state: WebState) -> Router {
@@ -37,19 +38,20 @@ pub fn build_router(web_state: WebState) -> Router {
         .route("/api/scrap", post(routes::add_scrap))
         .route("/api/description", post(routes::update_description))
         .route("/api/ticket", post(routes::update_ticket))
+        .route("/api/task/alias", post(routes::update_alias))
         .route("/api/link", post(routes::add_link))
         .route("/api/link/:id", delete(routes::delete_link))
         // SSE endpoint
         .route("/api/sse", get(sse_handler))
-        // Static files (CSS, JS)
-        .nest_service("/static", ServeDir::new("static"))
+        // Static files, embedded in the binary
+        .route("/static/:name", get(assets::static_asset))
         .with_state(web_state)
 }
 
 /// Start the WebUI server
-pub async fn start_server(port: u16, open_browser: bool) -> Result<()> {
+pub async fn start_server(port: u16, open_browser: bool, db_path: Option<&Path>) -> Result<()> {
     // Initialize application state
-    let app_state = AppState::new()?;
+    let app_state = AppState::new(db_path)?;
 
     // Initialize templates (embedded for single-binary distribution)
     let templates = Arc::new(Templates::embedded());
@@ -72,6 +74,9 @@ pub async fn start_server(port: u16, open_browser: bool) -> Result<()> {
 
     println!("Starting track webui server...");
     println!("  → http://localhost:{}", port);
+    if let Some(path) = &app_state.db_path {
+        println!("  → database: {}", path.display());
+    }
     println!();
     println!("Press Ctrl+C to stop the server.");
 
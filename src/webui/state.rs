@@ -2,7 +2,9 @@
 
 use crate::db::{Database, SectionRevs};
 use crate::utils::Result;
-use std::sync::Arc;
+use crate::webui::view::StatusResponse;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex as SyncMutex};
 use std::time::Duration;
 use tokio::sync::{broadcast, Mutex};
 
@@ -35,6 +37,13 @@ struct ChangeState {
     revs: SectionRevs,
 }
 
+/// Last `/api/status` payload together with the change state it was built from
+#[derive(Clone)]
+struct StatusSnapshot {
+    state: ChangeState,
+    response: StatusResponse,
+}
+
 /// Shared argument state
 #[derive(Clone)]
 pub struct AppState {
@@ -44,6 +53,10 @@ pub struct AppState {
     pub sse_tx: broadcast::Sender<SseEvent>,
     /// Last known state for change detection
     last_state: Arc<Mutex<Option<ChangeState>>>,
+    /// Cached `/api/status` payload, reused while the revisions are unchanged
+    status_snapshot: Arc<SyncMutex<Option<StatusSnapshot>>>,
+    /// Database file backing this instance (`None` for in-memory test state)
+    pub db_path: Option<PathBuf>,
 }
 
 impl AppState {
@@ -55,23 +68,34 @@ impl AppState {
             db: Arc::new(Mutex::new(db)),
             sse_tx,
             last_state: Arc::new(Mutex::new(None)),
+            status_snapshot: Arc::new(SyncMutex::new(None)),
+            db_path: None,
         }
     }
 
     /// Create new application state with database connection
-    pub fn new() -> Result<Self> {
-        let db = Database::new()?;
+    ///
+    /// `db_path` follows the CLI `--db` flag; `None` uses `TRACK_DB_PATH` or the default.
+    pub fn new(db_path: Option<&Path>) -> Result<Self> {
+        let db_path = Database::resolve_path(db_path)?;
+        let db = Database::open(&db_path)?;
+        // Read once at startup: a running WebUI does not pick up `config set time-*`.
+        crate::utils::time::apply_time_style(db.get_time_style());
         let (sse_tx, _) = broadcast::channel(100);
 
         Ok(Self {
             db: Arc::new(Mutex::new(db)),
             sse_tx,
             last_state: Arc::new(Mutex::new(None)),
+            status_snapshot: Arc::new(SyncMutex::new(None)),
+            db_path: Some(db_path),
         })
     }
 
     /// Broadcast an SSE event to all connected clients
     pub fn broadcast(&self, event: SseEvent) {
+        // Every write path broadcasts, so the status snapshot can no longer be trusted
+        self.invalidate_status();
         // Ignore send errors (no receivers connected)
         let _ = self.sse_tx.send(event);
     }
@@ -79,13 +103,38 @@ impl AppState {
     /// Get current change state (task ID and all revision numbers)
     async fn get_change_state(&self) -> Result<ChangeState> {
         let db = self.db.lock().await;
-        let current_task_id = db.get_current_task_id()?;
-        let revs = db.get_all_revs()?;
+        read_change_state(&db)
+    }
 
-        Ok(ChangeState {
-            current_task_id,
-            revs,
-        })
+    /// Serve the `/api/status` payload from the in-memory snapshot when the
+    /// change-detection loop has seen no new revisions since it was built;
+    /// otherwise lock the database and rebuild it with `build`.
+    ///
+    /// Before the loop has run once every request goes to the database.
+    pub async fn status_response<F>(&self, build: F) -> Result<StatusResponse>
+    where
+        F: FnOnce(&Database) -> Result<StatusResponse>,
+    {
+        let last = self.last_state.lock().await.clone();
+        if let Some(last) = last {
+            let snapshot = self.status_snapshot.lock().unwrap();
+            if let Some(snapshot) = snapshot.as_ref().filter(|s| s.state == last) {
+                return Ok(snapshot.response.clone());
+            }
+        }
+
+        let db = self.db.lock().await;
+        let state = read_change_state(&db)?;
+        let response = build(&db)?;
+        *self.status_snapshot.lock().unwrap() = Some(StatusSnapshot {
+            state,
+            response: response.clone(),
+        });
+        Ok(response)
+    }
+
+    fn invalidate_status(&self) {
+        *self.status_snapshot.lock().unwrap() = None;
     }
 
     /// Broadcast all section events (used on task switch)
@@ -116,56 +165,68 @@ impl AppState {
 
         loop {
             interval.tick().await;
+            self.poll_changes().await;
+        }
+    }
 
-            // Get current state
-            let current = match self.get_change_state().await {
-                Ok(state) => state,
-                Err(e) => {
-                    eprintln!("Error getting change state: {}", e);
-                    continue;
+    /// Compare the database revisions with the last poll, broadcast events for
+    /// changed sections and record the new state.
+    pub async fn poll_changes(&self) {
+        // Get current state
+        let current = match self.get_change_state().await {
+            Ok(state) => state,
+            Err(e) => {
+                eprintln!("Error getting change state: {}", e);
+                return;
+            }
+        };
+
+        // Compare with last state and broadcast specific events
+        let mut last = self.last_state.lock().await;
+
+        if let Some(ref prev) = *last {
+            // Check if current task changed (task switch or new task)
+            if current.current_task_id != prev.current_task_id {
+                // Task switched - reload all sections
+                self.broadcast_all();
+            } else {
+                // Same task - check for specific rev changes
+                if current.revs.task != prev.revs.task {
+                    // Task metadata changed (description, ticket, or alias)
+                    self.broadcast(SseEvent::Header);
+                    self.broadcast(SseEvent::Description);
+                    self.broadcast(SseEvent::Ticket);
                 }
-            };
 
-            // Compare with last state and broadcast specific events
-            let mut last = self.last_state.lock().await;
+                if current.revs.links != prev.revs.links {
+                    self.broadcast(SseEvent::Links);
+                }
 
-            if let Some(ref prev) = *last {
-                // Check if current task changed (task switch or new task)
-                if current.current_task_id != prev.current_task_id {
-                    // Task switched - reload all sections
-                    self.broadcast_all();
-                } else {
-                    // Same task - check for specific rev changes
-                    if current.revs.task != prev.revs.task {
-                        // Task metadata changed (description, ticket, or alias)
-                        self.broadcast(SseEvent::Header);
-                        self.broadcast(SseEvent::Description);
-                        self.broadcast(SseEvent::Ticket);
-                    }
-
-                    if current.revs.links != prev.revs.links {
-                        self.broadcast(SseEvent::Links);
-                    }
-
-                    // TODOs are affected by both todos and worktrees revisions
-                    if current.revs.todos != prev.revs.todos
-                        || current.revs.worktrees != prev.revs.worktrees
-                    {
-                        self.broadcast(SseEvent::Todos);
-                    }
-
-                    if current.revs.repos != prev.revs.repos {
-                        self.broadcast(SseEvent::Repos);
-                    }
-
-                    if current.revs.scraps != prev.revs.scraps {
-                        self.broadcast(SseEvent::Scraps);
-                    }
+                // TODOs are affected by both todos and worktrees revisions
+                if current.revs.todos != prev.revs.todos
+                    || current.revs.worktrees != prev.revs.worktrees
+                {
+                    self.broadcast(SseEvent::Todos);
+                }
+
+                if current.revs.repos != prev.revs.repos {
+                    self.broadcast(SseEvent::Repos);
                 }
-            }
 
-            // Update last state
-            *last = Some(current);
+                if current.revs.scraps != prev.revs.scraps {
+                    self.broadcast(SseEvent::Scraps);
+                }
+            }
         }
+
+        // Update last state
+        *last = Some(current);
     }
 }
+
+fn read_change_state(db: &Database) -> Result<ChangeState> {
+    Ok(ChangeState {
+        current_task_id: db.get_current_task_id()?,
+        revs: db.get_all_revs()?,
+    })
+}
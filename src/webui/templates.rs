@@ -63,6 +63,11 @@ impl Templates {
             include_str!("../../templates/partials/ticket.html"),
         )
         .expect("Failed to add ticket.html template");
+        env.add_template(
+            "partials/alias.html",
+            include_str!("../../templates/partials/alias.html"),
+        )
+        .expect("Failed to add alias.html template");
         env.add_template(
             "partials/links.html",
             include_str!("../../templates/partials/links.html"),
@@ -2,57 +2,14 @@
 
 use crate::db::Database;
 use crate::models::TodoStatus;
-use crate::models::{
-    AgentGuardrails, GitAgentContext, JjAgentContext, Scrap, Todo, TodoAgentView, VcsMode,
-    WorkflowContext, Worktree,
-};
+use crate::models::{Scrap, Todo, Worktree};
 use crate::services::agent_context::{build_agent_extensions, AgentStatusExtensions};
 use crate::services::WorktreeService;
-use crate::use_cases::{GetTaskInfoUseCase, TaskInfoSnapshot};
+use crate::use_cases::{GetTaskInfoUseCase, StatusDocument, TaskInfoSnapshot};
 use crate::utils::{Result, TrackError};
-use serde::Serialize;
-
-/// JSON API payload for `/api/status`.
-#[derive(Serialize)]
-pub struct StatusResponse {
-    pub task: Option<serde_json::Value>,
-    pub todos: Vec<serde_json::Value>,
-    pub links: Vec<serde_json::Value>,
-    pub scraps: Vec<serde_json::Value>,
-    pub worktrees: Vec<serde_json::Value>,
-    pub repos: Vec<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub workflow: Option<WorkflowContext>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub vcs_mode: Option<VcsMode>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub jj: Option<JjAgentContext>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub git: Option<GitAgentContext>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub todos_agent: Option<Vec<TodoAgentView>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub guardrails: Option<AgentGuardrails>,
-}
 
-impl StatusResponse {
-    pub fn empty() -> Self {
-        Self {
-            task: None,
-            todos: vec![],
-            links: vec![],
-            scraps: vec![],
-            worktrees: vec![],
-            repos: vec![],
-            workflow: None,
-            vcs_mode: None,
-            jj: None,
-            git: None,
-            todos_agent: None,
-            guardrails: None,
-        }
-    }
-}
+/// JSON API payload for `/api/status`: the same document as `track status --json`.
+pub type StatusResponse = StatusDocument;
 
 /// Build Minijinja context for the dashboard and status cards.
 pub fn build_template_context(db: &Database, task_id: i64) -> Result<serde_json::Value> {
@@ -63,40 +20,8 @@ pub fn build_template_context(db: &Database, task_id: i64) -> Result<serde_json:
 }
 
 /// Build `/api/status` response for the active task.
-pub fn build_api_status(db: &Database, snapshot: &TaskInfoSnapshot) -> Result<StatusResponse> {
-    let agent = agent_extensions(db, snapshot)?;
-    let todos = format_todos(&snapshot.todos, &snapshot.worktrees, &snapshot.scraps)?;
-
-    Ok(StatusResponse {
-        task: Some(to_json(&snapshot.task)?),
-        todos,
-        links: snapshot
-            .links
-            .iter()
-            .map(to_json)
-            .collect::<Result<Vec<_>>>()?,
-        scraps: snapshot
-            .scraps
-            .iter()
-            .map(to_json)
-            .collect::<Result<Vec<_>>>()?,
-        worktrees: snapshot
-            .worktrees
-            .iter()
-            .map(to_json)
-            .collect::<Result<Vec<_>>>()?,
-        repos: snapshot
-            .repos
-            .iter()
-            .map(to_json)
-            .collect::<Result<Vec<_>>>()?,
-        workflow: Some(agent.workflow),
-        vcs_mode: Some(agent.vcs_mode),
-        jj: agent.jj,
-        git: agent.git,
-        todos_agent: Some(agent.todos_agent),
-        guardrails: Some(agent.guardrails),
-    })
+pub fn build_api_status(db: &Database, snapshot: &TaskInfoSnapshot) -> StatusResponse {
+    GetTaskInfoUseCase::new(db).status_document(snapshot)
 }
 
 fn to_template_context(
@@ -202,25 +127,11 @@ pub fn format_todos(
 
 /// Format scraps with human-readable timestamps for templates.
 pub fn format_scraps(scraps: &[Scrap]) -> Vec<serde_json::Value> {
-    use chrono::Local;
+    use crate::utils::format_timestamp;
 
     scraps
         .iter()
-        .map(|scrap| {
-            let formatted_time = scrap
-                .created_at
-                .with_timezone(&Local)
-                .format("%Y-%m-%d %H:%M:%S")
-                .to_string();
-
-            serde_json::json!({
-                "scrap_id": scrap.scrap_id,
-                "content": scrap.content,
-                "content_html": scrap.content_html(),
-                "created_at": formatted_time,
-                "active_todo_id": scrap.active_todo_id,
-            })
-        })
+        .map(|scrap| scrap.card_json(|at| format_timestamp(at, "%Y-%m-%d %H:%M:%S")))
         .collect()
 }
 
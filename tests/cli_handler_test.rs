@@ -2,9 +2,13 @@ mod common;
 
 use common::jj::{self, JjWorkspace};
 use track::cli::handler::CommandHandler;
-use track::cli::{Commands, LinkCommands, RepoCommands, ScrapCommands, TodoCommands};
+use track::cli::{
+    Commands, CompletionCommands, CompletionShell, CompletionType, LinkCommands, RepoCommands,
+    ScrapCommands, TaskCommands, TaskSetCommands, TodoCommands, TodoState, Toggle,
+    WorktreeCommands,
+};
 use track::db::Database;
-use track::models::{TaskStatus, TodoStatus};
+use track::models::{TaskListFilter, TaskStatus, TodoStatus, WorktreeStatus};
 use track::services::{
     LinkService, RepoService, ScrapService, TaskService, TodoService, WorktreeService,
 };
@@ -25,6 +29,9 @@ fn test_handle_new_creates_and_switches_task() {
         ticket: None,
         ticket_url: None,
         template: None,
+        vars: vec![],
+        switch_if_exists: false,
+        no_switch: false,
     };
 
     handler.handle(cmd).unwrap();
@@ -41,6 +48,112 @@ fn test_handle_new_creates_and_switches_task() {
     assert_eq!(task.description.as_deref(), Some("Desc"));
 }
 
+#[test]
+fn test_handle_new_template_fills_vars() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let template = TaskService::new(db)
+        .create_task("Release template", None, None, None)
+        .unwrap();
+    let todo_service = TodoService::new(db);
+    todo_service
+        .add_todo(template.id, "Bump to {{version}}", false)
+        .unwrap();
+    todo_service
+        .add_todo(template.id, "Announce {{version}} in {{channel}}", false)
+        .unwrap();
+
+    handler
+        .handle(Commands::New {
+            name: "Release 1.2".to_string(),
+            description: None,
+            ticket: None,
+            ticket_url: None,
+            template: Some(template.id.to_string()),
+            vars: vec!["version=1.2".parse().unwrap()],
+            switch_if_exists: false,
+            no_switch: false,
+        })
+        .unwrap();
+
+    let task_id = db.get_current_task_id().unwrap().unwrap();
+    let contents: Vec<String> = todo_service
+        .list_todos(task_id)
+        .unwrap()
+        .into_iter()
+        .map(|todo| todo.content)
+        .collect();
+    assert_eq!(contents, vec!["Bump to 1.2", "Announce 1.2 in {{channel}}"]);
+}
+
+#[test]
+fn test_handle_new_switch_if_exists_reuses_ticket_task() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let task_service = TaskService::new(handler.get_db());
+    let existing = task_service
+        .create_task("Existing", None, Some("PROJ-1"), None)
+        .unwrap();
+    task_service.create_task("Other", None, None, None).unwrap();
+
+    let new_cmd = |switch_if_exists| Commands::New {
+        name: "X".to_string(),
+        description: None,
+        ticket: Some("PROJ-1".to_string()),
+        ticket_url: None,
+        template: None,
+        vars: vec![],
+        switch_if_exists,
+        no_switch: false,
+    };
+
+    let result = handler.handle(new_cmd(false));
+    assert!(matches!(result, Err(TrackError::DuplicateTicket(_, _))));
+
+    handler.handle(new_cmd(true)).unwrap();
+    assert_eq!(
+        handler.get_db().get_current_task_id().unwrap(),
+        Some(existing.id)
+    );
+    assert_eq!(
+        task_service.list_tasks(TaskListFilter::All).unwrap().len(),
+        2
+    );
+}
+
+#[test]
+fn test_handle_new_no_switch_keeps_current_task() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let task_service = TaskService::new(handler.get_db());
+    let current = task_service
+        .create_task("Current", None, None, None)
+        .unwrap();
+
+    handler
+        .handle(Commands::New {
+            name: "Backlog".to_string(),
+            description: None,
+            ticket: Some("PROJ-2".to_string()),
+            ticket_url: None,
+            template: None,
+            vars: vec![],
+            switch_if_exists: false,
+            no_switch: true,
+        })
+        .unwrap();
+
+    assert_eq!(
+        handler.get_db().get_current_task_id().unwrap(),
+        Some(current.id)
+    );
+    let tasks = task_service.list_tasks(TaskListFilter::All).unwrap();
+    assert!(tasks
+        .iter()
+        .any(|task| task.id != current.id && task.ticket_id.as_deref() == Some("PROJ-2")));
+}
+
 #[test]
 fn test_handle_switch_changes_task() {
     let db = Database::new_in_memory().unwrap();
@@ -54,13 +167,33 @@ fn test_handle_switch_changes_task() {
     assert_eq!(db.get_current_task_id().unwrap(), Some(t2.id));
 
     let cmd = Commands::Switch {
-        task_ref: t1.id.to_string(),
+        task_ref: Some(t1.id.to_string()),
     };
     handler.handle(cmd).unwrap();
 
     assert_eq!(db.get_current_task_id().unwrap(), Some(t1.id));
 }
 
+#[test]
+fn test_handle_switch_without_ref_in_non_tty_keeps_current_task() {
+    use std::io::IsTerminal;
+    if std::io::stdin().is_terminal() {
+        return;
+    }
+
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task_service = TaskService::new(db);
+
+    task_service.create_task("T1", None, None, None).unwrap();
+    let t2 = task_service.create_task("T2", None, None, None).unwrap();
+
+    handler.handle(Commands::Switch { task_ref: None }).unwrap();
+
+    assert_eq!(db.get_current_task_id().unwrap(), Some(t2.id));
+}
+
 #[test]
 fn test_handle_todo_add_and_update() {
     let db = Database::new_in_memory().unwrap();
@@ -75,6 +208,14 @@ fn test_handle_todo_add_and_update() {
         text: "My Todo".to_string(),
         worktree: false,
         no_workspace: false,
+        workspace: false,
+        top: false,
+        after_current: false,
+        links: vec![],
+        estimate: None,
+        due: None,
+        auto_link: false,
+        no_auto_link: false,
     });
     handler.handle(cmd).unwrap();
 
@@ -83,13 +224,69 @@ fn test_handle_todo_add_and_update() {
     assert_eq!(todos[0].content, "My Todo");
     assert_eq!(todos[0].status, TodoStatus::Pending);
 
-    let cmd = Commands::Todo(TodoCommands::Done { id: 1 });
+    let cmd = Commands::Todo(TodoCommands::Done {
+        id: 1,
+        commit: None,
+        close_issue: false,
+        push: false,
+        note: None,
+    });
     handler.handle(cmd).unwrap();
 
     let todo = todo_service.get_todo(todos[0].id).unwrap();
     assert_eq!(todo.status, TodoStatus::Done);
 }
 
+#[test]
+fn test_handle_todo_done_close_issue_without_forge_ticket_is_best_effort() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Jira task", None, Some("PROJ-7"), None)
+        .unwrap();
+    let todo_service = TodoService::new(db);
+    let todo = todo_service.add_todo(task.id, "Finish", false).unwrap();
+
+    handler
+        .handle(Commands::Todo(TodoCommands::Done {
+            id: todo.task_index,
+            commit: None,
+            close_issue: true,
+            push: false,
+            note: None,
+        }))
+        .unwrap();
+
+    let todo = todo_service.get_todo(todo.id).unwrap();
+    assert_eq!(todo.status, TodoStatus::Done);
+}
+
+#[test]
+fn test_handle_todo_done_push_without_worktree_is_best_effort() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
+    let todo_service = TodoService::new(db);
+    let todo = todo_service.add_todo(task.id, "Finish", false).unwrap();
+
+    handler
+        .handle(Commands::Todo(TodoCommands::Done {
+            id: todo.task_index,
+            commit: None,
+            close_issue: false,
+            push: true,
+            note: None,
+        }))
+        .unwrap();
+
+    let todo = todo_service.get_todo(todo.id).unwrap();
+    assert_eq!(todo.status, TodoStatus::Done);
+}
+
 #[test]
 fn test_handle_todo_add_rejects_worktree_flag() {
     let db = Database::new_in_memory().unwrap();
@@ -101,11 +298,298 @@ fn test_handle_todo_add_rejects_worktree_flag() {
         text: "Legacy".to_string(),
         worktree: true,
         no_workspace: false,
+        workspace: false,
+        top: false,
+        after_current: false,
+        links: vec![],
+        estimate: None,
+        due: None,
+        auto_link: false,
+        no_auto_link: false,
     });
     let result = handler.handle(cmd);
     assert!(matches!(result, Err(TrackError::WorktreeFlagRemoved)));
 }
 
+#[test]
+fn test_handle_todo_add_with_links() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
+
+    let add = |links: Vec<&str>| {
+        handler.handle(Commands::Todo(TodoCommands::Add {
+            text: "Fix issue".to_string(),
+            worktree: false,
+            no_workspace: false,
+            workspace: false,
+            top: false,
+            after_current: false,
+            links: links.into_iter().map(String::from).collect(),
+            estimate: None,
+            due: None,
+            auto_link: false,
+            no_auto_link: false,
+        }))
+    };
+
+    // An invalid URL rejects the whole command before the TODO is created.
+    let result = add(vec!["https://example.com/ok", "not-a-url"]);
+    assert!(matches!(result, Err(TrackError::InvalidUrl(_))));
+    assert!(TodoService::new(db).list_todos(task.id).unwrap().is_empty());
+
+    add(vec![
+        "https://github.com/o/r/issues/1",
+        "https://github.com/o/r/issues/2",
+    ])
+    .unwrap();
+    assert_eq!(TodoService::new(db).list_todos(task.id).unwrap().len(), 1);
+    let links = LinkService::new(db).list_links(task.id).unwrap();
+    let urls: Vec<&str> = links.iter().map(|l| l.url.as_str()).collect();
+    assert_eq!(
+        urls,
+        vec![
+            "https://github.com/o/r/issues/1",
+            "https://github.com/o/r/issues/2"
+        ]
+    );
+}
+
+#[test]
+fn test_handle_todo_add_auto_link_skips_existing_links() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
+    LinkService::new(db)
+        .add_link(task.id, "https://github.com/o/r/issues/1", None)
+        .unwrap();
+    handler
+        .handle(Commands::Task(TaskCommands::Set(
+            TaskSetCommands::AutoLink {
+                value: Toggle::On,
+                task: None,
+            },
+        )))
+        .unwrap();
+
+    let add = |text: &str, no_auto_link: bool| {
+        handler.handle(Commands::Todo(TodoCommands::Add {
+            text: text.to_string(),
+            worktree: false,
+            no_workspace: false,
+            workspace: false,
+            top: false,
+            after_current: false,
+            links: vec![],
+            estimate: None,
+            due: None,
+            auto_link: false,
+            no_auto_link,
+        }))
+    };
+    add(
+        "Fix https://github.com/o/r/issues/1 (dup of https://github.com/o/r/issues/2).",
+        false,
+    )
+    .unwrap();
+    add("Later: https://github.com/o/r/issues/3", true).unwrap();
+
+    let links = LinkService::new(db).list_links(task.id).unwrap();
+    let urls: Vec<&str> = links.iter().map(|l| l.url.as_str()).collect();
+    assert_eq!(
+        urls,
+        vec![
+            "https://github.com/o/r/issues/1",
+            "https://github.com/o/r/issues/2"
+        ]
+    );
+}
+
+#[test]
+fn test_todo_add_workspace_flags_conflict() {
+    use clap::Parser;
+
+    let result = track::cli::Cli::try_parse_from([
+        "track",
+        "todo",
+        "add",
+        "Item",
+        "--workspace",
+        "--no-workspace",
+    ]);
+    assert_eq!(
+        result.err().map(|e| e.kind()),
+        Some(clap::error::ErrorKind::ArgumentConflict)
+    );
+}
+
+#[test]
+fn test_todo_list_rejects_zero_limit() {
+    use clap::Parser;
+
+    let result = track::cli::Cli::try_parse_from(["track", "todo", "list", "--limit", "0"]);
+    assert_eq!(
+        result.err().map(|err| err.kind()),
+        Some(clap::error::ErrorKind::ValueValidation)
+    );
+    assert!(track::cli::Cli::try_parse_from(["track", "todo", "list", "--limit", "1"]).is_ok());
+}
+
+#[test]
+fn test_info_is_a_hidden_alias_for_status() {
+    use clap::{CommandFactory, Parser};
+
+    let cli = track::cli::Cli::try_parse_from(["track", "info", "--json", "--all"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Commands::Status {
+            json: true,
+            all: true,
+            ..
+        }
+    ));
+
+    let help = track::cli::Cli::command().render_help().to_string();
+    assert!(!help
+        .lines()
+        .any(|line| line.trim_start().starts_with("info")));
+}
+
+#[test]
+fn test_completion_test_parses_next_to_shell_argument() {
+    use clap::Parser;
+
+    let cli = track::cli::Cli::try_parse_from(["track", "completion", "test", "todos"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Commands::Completion {
+            shell: None,
+            command: Some(CompletionCommands::Test {
+                completion_type: CompletionType::Todos
+            }),
+            ..
+        }
+    ));
+
+    let cli = track::cli::Cli::try_parse_from(["track", "completion", "bash", "-d"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Commands::Completion {
+            shell: Some(CompletionShell::Bash),
+            dynamic: true,
+            command: None,
+        }
+    ));
+    assert!(track::cli::Cli::try_parse_from(["track", "completion"]).is_err());
+}
+
+#[test]
+fn test_handle_completion_test_flags_multiline_candidates() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let task = TaskService::new(handler.get_db())
+        .create_task("Task", None, None, None)
+        .unwrap();
+    let todo_service = TodoService::new(handler.get_db());
+    todo_service.add_todo(task.id, "Fine", false).unwrap();
+
+    let test_todos = || {
+        handler.handle(Commands::Completion {
+            shell: None,
+            dynamic: false,
+            command: Some(CompletionCommands::Test {
+                completion_type: CompletionType::Todos,
+            }),
+        })
+    };
+    test_todos().unwrap();
+
+    todo_service
+        .add_todo(task.id, "Broken\nacross lines", false)
+        .unwrap();
+    assert!(matches!(
+        test_todos(),
+        Err(TrackError::MalformedCompletionCandidates(1))
+    ));
+}
+
+#[test]
+fn test_handle_task_move_todo() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let task_service = TaskService::new(handler.get_db());
+    let todo_service = TodoService::new(handler.get_db());
+
+    let dest = task_service.create_task("Dest", None, None, None).unwrap();
+    let source = task_service
+        .create_task("Source", None, None, None)
+        .unwrap();
+    todo_service
+        .add_todo(source.id, "Wrong task", false)
+        .unwrap();
+
+    let move_todo = |to_task: String| {
+        Commands::Task(TaskCommands::MoveTodo {
+            id: 1,
+            to_task,
+            keep_worktree: false,
+        })
+    };
+
+    let result = handler.handle(move_todo(source.id.to_string()));
+    assert!(matches!(result, Err(TrackError::TodoAlreadyInTask(_))));
+
+    handler.handle(move_todo(dest.id.to_string())).unwrap();
+    assert!(todo_service.list_todos(source.id).unwrap().is_empty());
+    let moved = todo_service.list_todos(dest.id).unwrap();
+    assert_eq!(moved.len(), 1);
+    assert_eq!(moved[0].content, "Wrong task");
+}
+
+#[test]
+fn test_handle_task_merge_archives_source() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let task_service = TaskService::new(handler.get_db());
+    let todo_service = TodoService::new(handler.get_db());
+
+    let dest = task_service.create_task("Dest", None, None, None).unwrap();
+    todo_service.add_todo(dest.id, "Keep", false).unwrap();
+    let source = task_service
+        .create_task("Duplicate", None, None, None)
+        .unwrap();
+    todo_service.add_todo(source.id, "Bring", false).unwrap();
+
+    handler
+        .handle(Commands::Task(TaskCommands::Merge {
+            source_ref: source.id.to_string(),
+            into: dest.id.to_string(),
+            delete: false,
+        }))
+        .unwrap();
+
+    let todos = todo_service.list_todos(dest.id).unwrap();
+    let indexed: Vec<(i64, &str)> = todos
+        .iter()
+        .map(|todo| (todo.task_index, todo.content.as_str()))
+        .collect();
+    assert_eq!(indexed, vec![(1, "Keep"), (2, "Bring")]);
+    assert_eq!(
+        task_service.get_task(source.id).unwrap().status,
+        TaskStatus::Archived
+    );
+    assert_eq!(
+        handler.get_db().get_current_task_id().unwrap(),
+        Some(dest.id)
+    );
+}
+
 #[test]
 fn test_handle_todo_update_done_is_rejected() {
     let db = Database::new_in_memory().unwrap();
@@ -120,6 +604,14 @@ fn test_handle_todo_update_done_is_rejected() {
         text: "My Todo".to_string(),
         worktree: false,
         no_workspace: false,
+        workspace: false,
+        top: false,
+        after_current: false,
+        links: vec![],
+        estimate: None,
+        due: None,
+        auto_link: false,
+        no_auto_link: false,
     });
     handler.handle(cmd).unwrap();
 
@@ -134,81 +626,583 @@ fn test_handle_todo_update_done_is_rejected() {
         Err(track::utils::TrackError::TodoCompleteRequiresDoneCommand)
     ));
 
-    let todos = todo_service.list_todos(1).unwrap();
-    assert_eq!(todos[0].status, TodoStatus::Pending);
+    let todos = todo_service.list_todos(1).unwrap();
+    assert_eq!(todos[0].status, TodoStatus::Pending);
+}
+
+#[test]
+fn test_handle_todo_set_status_bulk() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
+    let todo_service = TodoService::new(db);
+    for i in 1..=6 {
+        todo_service
+            .add_todo(task.id, &format!("Cleanup step {i}"), false)
+            .unwrap();
+    }
+    todo_service.add_todo(task.id, "Release", false).unwrap();
+
+    let set_status = |matching: Option<&str>, force| {
+        handler.handle(Commands::Todo(TodoCommands::SetStatus {
+            status: TodoState::Cancelled,
+            matching: matching.map(str::to_string),
+            all_pending: matching.is_none(),
+            force,
+        }))
+    };
+
+    let result = set_status(Some("cleanup"), false);
+    assert!(matches!(
+        result,
+        Err(TrackError::BulkStatusRequiresForce { count: 6, limit: 5 })
+    ));
+    assert_eq!(
+        todo_service
+            .find_pending_todos(task.id, None)
+            .unwrap()
+            .len(),
+        7
+    );
+
+    set_status(Some("cleanup"), true).unwrap();
+    let pending = todo_service.find_pending_todos(task.id, None).unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].content, "Release");
+
+    set_status(None, false).unwrap();
+    assert!(todo_service
+        .list_todos(task.id)
+        .unwrap()
+        .iter()
+        .all(|todo| todo.status == TodoStatus::Cancelled));
+}
+
+#[test]
+fn test_handle_todo_set_status_done_skips_worktree_merges() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
+    let todo_service = TodoService::new(db);
+    todo_service.add_todo(task.id, "Plain", false).unwrap();
+    let with_worktree = todo_service
+        .add_todo(task.id, "Branch work", false)
+        .unwrap();
+    db.get_connection()
+        .execute(
+            "INSERT INTO worktrees (task_id, path, branch, status, created_at, todo_id, is_base) VALUES (?1, '/tmp/wt', 'b', 'active', '2026-01-01T00:00:00Z', ?2, 0)",
+            [task.id, with_worktree.id],
+        )
+        .unwrap();
+
+    let result = handler.handle(Commands::Todo(TodoCommands::SetStatus {
+        status: TodoState::Done,
+        matching: None,
+        all_pending: true,
+        force: false,
+    }));
+    assert!(matches!(result, Err(TrackError::BulkDoneHasWorktrees(ids)) if ids == "#2"));
+    assert_eq!(
+        todo_service
+            .find_pending_todos(task.id, None)
+            .unwrap()
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_handle_todo_due_dates() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
+    let todo_service = TodoService::new(db);
+
+    let cmd = Commands::Todo(TodoCommands::Add {
+        text: "Ship it".to_string(),
+        worktree: false,
+        no_workspace: false,
+        workspace: false,
+        top: false,
+        after_current: false,
+        links: vec![],
+        estimate: None,
+        due: Some("2026-01-15".to_string()),
+        auto_link: false,
+        no_auto_link: false,
+    });
+    handler.handle(cmd).unwrap();
+    let todo = todo_service.get_todo_by_index(task.id, 1).unwrap();
+    assert_eq!(
+        todo.due_date.map(|due| due.to_string()).as_deref(),
+        Some("2026-01-15")
+    );
+
+    handler
+        .handle(Commands::Todo(TodoCommands::Due {
+            id: 1,
+            date: Some("2026-02-01".to_string()),
+            clear: false,
+        }))
+        .unwrap();
+    let todo = todo_service.get_todo(todo.id).unwrap();
+    assert_eq!(
+        todo.due_date.map(|due| due.to_string()).as_deref(),
+        Some("2026-02-01")
+    );
+
+    let result = handler.handle(Commands::Todo(TodoCommands::Due {
+        id: 1,
+        date: Some("someday".to_string()),
+        clear: false,
+    }));
+    assert!(matches!(result, Err(TrackError::InvalidDate { .. })));
+
+    handler
+        .handle(Commands::Todo(TodoCommands::Due {
+            id: 1,
+            date: None,
+            clear: true,
+        }))
+        .unwrap();
+    assert_eq!(todo_service.get_todo(todo.id).unwrap().due_date, None);
+}
+
+#[test]
+fn test_handle_todo_done_does_not_touch_other_task() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task_service = TaskService::new(db);
+    let todo_service = TodoService::new(db);
+
+    let other = task_service.create_task("Other", None, None, None).unwrap();
+    todo_service.add_todo(other.id, "Other 1", false).unwrap();
+    let other_second = todo_service.add_todo(other.id, "Other 2", false).unwrap();
+
+    let current = task_service
+        .create_task("Current", None, None, None)
+        .unwrap();
+    todo_service.add_todo(current.id, "Mine", false).unwrap();
+
+    // Global id of the other task's TODO must not resolve in the current task.
+    let cmd = Commands::Todo(TodoCommands::Done {
+        id: other_second.id,
+        commit: None,
+        close_issue: false,
+        push: false,
+        note: None,
+    });
+    let result = handler.handle(cmd);
+    assert!(matches!(result, Err(TrackError::TodoIndexNotFound(id)) if id == other_second.id));
+
+    let todo = todo_service.get_todo(other_second.id).unwrap();
+    assert_eq!(todo.status, TodoStatus::Pending);
+}
+
+#[test]
+fn test_handle_todo_commands_use_task_scoped_index() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task_service = TaskService::new(db);
+    let todo_service = TodoService::new(db);
+
+    let other = task_service.create_task("Other", None, None, None).unwrap();
+    todo_service.add_todo(other.id, "Other 1", false).unwrap();
+    todo_service.add_todo(other.id, "Other 2", false).unwrap();
+
+    let current = task_service
+        .create_task("Current", None, None, None)
+        .unwrap();
+    let first = todo_service.add_todo(current.id, "First", false).unwrap();
+    let second = todo_service.add_todo(current.id, "Second", false).unwrap();
+    assert_ne!(first.id, first.task_index);
+
+    handler
+        .handle(Commands::Todo(TodoCommands::Update {
+            id: 1,
+            status: "cancelled".to_string(),
+        }))
+        .unwrap();
+    handler
+        .handle(Commands::Todo(TodoCommands::Delete { id: 2, force: true }))
+        .unwrap();
+
+    assert_eq!(
+        todo_service.get_todo(first.id).unwrap().status,
+        TodoStatus::Cancelled
+    );
+    assert!(todo_service.get_todo(second.id).is_err());
+
+    let other_todos = todo_service.list_todos(other.id).unwrap();
+    assert_eq!(other_todos.len(), 2);
+    assert!(other_todos
+        .iter()
+        .all(|todo| todo.status == TodoStatus::Pending));
+}
+
+#[test]
+fn test_handle_link_add() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task_service = TaskService::new(db);
+    let link_service = LinkService::new(db);
+
+    let task = task_service.create_task("Task", None, None, None).unwrap();
+
+    let cmd = Commands::Link(LinkCommands::Add {
+        url: "http://example.com".to_string(),
+        title: Some("Example".to_string()),
+    });
+    handler.handle(cmd).unwrap();
+
+    let links = link_service.list_links(task.id).unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].url, "http://example.com");
+}
+
+#[test]
+fn test_handle_link_open_all_dry_run_skips_invalid_urls() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
+    LinkService::new(db)
+        .add_link(task.id, "https://example.com", None)
+        .unwrap();
+    // Legacy rows can predate URL validation
+    db.get_connection()
+        .execute(
+            "INSERT INTO links (task_id, task_index, url, title, created_at) VALUES (?1, 2, 'notes.txt', 'notes', '2026-01-01T00:00:00+00:00')",
+            [task.id],
+        )
+        .unwrap();
+
+    // --dry-run never launches a browser, so this is safe in CI
+    handler
+        .handle(Commands::Link(LinkCommands::OpenAll { dry_run: true }))
+        .unwrap();
+}
+
+#[test]
+fn test_handle_scrap_add() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task_service = TaskService::new(db);
+    let scrap_service = ScrapService::new(db);
+
+    let task = task_service.create_task("Task", None, None, None).unwrap();
+
+    let cmd = Commands::Scrap(ScrapCommands::Add {
+        content: "My Note".to_string(),
+        pin: false,
+    });
+    handler.handle(cmd).unwrap();
+
+    let scraps = scrap_service.list_scraps(task.id).unwrap();
+    assert_eq!(scraps.len(), 1);
+    assert_eq!(scraps[0].content, "My Note");
+}
+
+#[test]
+fn test_handle_scrap_pin_lists_pinned_first() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
+
+    for (content, pin) in [
+        ("Log 1", false),
+        ("Creds are in 1Password", true),
+        ("Log 2", false),
+    ] {
+        handler
+            .handle(Commands::Scrap(ScrapCommands::Add {
+                content: content.to_string(),
+                pin,
+            }))
+            .unwrap();
+    }
+    handler
+        .handle(Commands::Scrap(ScrapCommands::Pin { index: 3 }))
+        .unwrap();
+    handler
+        .handle(Commands::Scrap(ScrapCommands::Unpin { index: 2 }))
+        .unwrap();
+
+    let scraps = ScrapService::new(db).list_scraps(task.id).unwrap();
+    let order: Vec<(&str, bool)> = scraps
+        .iter()
+        .map(|scrap| (scrap.content.as_str(), scrap.pinned))
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            ("Log 2", true),
+            ("Log 1", false),
+            ("Creds are in 1Password", false)
+        ]
+    );
+
+    let result = handler.handle(Commands::Scrap(ScrapCommands::Pin { index: 9 }));
+    assert!(matches!(result, Err(TrackError::ScrapIndexNotFound(9))));
+}
+
+#[test]
+fn test_handle_repo_add_remove() {
+    let Some(ws) = JjWorkspace::new() else {
+        return;
+    };
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task_service = TaskService::new(db);
+    let repo_service = RepoService::new(db);
+
+    let task = task_service.create_task("Task", None, None, None).unwrap();
+
+    let repo_path = ws.repo_path_string();
+
+    let cmd = Commands::Repo(RepoCommands::Add {
+        path: Some(repo_path.clone()),
+        base: None,
+        base_commit: None,
+    });
+    handler.handle(cmd).unwrap();
+
+    let repos = repo_service.list_repos(task.id).unwrap();
+    assert_eq!(repos.len(), 1);
+    assert_eq!(repos[0].repo_path, repo_path);
+
+    let cmd = Commands::Repo(RepoCommands::Remove {
+        repo_ref: repos[0].task_index.to_string(),
+        worktrees: false,
+        force: false,
+    });
+    handler.handle(cmd).unwrap();
+
+    let repos = repo_service.list_repos(task.id).unwrap();
+    assert_eq!(repos.len(), 0);
 }
 
 #[test]
-fn test_handle_link_add() {
+fn test_handle_repo_remove_with_worktrees() {
     let db = Database::new_in_memory().unwrap();
     let handler = CommandHandler::from_db(db);
     let db = handler.get_db();
-    let task_service = TaskService::new(db);
-    let link_service = LinkService::new(db);
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
+    let repo_service = RepoService::new(db);
+    let worktree_service = WorktreeService::new(db);
 
-    let task = task_service.create_task("Task", None, None, None).unwrap();
+    let kept = tempfile::tempdir().unwrap();
+    let removed = tempfile::tempdir().unwrap();
+    for dir in [&kept, &removed] {
+        std::fs::create_dir(dir.path().join(".jj")).unwrap();
+        let repo = repo_service
+            .add_repo(task.id, &dir.path().to_string_lossy(), None, None)
+            .unwrap();
+        worktree_service
+            .register_base_worktree(task.id, &repo.repo_path, "task/task-1")
+            .unwrap();
+    }
+    assert!(kept.path().exists() && removed.path().exists());
 
-    let cmd = Commands::Link(LinkCommands::Add {
-        url: "http://example.com".to_string(),
-        title: Some("Example".to_string()),
-    });
-    handler.handle(cmd).unwrap();
+    handler
+        .handle(Commands::Repo(RepoCommands::Remove {
+            repo_ref: "1".to_string(),
+            worktrees: false,
+            force: false,
+        }))
+        .unwrap();
+    assert_eq!(worktree_service.list_worktrees(task.id).unwrap().len(), 2);
 
-    let links = link_service.list_links(task.id).unwrap();
-    assert_eq!(links.len(), 1);
-    assert_eq!(links[0].url, "http://example.com");
+    handler
+        .handle(Commands::Repo(RepoCommands::Remove {
+            repo_ref: "2".to_string(),
+            worktrees: true,
+            force: true,
+        }))
+        .unwrap();
+    let worktrees = worktree_service.list_worktrees(task.id).unwrap();
+    assert_eq!(worktrees.len(), 1);
+    assert_eq!(
+        worktrees[0].base_repo.as_deref(),
+        Some(kept.path().to_string_lossy().as_ref())
+    );
+    // The base worktree is the main checkout; only its record goes away.
+    assert!(removed.path().exists());
 }
 
 #[test]
-fn test_handle_scrap_add() {
+fn test_handle_repo_remove_with_worktrees_keeps_repo_on_failure() {
     let db = Database::new_in_memory().unwrap();
     let handler = CommandHandler::from_db(db);
     let db = handler.get_db();
-    let task_service = TaskService::new(db);
-    let scrap_service = ScrapService::new(db);
-
-    let task = task_service.create_task("Task", None, None, None).unwrap();
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
+    let repo_service = RepoService::new(db);
+    let worktree_service = WorktreeService::new(db);
 
-    let cmd = Commands::Scrap(ScrapCommands::Add {
-        content: "My Note".to_string(),
-    });
-    handler.handle(cmd).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join(".jj")).unwrap();
+    let repo = repo_service
+        .add_repo(task.id, &dir.path().to_string_lossy(), None, None)
+        .unwrap();
+    // The first directory exists but is no jj workspace, so removing it fails;
+    // the second is already gone and is retired.
+    let stuck = dir.path().join("stuck");
+    std::fs::create_dir(&stuck).unwrap();
+    let now = chrono::Utc::now().to_rfc3339();
+    for (path, branch) in [(stuck.clone(), "stuck"), (dir.path().join("gone"), "gone")] {
+        db.get_connection()
+            .execute(
+                "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, ?2, ?3, ?4, 'active', ?5, NULL, 0)",
+                rusqlite::params![task.id, path.to_string_lossy(), branch, repo.repo_path, now],
+            )
+            .unwrap();
+    }
+
+    let result = handler.handle(Commands::Repo(RepoCommands::Remove {
+        repo_ref: "1".to_string(),
+        worktrees: true,
+        force: true,
+    }));
 
-    let scraps = scrap_service.list_scraps(task.id).unwrap();
-    assert_eq!(scraps.len(), 1);
-    assert_eq!(scraps[0].content, "My Note");
+    assert!(matches!(
+        result,
+        Err(TrackError::RepoWorktreeRemovalIncomplete(1))
+    ));
+    let remaining = worktree_service.list_worktrees(task.id).unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].branch, "stuck");
+    assert_eq!(repo_service.list_repos(task.id).unwrap().len(), 1);
 }
 
 #[test]
-fn test_handle_repo_add_remove() {
+fn test_handle_repo_add_pins_base_commit() {
     let Some(ws) = JjWorkspace::new() else {
         return;
     };
     let db = Database::new_in_memory().unwrap();
     let handler = CommandHandler::from_db(db);
     let db = handler.get_db();
-    let task_service = TaskService::new(db);
-    let repo_service = RepoService::new(db);
-
-    let task = task_service.create_task("Task", None, None, None).unwrap();
-
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
     let repo_path = ws.repo_path_string();
 
-    let cmd = Commands::Repo(RepoCommands::Add {
+    let result = handler.handle(Commands::Repo(RepoCommands::Add {
         path: Some(repo_path.clone()),
         base: None,
-    });
-    handler.handle(cmd).unwrap();
+        base_commit: Some("deadbeefdeadbeef".to_string()),
+    }));
+    assert!(matches!(result, Err(TrackError::Jj(_))));
 
-    let repos = repo_service.list_repos(task.id).unwrap();
+    handler
+        .handle(Commands::Repo(RepoCommands::Add {
+            path: Some(repo_path),
+            base: None,
+            base_commit: Some("root()".to_string()),
+        }))
+        .unwrap();
+
+    let repos = RepoService::new(db).list_repos(task.id).unwrap();
     assert_eq!(repos.len(), 1);
-    assert_eq!(repos[0].repo_path, repo_path);
+    assert!(repos[0].base_branch.is_none());
+    assert_eq!(
+        repos[0].base_commit_hash.as_deref(),
+        Some("0".repeat(40).as_str())
+    );
+}
 
-    let cmd = Commands::Repo(RepoCommands::Remove { id: repos[0].id });
-    handler.handle(cmd).unwrap();
+#[test]
+fn test_handle_worktree_remove_keeps_history_until_purged() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
+    db.set_current_task_id(task.id).unwrap();
+    db.get_connection()
+        .execute(
+            "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, '/missing/wt', 'b', '/repo', 'active', '2026-01-01T00:00:00+00:00', NULL, 0)",
+            rusqlite::params![task.id],
+        )
+        .unwrap();
+    let worktree_service = WorktreeService::new(db);
+    let id = worktree_service.list_worktrees(task.id).unwrap()[0].id;
+    let remove = |purge| {
+        handler.handle(Commands::Worktree(WorktreeCommands::Remove {
+            id,
+            force: false,
+            purge,
+        }))
+    };
 
-    let repos = repo_service.list_repos(task.id).unwrap();
-    assert_eq!(repos.len(), 0);
+    remove(false).unwrap();
+    assert_eq!(
+        worktree_service.get_worktree(id).unwrap().status,
+        WorktreeStatus::Removed
+    );
+    assert!(matches!(
+        remove(false),
+        Err(TrackError::WorktreeRetired { .. })
+    ));
+    assert!(matches!(
+        handler.handle(Commands::Worktree(WorktreeCommands::MergeStatus { id })),
+        Err(TrackError::WorktreeRetired { .. })
+    ));
+
+    remove(true).unwrap();
+    assert!(matches!(
+        worktree_service.get_worktree(id),
+        Err(TrackError::WorktreeNotFound(_))
+    ));
+}
+
+#[test]
+fn test_handle_worktree_add_resolves_todo_index_in_current_task() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task_service = TaskService::new(db);
+    let todo_service = TodoService::new(db);
+
+    let other = task_service.create_task("Other", None, None, None).unwrap();
+    todo_service.add_todo(other.id, "Elsewhere", false).unwrap();
+    let task = task_service.create_task("Task", None, None, None).unwrap();
+    db.set_current_task_id(task.id).unwrap();
+
+    // Index 1 only exists in the other task, so it must not resolve here
+    let result = handler.handle(Commands::Worktree(WorktreeCommands::Add {
+        repo_ref: "1".to_string(),
+        branch: None,
+        todo: Some(1),
+        from: None,
+        use_existing: false,
+        base_worktree: false,
+    }));
+    assert!(matches!(result, Err(TrackError::TodoIndexNotFound(1))));
 }
 
 #[test]
@@ -232,6 +1226,27 @@ fn test_todo_delete_force() {
     assert_eq!(todos.len(), 0);
 }
 
+#[test]
+fn test_todo_delete_with_assume_yes_skips_prompt() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db).with_assume_yes(true);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
+    let todo_service = TodoService::new(db);
+    todo_service.add_todo(task.id, "To Delete", false).unwrap();
+
+    // Without --force this would read stdin; --yes answers the prompt
+    let cmd = Commands::Todo(TodoCommands::Delete {
+        id: 1,
+        force: false,
+    });
+    handler.handle(cmd).unwrap();
+
+    assert!(todo_service.list_todos(task.id).unwrap().is_empty());
+}
+
 #[test]
 fn test_todo_workspace_requires_current_repo() {
     let Some(ws) = JjWorkspace::new() else {
@@ -262,6 +1277,8 @@ fn test_todo_workspace_requires_current_repo() {
         recreate: false,
         force: false,
         all: false,
+        strict: false,
+        branch: None,
     });
     let result = handler.handle(cmd);
 
@@ -308,6 +1325,8 @@ fn test_todo_workspace_accepts_subdir_repo() {
         recreate: false,
         force: false,
         all: false,
+        strict: false,
+        branch: None,
     });
     handler.handle(cmd).unwrap();
 
@@ -317,6 +1336,53 @@ fn test_todo_workspace_accepts_subdir_repo() {
     assert_eq!(worktrees.len(), 1);
 }
 
+#[test]
+fn test_todo_workspace_uses_explicit_branch() {
+    let Some(ws) = JjWorkspace::new() else {
+        return;
+    };
+    let _guard = cwd_lock();
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task_service = TaskService::new(db);
+    let todo_service = TodoService::new(db);
+    let repo_service = RepoService::new(db);
+    let worktree_service = WorktreeService::new(db);
+
+    let task = task_service
+        .create_task("Task", None, Some("PROJ-1"), None)
+        .unwrap();
+    let _todo = todo_service.add_todo(task.id, "Fix login", false).unwrap();
+
+    let repo_path = ws.repo_path().to_path_buf();
+    ws.create_bookmark("task/PROJ-1");
+
+    repo_service
+        .add_repo(task.id, repo_path.to_str().unwrap(), None, None)
+        .unwrap();
+
+    let original_dir = std::env::current_dir().unwrap();
+    std::env::set_current_dir(&repo_path).unwrap();
+
+    let cmd = Commands::Todo(TodoCommands::Workspace {
+        id: 1,
+        recreate: false,
+        force: false,
+        all: false,
+        strict: false,
+        branch: Some("fix-login-bug".to_string()),
+    });
+    let result = handler.handle(cmd);
+
+    std::env::set_current_dir(original_dir).unwrap();
+    result.unwrap();
+
+    let worktrees = worktree_service.list_worktrees(task.id).unwrap();
+    assert_eq!(worktrees.len(), 1);
+    assert_eq!(worktrees[0].branch, "PROJ-1/fix-login-bug");
+}
+
 #[test]
 fn test_list_repo_links_manual() {
     // Tests WorktreeService::list_repo_links by manually inserting data
@@ -383,6 +1449,7 @@ fn test_handle_archive_clean_worktree() {
     let cmd = Commands::Archive {
         task_ref: Some(task.id.to_string()),
         force: false,
+        list: false,
     };
 
     // Note: This relies on stdin being empty in test env.
@@ -424,7 +1491,14 @@ fn test_handle_sync() {
     todo_service.add_todo(task.id, "Todo WT", true).unwrap();
 
     // Call Sync
-    let cmd = Commands::Sync { legacy: false };
+    let cmd = Commands::Sync {
+        legacy: false,
+        create_base_worktree: false,
+        prune: false,
+        force: false,
+        json: false,
+        verify: None,
+    };
     handler.handle(cmd).unwrap();
 
     // Verify bookmarks created
@@ -508,13 +1582,59 @@ fn test_handle_sync_dirty_repo() {
 
     std::fs::write(std::path::Path::new(&repo_path).join("README.md"), "dirty").unwrap();
 
-    let cmd = Commands::Sync { legacy: true };
+    let cmd = Commands::Sync {
+        legacy: true,
+        create_base_worktree: false,
+        prune: false,
+        force: false,
+        json: false,
+        verify: None,
+    };
     let result = handler.handle(cmd);
 
     assert!(result.is_err());
     assert!(result.unwrap_err().to_string().contains("pending changes"));
 }
 
+#[test]
+fn test_handle_sync_prune_refused_in_jj_mode_keeps_worktrees() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", None, Some("SYNC-881"), None)
+        .unwrap();
+    let todo_service = TodoService::new(db);
+    let todo = todo_service.add_todo(task.id, "Done item", false).unwrap();
+    todo_service.mark_done(todo.id).unwrap();
+    db.get_connection()
+        .execute_batch(&format!(
+            "INSERT INTO task_repos (task_id, task_index, repo_path, created_at) VALUES ({task}, 1, '/repo', '{now}');
+             INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES ({task}, '/repo/wt', 'task/SYNC-881-todo-1', '/repo', 'active', '{now}', {todo}, 0);",
+            task = task.id,
+            todo = todo.id,
+            now = chrono::Utc::now().to_rfc3339()
+        ))
+        .unwrap();
+
+    let result = handler.handle(Commands::Sync {
+        legacy: false,
+        create_base_worktree: false,
+        prune: true,
+        force: true,
+        json: false,
+        verify: None,
+    });
+    assert!(matches!(result, Err(TrackError::SyncUseJjTask { .. })));
+    assert_eq!(
+        WorktreeService::new(db)
+            .list_worktrees(task.id)
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
 #[test]
 fn test_handle_sync_repo_not_found() {
     let Some(ws) = JjWorkspace::new() else {
@@ -544,13 +1664,59 @@ fn test_handle_sync_repo_not_found() {
     drop(ws);
 
     // Sync should handle this gracefully (skip non-existent repos)
-    let cmd = Commands::Sync { legacy: true };
+    let cmd = Commands::Sync {
+        legacy: true,
+        create_base_worktree: false,
+        prune: false,
+        force: false,
+        json: false,
+        verify: None,
+    };
     let result = handler.handle(cmd);
 
     // Should succeed (just skip the missing repo)
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_handle_sync_create_base_worktree() {
+    let Some(ws) = JjWorkspace::new() else {
+        return;
+    };
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", None, Some("SYNC-BASE"), None)
+        .unwrap();
+    let repo_path = ws.repo_path_string();
+    RepoService::new(db)
+        .add_repo(task.id, &repo_path, None, None)
+        .unwrap();
+
+    for _ in 0..2 {
+        let cmd = Commands::Sync {
+            legacy: true,
+            create_base_worktree: true,
+            prune: false,
+            force: false,
+            json: false,
+            verify: None,
+        };
+        handler.handle(cmd).unwrap();
+    }
+
+    let bases: Vec<_> = WorktreeService::new(db)
+        .list_worktrees(task.id)
+        .unwrap()
+        .into_iter()
+        .filter(|wt| wt.is_base)
+        .collect();
+    assert_eq!(bases.len(), 1);
+    assert_eq!(bases[0].path, repo_path);
+    assert_eq!(bases[0].branch, "task/SYNC-BASE");
+}
+
 #[test]
 fn test_handle_sync_branch_already_exists() {
     let Some(ws) = JjWorkspace::new() else {
@@ -577,7 +1743,14 @@ fn test_handle_sync_branch_already_exists() {
         .unwrap();
 
     // Call Sync - should detect existing branch and checkout
-    let cmd = Commands::Sync { legacy: true };
+    let cmd = Commands::Sync {
+        legacy: true,
+        create_base_worktree: false,
+        prune: false,
+        force: false,
+        json: false,
+        verify: None,
+    };
     handler.handle(cmd).unwrap();
 
     // Verify task bookmark exists
@@ -630,7 +1803,14 @@ fn test_handle_sync_worktree_already_exists() {
         .unwrap();
 
     // Call Sync - should detect existing worktree and NOT create duplicate
-    let cmd = Commands::Sync { legacy: false };
+    let cmd = Commands::Sync {
+        legacy: false,
+        create_base_worktree: false,
+        prune: false,
+        force: false,
+        json: false,
+        verify: None,
+    };
     handler.handle(cmd).unwrap();
 
     // Verify only 1 worktree exists (not duplicated)
@@ -667,7 +1847,14 @@ fn test_handle_sync_skip_done_todos() {
     todo_service.update_status(todo.id, "done").unwrap();
 
     // Call Sync - should NOT create worktree for done TODO
-    let cmd = Commands::Sync { legacy: true };
+    let cmd = Commands::Sync {
+        legacy: true,
+        create_base_worktree: false,
+        prune: false,
+        force: false,
+        json: false,
+        verify: None,
+    };
     handler.handle(cmd).unwrap();
 
     // Verify no worktrees created
@@ -705,7 +1892,14 @@ fn test_handle_sync_failed_branch_create() {
     std::fs::set_permissions(&jj_dir, perms).ok(); // May fail on some systems
 
     // Sync should handle failures gracefully (they cause continue, not panic)
-    let cmd = Commands::Sync { legacy: true };
+    let cmd = Commands::Sync {
+        legacy: true,
+        create_base_worktree: false,
+        prune: false,
+        force: false,
+        json: false,
+        verify: None,
+    };
     let result = handler.handle(cmd);
 
     // Should succeed even if JJ operations failed
@@ -786,7 +1980,14 @@ fn test_handle_sync_multiple_todos_different_worktrees() {
         .unwrap();
 
     // Call Sync - should create worktree for todo2, but NOT todo1 (exists check)
-    let cmd = Commands::Sync { legacy: false };
+    let cmd = Commands::Sync {
+        legacy: false,
+        create_base_worktree: false,
+        prune: false,
+        force: false,
+        json: false,
+        verify: None,
+    };
     handler.handle(cmd).unwrap();
 
     // Verify both worktrees exist with correct todo_id linkage
@@ -825,6 +2026,7 @@ fn test_handle_archive_default_current_task() {
     let cmd = Commands::Archive {
         task_ref: None,
         force: false,
+        list: false,
     };
     handler.handle(cmd).unwrap();
 
@@ -848,15 +2050,57 @@ fn test_handle_status_explicit_id() {
 
     // Call status for t1 while t2 is active
     let cmd = Commands::Status {
-        id: Some(t1.id.to_string()),
+        task_ref: Some(t1.id.to_string()),
         json: false,
         all: false,
+        watch: false,
+        interval: 2,
+        only: vec![],
+        no_todos: false,
+        no_links: false,
+        no_scraps: false,
+        no_worktrees: false,
+        absolute: false,
     };
 
     // Should succeed
     handler.handle(cmd).unwrap();
 }
 
+#[test]
+fn test_handle_status_ticket_and_alias_refs() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let task_service = TaskService::new(handler.get_db());
+
+    let task = task_service
+        .create_task("Referenced", None, Some("PROJ-1"), None)
+        .unwrap();
+    task_service.set_alias(task.id, "myalias", false).unwrap();
+    task_service.create_task("Other", None, None, None).unwrap();
+
+    let status = |task_ref: &str| Commands::Status {
+        task_ref: Some(task_ref.to_string()),
+        json: true,
+        all: false,
+        watch: false,
+        interval: 2,
+        only: vec![],
+        no_todos: false,
+        no_links: false,
+        no_scraps: false,
+        no_worktrees: false,
+        absolute: false,
+    };
+
+    handler.handle(status("t:PROJ-1")).unwrap();
+    handler.handle(status("a:myalias")).unwrap();
+    assert!(matches!(
+        handler.handle(status("a:unknown")),
+        Err(TrackError::TaskReferenceNotFound(_))
+    ));
+}
+
 #[test]
 fn test_handle_todo_add_no_workspace_flag() {
     let db = Database::new_in_memory().unwrap();
@@ -872,6 +2116,14 @@ fn test_handle_todo_add_no_workspace_flag() {
             text: "Research".to_string(),
             worktree: false,
             no_workspace: true,
+            workspace: false,
+            top: false,
+            after_current: false,
+            links: vec![],
+            estimate: None,
+            due: None,
+            auto_link: false,
+            no_auto_link: false,
         }))
         .unwrap();
 
@@ -881,6 +2133,49 @@ fn test_handle_todo_add_no_workspace_flag() {
     assert!(!todos[0].requires_workspace);
 }
 
+#[test]
+fn test_handle_todo_add_follows_task_auto_workspace() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task_service = TaskService::new(db);
+    let todo_service = TodoService::new(db);
+
+    task_service.create_task("Task", None, None, None).unwrap();
+
+    handler
+        .handle(Commands::Task(TaskCommands::Set(
+            TaskSetCommands::AutoWorkspace {
+                value: Toggle::Off,
+                task: None,
+            },
+        )))
+        .unwrap();
+
+    for (text, workspace) in [("Research", false), ("Implement", true)] {
+        handler
+            .handle(Commands::Todo(TodoCommands::Add {
+                text: text.to_string(),
+                worktree: false,
+                no_workspace: false,
+                workspace,
+                top: false,
+                after_current: false,
+                links: vec![],
+                estimate: None,
+                due: None,
+                auto_link: false,
+                no_auto_link: false,
+            }))
+            .unwrap();
+    }
+
+    let task_id = db.get_current_task_id().unwrap().unwrap();
+    let todos = todo_service.list_todos(task_id).unwrap();
+    assert!(!todos[0].requires_workspace);
+    assert!(todos[1].requires_workspace);
+}
+
 #[test]
 fn test_handle_status_json_includes_workflow() {
     let db = Database::new_in_memory().unwrap();
@@ -894,9 +2189,70 @@ fn test_handle_status_json_includes_workflow() {
 
     handler
         .handle(Commands::Status {
-            id: None,
+            task_ref: None,
             json: true,
             all: false,
+            watch: false,
+            interval: 2,
+            only: vec![],
+            no_todos: false,
+            no_links: false,
+            no_scraps: false,
+            no_worktrees: false,
+            absolute: false,
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_handle_status_clears_stale_current_task() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+
+    // Points at a task row that no longer exists
+    db.set_current_task_id(99).unwrap();
+
+    handler
+        .handle(Commands::Status {
+            task_ref: None,
+            json: false,
+            all: false,
+            watch: false,
+            interval: 2,
+            only: vec![],
+            no_todos: false,
+            no_links: false,
+            no_scraps: false,
+            no_worktrees: false,
+            absolute: false,
         })
         .unwrap();
+
+    assert_eq!(db.get_current_task_id().unwrap(), None);
+}
+
+#[test]
+fn test_handle_status_json_errors_on_stale_current_task() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    db.set_current_task_id(99).unwrap();
+
+    let result = handler.handle(Commands::Status {
+        task_ref: None,
+        json: true,
+        all: false,
+        watch: false,
+        interval: 2,
+        only: vec![],
+        no_todos: false,
+        no_links: false,
+        no_scraps: false,
+        no_worktrees: false,
+        absolute: false,
+    });
+
+    assert!(matches!(result, Err(TrackError::NoActiveTask)));
+    assert_eq!(db.get_current_task_id().unwrap(), None);
 }
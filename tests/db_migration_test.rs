@@ -1,6 +1,6 @@
 use rusqlite::Connection;
 use track::db::Database;
-use track::models::TodoStatus;
+use track::models::{TaskStatus, TodoStatus};
 
 fn open_legacy_git_items_db() -> Connection {
     let conn = Connection::open_in_memory().unwrap();
@@ -122,3 +122,55 @@ fn fresh_database_initializes_status_constraints() {
     )
     .unwrap_err();
 }
+
+#[test]
+fn migrate_widens_task_status_check_for_blocked_and_paused() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("track.db");
+    {
+        let conn = Connection::open(&path).unwrap();
+        conn.execute_batch(
+            r#"
+            CREATE TABLE tasks (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL,
+                description TEXT,
+                status TEXT NOT NULL DEFAULT 'active' CHECK (status IN ('active', 'archived')),
+                ticket_id TEXT,
+                ticket_url TEXT,
+                alias TEXT,
+                is_today_task INTEGER DEFAULT 0,
+                created_at TEXT NOT NULL
+            );
+            CREATE UNIQUE INDEX idx_tasks_alias ON tasks(alias);
+            INSERT INTO tasks (name, status, alias, created_at)
+            VALUES ('Legacy', 'active', 'legacy', '2026-01-01T00:00:00+00:00');
+            "#,
+        )
+        .unwrap();
+    }
+
+    let db = Database::open(&path).unwrap();
+    let conn = db.get_connection();
+
+    conn.execute(
+        "UPDATE tasks SET status = ?1 WHERE alias = 'legacy'",
+        [TaskStatus::BLOCKED],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO tasks (name, status, alias, created_at) VALUES ('Dup', 'paused', 'legacy', datetime('now'))",
+        [],
+    )
+    .unwrap_err();
+    conn.execute(
+        "INSERT INTO tasks (name, status, created_at) VALUES ('bad', 'bogus', datetime('now'))",
+        [],
+    )
+    .unwrap_err();
+
+    let name: String = conn
+        .query_row("SELECT name FROM tasks WHERE id = 1", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(name, "Legacy");
+}
@@ -2,8 +2,10 @@ mod common;
 
 use common::jj::JjWorkspace;
 use track::db::Database;
-use track::models::{TaskStatus, TodoStatus};
-use track::services::{RepoService, TaskService, TodoService, WorktreeService};
+use track::models::{TaskListFilter, TaskStatus, TodoStatus};
+use track::services::{
+    LinkService, RepoService, ScrapService, TaskService, TodoService, WorktreeService,
+};
 
 /// Integration test: Full workflow from task creation to worktree management
 #[test]
@@ -119,18 +121,18 @@ fn test_task_switching() {
     assert_eq!(current_id, Some(task1.id));
 
     // List tasks (should not include archived)
-    let tasks = task_service.list_tasks(false).unwrap();
+    let tasks = task_service.list_tasks(TaskListFilter::Active).unwrap();
     assert_eq!(tasks.len(), 2);
 
     // Archive task1
     task_service.archive_task(task1.id).unwrap();
 
     // List tasks without archived
-    let active_tasks = task_service.list_tasks(false).unwrap();
+    let active_tasks = task_service.list_tasks(TaskListFilter::Active).unwrap();
     assert_eq!(active_tasks.len(), 1);
 
     // List tasks with archived
-    let all_tasks = task_service.list_tasks(true).unwrap();
+    let all_tasks = task_service.list_tasks(TaskListFilter::All).unwrap();
     assert_eq!(all_tasks.len(), 2);
 }
 
@@ -335,3 +337,54 @@ fn test_concurrent_todo_additions() {
         assert_eq!(idx, (i + 1) as i64, "Indices should be sequential");
     }
 }
+
+/// Integration test: every CLI-side mutation bumps the revision the WebUI polls
+#[test]
+fn test_mutations_bump_section_revs() {
+    let db = Database::new_in_memory().unwrap();
+    let task_service = TaskService::new(&db);
+    let todo_service = TodoService::new(&db);
+
+    let bumps = |section: &str, mutate: &dyn Fn()| {
+        let before = db.get_rev(section).unwrap();
+        mutate();
+        assert!(
+            db.get_rev(section).unwrap() > before,
+            "{section} revision not bumped"
+        );
+    };
+
+    let task = task_service.create_task("Revs", None, None, None).unwrap();
+    bumps("task", &|| {
+        task_service.create_task("Other", None, None, None).unwrap();
+    });
+    bumps("task", &|| {
+        task_service.set_alias(task.id, "revs", false).unwrap()
+    });
+    bumps("task", &|| task_service.remove_alias(task.id).unwrap());
+    bumps("task", &|| {
+        task_service.set_description(task.id, "desc").unwrap()
+    });
+
+    let todo = todo_service.add_todo(task.id, "First", false).unwrap();
+    bumps("todos", &|| {
+        todo_service.add_todo(task.id, "Second", false).unwrap();
+    });
+    bumps("todos", &|| {
+        todo_service.update_status(todo.id, "done").unwrap()
+    });
+    bumps("todos", &|| todo_service.delete_todo(todo.id).unwrap());
+
+    bumps("scraps", &|| {
+        ScrapService::new(&db).add_scrap(task.id, "note").unwrap();
+    });
+
+    let link_service = LinkService::new(&db);
+    bumps("links", &|| {
+        link_service
+            .add_link(task.id, "https://example.com", None)
+            .unwrap();
+    });
+    let link = link_service.list_links(task.id).unwrap().remove(0);
+    bumps("links", &|| link_service.delete_link(link.id).unwrap());
+}
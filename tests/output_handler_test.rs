@@ -1,7 +1,11 @@
 use track::cli::handler::CommandHandler;
-use track::cli::{Commands, LinkCommands, ScrapCommands, TodoCommands};
+use track::cli::{
+    Commands, ExportFormat, LinkCommands, ListSort, ScrapCommands, StatusSection, StatusSections,
+    TodoCommands, WorktreeCommands, WorktreeState,
+};
 use track::db::Database;
 use track::services::{LinkService, ScrapService, TaskService, TodoService};
+use track::utils::TrackError;
 
 #[test]
 fn test_handle_list_no_output_errors() {
@@ -20,11 +24,33 @@ fn test_handle_list_no_output_errors() {
     task_service.archive_task(t1.id).unwrap();
 
     // List active only - should not error
-    let cmd = Commands::List { all: false };
+    let cmd = Commands::List {
+        all: false,
+        archived_only: false,
+        sort: ListSort::Created,
+        width: None,
+        no_truncate: false,
+    };
     assert!(handler.handle(cmd).is_ok());
 
     // List all - should not error
-    let cmd = Commands::List { all: true };
+    let cmd = Commands::List {
+        all: true,
+        archived_only: false,
+        sort: ListSort::Created,
+        width: None,
+        no_truncate: false,
+    };
+    assert!(handler.handle(cmd).is_ok());
+
+    // List by recent activity - should not error
+    let cmd = Commands::List {
+        all: false,
+        archived_only: false,
+        sort: ListSort::Recent,
+        width: None,
+        no_truncate: false,
+    };
     assert!(handler.handle(cmd).is_ok());
 }
 
@@ -61,17 +87,86 @@ fn test_handle_info_no_output_errors() {
 
     // Info without JSON - should not error
     let cmd = Commands::Status {
-        id: None,
+        task_ref: None,
         json: false,
         all: false,
+        watch: false,
+        interval: 2,
+        only: vec![],
+        no_todos: false,
+        no_links: false,
+        no_scraps: false,
+        no_worktrees: false,
+        absolute: false,
+    };
+    assert!(handler.handle(cmd).is_ok());
+
+    // Full timestamps instead of relative ones
+    let cmd = Commands::Status {
+        task_ref: None,
+        json: false,
+        all: true,
+        watch: false,
+        interval: 2,
+        only: vec![],
+        no_todos: false,
+        no_links: false,
+        no_scraps: false,
+        no_worktrees: false,
+        absolute: true,
     };
     assert!(handler.handle(cmd).is_ok());
 
     // Info with JSON - should not error
     let cmd = Commands::Status {
-        id: None,
+        task_ref: None,
         json: true,
         all: false,
+        watch: false,
+        interval: 2,
+        only: vec![],
+        no_todos: false,
+        no_links: false,
+        no_scraps: false,
+        no_worktrees: false,
+        absolute: false,
+    };
+    assert!(handler.handle(cmd).is_ok());
+}
+
+#[test]
+fn test_handle_status_section_toggles() {
+    let sections = StatusSections::from_flags(&[], false, false, true, false);
+    assert!(sections.todos && sections.links && sections.worktrees);
+    assert!(!sections.scraps);
+
+    let sections = StatusSections::from_flags(&[StatusSection::Todos], false, false, false, false);
+    assert!(sections.todos);
+    assert!(!sections.description && !sections.links && !sections.scraps);
+
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", Some("Description"), None, None)
+        .unwrap();
+    TodoService::new(db)
+        .add_todo(task.id, "Pending", false)
+        .unwrap();
+    ScrapService::new(db).add_scrap(task.id, "Note").unwrap();
+
+    let cmd = Commands::Status {
+        task_ref: None,
+        json: false,
+        all: false,
+        watch: false,
+        interval: 2,
+        only: vec![StatusSection::Todos],
+        no_todos: false,
+        no_links: false,
+        no_scraps: false,
+        no_worktrees: false,
+        absolute: false,
     };
     assert!(handler.handle(cmd).is_ok());
 }
@@ -88,6 +183,8 @@ fn test_handle_desc_view_and_set() {
     // View mode (no description) - should not error
     let cmd = Commands::Desc {
         description: None,
+        append: None,
+        editor: false,
         task: None,
     };
     assert!(handler.handle(cmd).is_ok());
@@ -95,6 +192,8 @@ fn test_handle_desc_view_and_set() {
     // Set mode - should not error
     let cmd = Commands::Desc {
         description: Some("New description".to_string()),
+        append: None,
+        editor: false,
         task: None,
     };
     assert!(handler.handle(cmd).is_ok());
@@ -102,6 +201,20 @@ fn test_handle_desc_view_and_set() {
     // Verify it was set
     let updated_task = task_service.get_task(task.id).unwrap();
     assert_eq!(updated_task.description.as_deref(), Some("New description"));
+
+    // Append mode keeps the existing text
+    let cmd = Commands::Desc {
+        description: None,
+        append: Some("More notes".to_string()),
+        editor: false,
+        task: None,
+    };
+    assert!(handler.handle(cmd).is_ok());
+    let updated_task = task_service.get_task(task.id).unwrap();
+    assert_eq!(
+        updated_task.description.as_deref(),
+        Some("New description\nMore notes")
+    );
 }
 
 #[test]
@@ -115,8 +228,9 @@ fn test_handle_ticket_links() {
 
     // Link ticket - should not error
     let cmd = Commands::Ticket {
-        ticket_id: "TICK-123".to_string(),
-        url: "http://ticket.com".to_string(),
+        ticket_id: Some("TICK-123".to_string()),
+        url: Some("http://ticket.com".to_string()),
+        set_url: None,
         task: None,
     };
     assert!(handler.handle(cmd).is_ok());
@@ -128,6 +242,22 @@ fn test_handle_ticket_links() {
         updated_task.ticket_url.as_deref(),
         Some("http://ticket.com")
     );
+
+    // --url replaces only the URL
+    let cmd = Commands::Ticket {
+        ticket_id: None,
+        url: None,
+        set_url: Some("https://tracker.example.com/TICK-123".to_string()),
+        task: None,
+    };
+    handler.handle(cmd).unwrap();
+
+    let updated_task = task_service.get_task(task.id).unwrap();
+    assert_eq!(updated_task.ticket_id.as_deref(), Some("TICK-123"));
+    assert_eq!(
+        updated_task.ticket_url.as_deref(),
+        Some("https://tracker.example.com/TICK-123")
+    );
 }
 
 #[test]
@@ -150,10 +280,184 @@ fn test_handle_todo_list_empty() {
     let _task = task_service.create_task("Task", None, None, None).unwrap();
 
     // List empty todos - should not error
-    let cmd = Commands::Todo(TodoCommands::List);
+    let cmd = Commands::Todo(TodoCommands::List {
+        completed_since: None,
+        worktree_only: false,
+        limit: None,
+        offset: 0,
+        width: None,
+        no_truncate: false,
+    });
     assert!(handler.handle(cmd).is_ok());
 }
 
+#[test]
+fn test_handle_list_and_todo_list_with_narrow_width() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let task = TaskService::new(handler.get_db())
+        .create_task(
+            "A task name long enough to blow out a narrow terminal",
+            None,
+            Some("PROJ-123456789"),
+            None,
+        )
+        .unwrap();
+    TodoService::new(handler.get_db())
+        .add_todo(
+            task.id,
+            "A TODO whose content\nspans lines and keeps going",
+            false,
+        )
+        .unwrap();
+
+    for (width, no_truncate) in [(Some(30), false), (Some(1), false), (None, true)] {
+        let cmd = Commands::List {
+            all: false,
+            archived_only: false,
+            sort: ListSort::Created,
+            width,
+            no_truncate,
+        };
+        assert!(handler.handle(cmd).is_ok());
+
+        let cmd = Commands::Todo(TodoCommands::List {
+            completed_since: None,
+            worktree_only: false,
+            limit: None,
+            offset: 0,
+            width,
+            no_truncate,
+        });
+        assert!(handler.handle(cmd).is_ok());
+    }
+}
+
+#[test]
+fn test_handle_todo_list_with_mixed_statuses() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let task = TaskService::new(handler.get_db())
+        .create_task("Task", None, None, None)
+        .unwrap();
+    let todo_service = TodoService::new(handler.get_db());
+    todo_service.add_todo(task.id, "Open", false).unwrap();
+    let done = todo_service.add_todo(task.id, "Shipped", false).unwrap();
+    todo_service.mark_done(done.id).unwrap();
+
+    let cmd = Commands::Todo(TodoCommands::List {
+        completed_since: None,
+        worktree_only: false,
+        limit: None,
+        offset: 0,
+        width: None,
+        no_truncate: false,
+    });
+    assert!(handler.handle(cmd).is_ok());
+
+    // Paginated, including an offset past the end
+    for (limit, offset) in [(Some(1), 1), (Some(5), 10), (None, 1)] {
+        let cmd = Commands::Todo(TodoCommands::List {
+            completed_since: None,
+            worktree_only: false,
+            limit,
+            offset,
+            width: None,
+            no_truncate: false,
+        });
+        assert!(handler.handle(cmd).is_ok());
+    }
+}
+
+#[test]
+fn test_handle_todo_list_worktree_only() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let task = TaskService::new(handler.get_db())
+        .create_task("Task", None, None, None)
+        .unwrap();
+    let todo_service = TodoService::new(handler.get_db());
+    todo_service.add_todo(task.id, "Research", false).unwrap();
+    let branch_work = todo_service.add_todo(task.id, "Branch work", true).unwrap();
+    handler
+        .get_db()
+        .get_connection()
+        .execute(
+            "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, '/repo/wt', 'task-1-todo-2', '/repo', 'active', '2026-01-01T00:00:00+00:00', ?2, 0)",
+            [task.id, branch_work.id],
+        )
+        .unwrap();
+
+    let cmd = Commands::Todo(TodoCommands::List {
+        completed_since: None,
+        worktree_only: true,
+        limit: None,
+        offset: 0,
+        width: None,
+        no_truncate: false,
+    });
+    assert!(handler.handle(cmd).is_ok());
+}
+
+#[test]
+fn test_handle_todo_list_completed_since() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let task = TaskService::new(handler.get_db())
+        .create_task("Task", None, None, None)
+        .unwrap();
+    let todo_service = TodoService::new(handler.get_db());
+    let todo = todo_service.add_todo(task.id, "Shipped", false).unwrap();
+    todo_service.mark_done(todo.id).unwrap();
+
+    let cmd = Commands::Todo(TodoCommands::List {
+        completed_since: Some("3d".to_string()),
+        worktree_only: false,
+        limit: None,
+        offset: 0,
+        width: None,
+        no_truncate: false,
+    });
+    assert!(handler.handle(cmd).is_ok());
+
+    let cmd = Commands::Todo(TodoCommands::List {
+        completed_since: Some("soon".to_string()),
+        worktree_only: false,
+        limit: None,
+        offset: 0,
+        width: None,
+        no_truncate: false,
+    });
+    assert!(matches!(
+        handler.handle(cmd),
+        Err(TrackError::InvalidDate { .. })
+    ));
+}
+
+#[test]
+fn test_handle_todo_show() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let task = TaskService::new(handler.get_db())
+        .create_task("Task", None, None, None)
+        .unwrap();
+    TodoService::new(handler.get_db())
+        .add_todo(task.id, "Long **body**\n\n- step", false)
+        .unwrap();
+    ScrapService::new(handler.get_db())
+        .add_scrap(task.id, "note")
+        .unwrap();
+
+    let cmd = Commands::Todo(TodoCommands::Show { id: 1 });
+    assert!(handler.handle(cmd).is_ok());
+
+    let cmd = Commands::Todo(TodoCommands::Show { id: 9 });
+    assert!(matches!(
+        handler.handle(cmd),
+        Err(TrackError::TodoIndexNotFound(9))
+    ));
+}
+
 #[test]
 fn test_handle_link_list_empty() {
     let db = Database::new_in_memory().unwrap();
@@ -164,10 +468,28 @@ fn test_handle_link_list_empty() {
     let _task = task_service.create_task("Task", None, None, None).unwrap();
 
     // List empty links - should not error
-    let cmd = Commands::Link(LinkCommands::List);
+    let cmd = Commands::Link(LinkCommands::List { json: false });
     assert!(handler.handle(cmd).is_ok());
 }
 
+#[test]
+fn test_handle_link_list_json() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
+    LinkService::new(db)
+        .add_link(task.id, "https://github.com/o/r/pull/3", Some("Fix"))
+        .unwrap();
+
+    for json in [false, true] {
+        let cmd = Commands::Link(LinkCommands::List { json });
+        assert!(handler.handle(cmd).is_ok());
+    }
+}
+
 #[test]
 fn test_handle_scrap_list_empty() {
     let db = Database::new_in_memory().unwrap();
@@ -178,6 +500,110 @@ fn test_handle_scrap_list_empty() {
     let _task = task_service.create_task("Task", None, None, None).unwrap();
 
     // List empty scraps - should not error
-    let cmd = Commands::Scrap(ScrapCommands::List);
+    let cmd = Commands::Scrap(ScrapCommands::List { json: false });
+    assert!(handler.handle(cmd).is_ok());
+
+    let cmd = Commands::Scrap(ScrapCommands::List { json: true });
+    assert!(handler.handle(cmd).is_ok());
+}
+
+#[test]
+fn test_handle_worktree_list_all_tasks_without_active_task() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+
+    // No current task is needed for the cross-task view
+    let cmd = Commands::Worktree(WorktreeCommands::List {
+        all_tasks: true,
+        size: false,
+        status: WorktreeState::Active,
+    });
+    assert!(handler.handle(cmd).is_ok());
+
+    let cmd = Commands::Worktree(WorktreeCommands::List {
+        all_tasks: false,
+        size: false,
+        status: WorktreeState::Active,
+    });
+    assert!(matches!(handler.handle(cmd), Err(TrackError::NoActiveTask)));
+}
+
+#[test]
+fn test_handle_worktree_list_size_tolerates_missing_paths() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let db = handler.get_db();
+    let task = TaskService::new(db)
+        .create_task("Task", None, None, None)
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("file.txt"), "content").unwrap();
+    for path in [dir.path().to_str().unwrap(), "/nonexistent/worktree"] {
+        db.get_connection()
+            .execute(
+                "INSERT INTO worktrees (task_id, path, branch, base_repo, status, created_at, todo_id, is_base) VALUES (?1, ?2, 'b', NULL, 'active', '2026-01-01T00:00:00+00:00', NULL, 0)",
+                rusqlite::params![task.id, path],
+            )
+            .unwrap();
+    }
+
+    for all_tasks in [false, true] {
+        for status in [
+            WorktreeState::Active,
+            WorktreeState::Merged,
+            WorktreeState::All,
+        ] {
+            let cmd = Commands::Worktree(WorktreeCommands::List {
+                all_tasks,
+                size: true,
+                status,
+            });
+            handler.handle(cmd).unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_handle_export_all_writes_one_file_per_task() {
+    let db = Database::new_in_memory().unwrap();
+    let handler = CommandHandler::from_db(db);
+    let task_service = TaskService::new(handler.get_db());
+    let first = task_service.create_task("First", None, None, None).unwrap();
+    task_service
+        .create_task("Second", None, None, None)
+        .unwrap();
+    handler
+        .get_db()
+        .get_connection()
+        .execute(
+            "INSERT INTO task_repos (task_id, task_index, repo_path, base_branch, created_at) VALUES (?1, 1, '/tmp/first-repo', 'main', '2026-01-01T00:00:00+00:00')",
+            [first.id],
+        )
+        .unwrap();
+    task_service.archive_task(first.id).unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let cmd = Commands::Export {
+        task_ref: None,
+        format: ExportFormat::Json,
+        all: true,
+        include_archived: false,
+        output: Some(dir.path().to_path_buf()),
+    };
+    assert!(handler.handle(cmd).is_ok());
+    let files: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+    assert_eq!(files.len(), 1);
+
+    let cmd = Commands::Export {
+        task_ref: Some(first.id.to_string()),
+        format: ExportFormat::Markdown,
+        all: false,
+        include_archived: false,
+        output: Some(dir.path().join("first.md")),
+    };
     assert!(handler.handle(cmd).is_ok());
+    let markdown = std::fs::read_to_string(dir.path().join("first.md")).unwrap();
+    assert!(markdown.starts_with(&format!("# Task #{}: First", first.id)));
+    assert!(markdown.contains("- **[1]** `/tmp/first-repo` (base: main)"));
 }
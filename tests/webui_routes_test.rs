@@ -37,8 +37,10 @@ async fn api_status_without_active_task_returns_empty_payload() {
         .unwrap()
         .to_bytes();
     let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["schema_version"], 1);
     assert!(json.get("task").unwrap().is_null());
     assert_eq!(json.get("todos").unwrap().as_array().unwrap().len(), 0);
+    assert!(json.get("workflow").is_none());
 }
 
 #[tokio::test]
@@ -74,8 +76,10 @@ async fn api_status_returns_current_task_todos() {
         .unwrap()
         .to_bytes();
     let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["schema_version"], 1);
     assert_eq!(json.get("task").unwrap()["name"], "Web task");
     assert_eq!(json.get("todos").unwrap().as_array().unwrap().len(), 1);
+    assert_eq!(json["todos"][0]["todo_id"], 1);
 }
 
 #[tokio::test]
@@ -227,3 +231,169 @@ async fn index_renders_without_active_task() {
     let html = String::from_utf8(body.to_vec()).unwrap();
     assert!(html.contains("<html") || html.contains("track"));
 }
+
+#[tokio::test]
+async fn app_states_with_different_db_paths_are_isolated() {
+    let dir = tempfile::tempdir().unwrap();
+    let path_a = dir.path().join("client-a.db");
+    let path_b = dir.path().join("client-b.db");
+
+    let state_a = AppState::new(Some(&path_a)).unwrap();
+    let state_b = AppState::new(Some(&path_b)).unwrap();
+    assert_eq!(state_a.db_path.as_deref(), Some(path_a.as_path()));
+
+    {
+        let db = state_a.db.lock().await;
+        let task = TaskService::new(&db)
+            .create_task("Client A", None, None, None)
+            .unwrap();
+        db.set_current_task_id(task.id).unwrap();
+    }
+
+    let app = build_router(WebState {
+        app: state_b,
+        templates: Arc::new(Templates::embedded()),
+    });
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/status")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = http_body_util::BodyExt::collect(response.into_body())
+        .await
+        .unwrap()
+        .to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(json.get("task").unwrap().is_null());
+}
+
+async fn get_status_json(app: &axum::Router) -> serde_json::Value {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/status")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = http_body_util::BodyExt::collect(response.into_body())
+        .await
+        .unwrap()
+        .to_bytes();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn api_status_serves_snapshot_until_revisions_change() {
+    let db = Database::new_in_memory().unwrap();
+    let task = TaskService::new(&db)
+        .create_task("Cached task", None, None, None)
+        .unwrap();
+    db.set_current_task_id(task.id).unwrap();
+    let todo = TodoService::new(&db)
+        .add_todo(task.id, "Original", false)
+        .unwrap();
+
+    let app_state = AppState::from_database(db);
+    let app = build_router(WebState {
+        app: app_state.clone(),
+        templates: Arc::new(Templates::embedded()),
+    });
+    app_state.poll_changes().await;
+
+    let json = get_status_json(&app).await;
+    assert_eq!(json["todos"][0]["content"], "Original");
+
+    // A write that does not bump a revision is invisible to the snapshot...
+    {
+        let db = app_state.db.lock().await;
+        db.get_connection()
+            .execute(
+                "UPDATE todos SET content = 'Edited' WHERE id = ?1",
+                [todo.id],
+            )
+            .unwrap();
+    }
+    let json = get_status_json(&app).await;
+    assert_eq!(json["todos"][0]["content"], "Original");
+
+    // ...until the change-detection loop observes a new revision.
+    app_state.db.lock().await.increment_rev("todos").unwrap();
+    app_state.poll_changes().await;
+    let json = get_status_json(&app).await;
+    assert_eq!(json["todos"][0]["content"], "Edited");
+}
+
+#[tokio::test]
+async fn static_assets_are_served_from_the_binary() {
+    let app = test_router(Database::new_in_memory().unwrap());
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/static/favicon.ico")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "image/x-icon");
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/static/missing.css")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+async fn post_alias(app: &axum::Router, alias: &str) -> StatusCode {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/task/alias")
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from(format!("alias={alias}")))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+        .status()
+}
+
+#[tokio::test]
+async fn update_alias_sets_and_clears_the_current_task_alias() {
+    let db = Database::new_in_memory().unwrap();
+    let task_service = TaskService::new(&db);
+    let other = task_service.create_task("Other", None, None, None).unwrap();
+    task_service.set_alias(other.id, "taken", false).unwrap();
+    let task = task_service
+        .create_task("Web task", None, None, None)
+        .unwrap();
+    db.set_current_task_id(task.id).unwrap();
+    let app = test_router(db);
+
+    assert_eq!(post_alias(&app, "web").await, StatusCode::OK);
+    assert_eq!(get_status_json(&app).await["task"]["alias"], "web");
+
+    assert_eq!(post_alias(&app, "taken").await, StatusCode::BAD_REQUEST);
+    assert_eq!(get_status_json(&app).await["task"]["alias"], "web");
+
+    assert_eq!(post_alias(&app, "").await, StatusCode::OK);
+    assert!(get_status_json(&app).await["task"]["alias"].is_null());
+}
@@ -121,6 +121,63 @@ fn test_complete_worktree_with_uncommitted_changes() {
     assert_eq!(worktrees.len(), 2);
 }
 
+/// Test complete_worktree_for_todo_with_commit commits a dirty workspace before merging
+#[test]
+fn test_complete_worktree_with_commit_message_commits_dirty_changes() {
+    let Some(ws) = JjWorkspace::new() else {
+        return;
+    };
+    let repo_path = ws.repo_path();
+
+    let db = Database::new_in_memory().unwrap();
+    let task_service = TaskService::new(&db);
+    let todo_service = TodoService::new(&db);
+    let worktree_service = WorktreeService::new(&db);
+
+    let task = task_service
+        .create_task("Task", None, Some("WT-102"), None)
+        .unwrap();
+    let todo = todo_service.add_todo(task.id, "Todo WT", true).unwrap();
+
+    let base_wt = worktree_service
+        .add_worktree(
+            task.id,
+            &repo_path.to_string_lossy(),
+            None,
+            Some("WT-102"),
+            None,
+            true,
+        )
+        .unwrap();
+    let todo_wt = worktree_service
+        .add_worktree(
+            task.id,
+            &repo_path.to_string_lossy(),
+            None,
+            Some("WT-102"),
+            Some(todo.id),
+            false,
+        )
+        .unwrap();
+
+    fs::write(
+        std::path::Path::new(&todo_wt.path).join("dirty.txt"),
+        "uncommitted",
+    )
+    .unwrap();
+
+    let branch_name = worktree_service
+        .complete_worktree_for_todo_with_commit(todo.id, Some("wip: dirty changes"))
+        .unwrap();
+    assert_eq!(branch_name.as_deref(), Some(todo_wt.branch.as_str()));
+
+    let worktrees = worktree_service.list_worktrees(task.id).unwrap();
+    assert_eq!(worktrees.len(), 1);
+    assert!(std::path::Path::new(&base_wt.path)
+        .join("dirty.txt")
+        .exists());
+}
+
 /// Test complete_worktree_for_todo when no worktree exists
 #[test]
 fn test_complete_worktree_no_worktree() {